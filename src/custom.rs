@@ -2,24 +2,68 @@
 
 // modules
 pub mod activate;
+pub mod allele_count;
+pub mod allele_counter;
+pub mod ambiguity_enum;
 pub mod anchor_enum;
+pub mod anchor_resolution;
+pub mod annotation_index;
+pub mod bam_reader;
 pub mod bin_position;
 pub mod break_point;
+pub mod call_merger;
 pub mod chr_anchor;
 pub mod chr_anchor_enum;
+pub mod clip_side_enum;
 pub mod cigar;
+pub mod depth_profile;
+pub mod detection_config;
 pub mod erv_annotations;
 pub mod extra_values_enum;
+pub mod fasta_index_record;
+pub mod feature_enum;
+pub mod feature_index;
+pub mod gene_annotation;
+pub mod genomic_interval;
+pub mod genotype_enum;
+pub mod indexed_fasta;
+pub mod interval_index;
 pub mod me_anchor;
 pub mod me_chimeric_pair;
 pub mod me_chimeric_read;
+pub mod me_classification;
+pub mod me_deletion_call;
+pub mod me_genotyper;
+pub mod me_info;
+pub mod me_insertion_call;
+pub mod me_insertion_caller;
 pub mod me_library;
+pub mod me_level_enum;
+pub mod merge_mode_enum;
+pub mod merged_call;
 pub mod orientation_enum;
+pub mod origin_enum;
+pub mod poly_tail;
+pub mod position;
 pub mod raw_values;
 pub mod read_control;
+pub mod refined_break_point;
+pub mod region;
+pub mod repair_enum;
+pub mod repeat_masker_record;
+pub mod resolution_strategy_enum;
+pub mod sample_genotype;
+pub mod site_filter;
+pub mod site_filter_enum;
 pub mod strand_direction;
+pub mod strand_enum;
+pub mod supplementary_alignment;
+pub mod sv_call;
+pub mod sv_caller;
 pub mod sv_chimeric_pair;
 pub mod sv_chimeric_read;
+pub mod sv_junction;
 pub mod sv_type;
+pub mod target_site_duplication;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,109 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::sample_genotype::SampleGenotype,
+  function::genotype_likelihood::genotype_likelihoods,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Fragments supporting reference & alternate alleles at a site.
+#[derive(Debug, new, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlleleCount {
+  /// Fragments supporting reference allele.
+  pub reference: usize,
+
+  /// Fragments supporting alternate allele.
+  pub alternate: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl AlleleCount {
+  /// Retrieve fragments supporting either allele.
+  pub fn depth(&self) -> usize {
+    self.reference + self.alternate
+  }
+
+  ///
+  /// Retrieve alternate allele fraction.
+  ///
+  /// # Returns
+  ///
+  /// Return allele balance. `None` without support.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::AlleleCount;
+  ///
+  /// assert_eq!(AlleleCount::new(6, 2).allele_balance(), Some(0.25));
+  /// assert_eq!(AlleleCount::new(0, 0).allele_balance(), None);
+  /// ```
+  pub fn allele_balance(&self) -> Option<f64> {
+    match self.depth() {
+      0 => None,
+      depth => Some(self.alternate as f64 / depth as f64),
+    }
+  }
+
+  ///
+  /// Compute log10 likelihoods on 0/0, 0/1 & 1/1.
+  ///
+  /// # Parameters
+  ///
+  /// * `error_rate` - Sequencing & alignment error rate.
+  pub fn likelihoods(
+    &self,
+    error_rate: f64,
+  ) -> [f64; 3] {
+    genotype_likelihoods(self.reference, self.alternate, error_rate)
+  }
+
+  ///
+  /// Genotype site on allele support.
+  ///
+  /// # Parameters
+  ///
+  /// * `error_rate` - Sequencing & alignment error rate.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AlleleCount,
+  ///   GenotypeEnum,
+  /// };
+  ///
+  /// let sample_genotype = AlleleCount::new(1, 15).genotype(0.01);
+  ///
+  /// assert_eq!(sample_genotype.genotype, GenotypeEnum::HomozygousAlternate);
+  /// ```
+  pub fn genotype(
+    &self,
+    error_rate: f64,
+  ) -> SampleGenotype {
+    SampleGenotype::load(self.reference, self.alternate, error_rate)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF AD
+impl fmt::Display for AlleleCount {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(f, "{},{}", self.reference, self.alternate)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,371 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Result as anyResult;
+use std::collections::{
+  HashMap,
+  HashSet,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    allele_count::AlleleCount,
+    chr_anchor::ChrAnchor,
+    clip_side_enum::ClipSideEnum,
+    detection_config::DetectionConfig,
+    genomic_interval::GenomicInterval,
+    interval_index::IntervalIndex,
+    me_chimeric_pair::MEChimericPair,
+    position::Position,
+    raw_values::RawValues,
+    sv_chimeric_pair::SVChimericPair,
+    sv_type::SVType,
+  },
+  function::flag_interpretor::SAMFlag,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Count fragments supporting reference & alternate alleles at candidate
+/// breakpoints.
+///
+/// Reference support comes from reads & proper pairs spanning the breakpoint
+/// without clipping or discordance. Alternate support comes from chimeric
+/// pairs, i.e., discordant pairs anchored near the breakpoint & split reads
+/// clipped at it.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct AlleleCounter {
+  /// Detection parameters.
+  #[new(default)]
+  pub config: DetectionConfig,
+
+  /// Maximum distance from breakpoint to anchor of discordant pairs.
+  #[new(value = "500")]
+  pub pair_distance: u64,
+
+  /// Maximum distance from breakpoint to clip junction of split reads.
+  #[new(value = "10")]
+  pub window: u64,
+
+  /// Minimum clipped bases to count read as split read.
+  #[new(value = "5")]
+  pub min_clip: i32,
+
+  /// Minimum aligned bases on each side of breakpoint on spanning reads.
+  #[new(value = "10")]
+  pub min_overhang: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// reference
+impl AlleleCounter {
+  ///
+  /// Check whether record supports reference allele at breakpoint.
+  ///
+  /// Unclipped reads spanning the breakpoint by minimum overhang on both
+  /// sides support the reference, whether paired properly or not. Proper
+  /// pairs also count when their template spans it, read from the leftmost
+  /// mate. Clipped reads are never counted.
+  ///
+  /// # Parameters
+  ///
+  /// * `values` - Record aligned to reference assembly.
+  ///
+  /// * `site` - Breakpoint.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AlleleCounter,
+  ///   GenomicInterval,
+  ///   Position,
+  ///   RawValues,
+  /// };
+  ///
+  /// let record = |cigar| {
+  ///   RawValues::load(vec![
+  ///     "ID", "0", "chr1", "951", "60", cigar, "*", "0", "0", "ACGT", "*",
+  ///   ])
+  ///   .expect("RawValues loading failed!")
+  /// };
+  /// let site = GenomicInterval::new(
+  ///   "chr1".to_string(),
+  ///   Position::OneBased(1000),
+  ///   Position::OneBased(1000),
+  /// );
+  ///
+  /// let allele_counter = AlleleCounter::new();
+  /// assert!(allele_counter.spans(&record("100M"), &site));
+  /// assert!(!allele_counter.spans(&record("55M45S"), &site));
+  /// ```
+  pub fn spans(
+    &self,
+    values: &RawValues,
+    site: &GenomicInterval,
+  ) -> bool {
+    if values.scaffold != site.chr ||
+      values.cigar.left_clip >= self.min_clip ||
+      values.cigar.rigth_clip >= self.min_clip
+    {
+      return false;
+    }
+    let (start, end) = template(values);
+    start + self.min_overhang <= site.start &&
+      end >= site.end + self.min_overhang
+  }
+
+  ///
+  /// Collect fragments supporting reference allele per breakpoint.
+  ///
+  /// Only primary alignments above minimum mapping quality are counted.
+  ///
+  /// # Parameters
+  ///
+  /// * `records` - Records aligned to reference assembly, e.g.,
+  ///   `RawValues::records`.
+  ///
+  /// * `sites` - Breakpoints.
+  ///
+  /// # Returns
+  ///
+  /// Return read IDs in breakpoint order. Error on malformed records.
+  pub fn reference<I>(
+    &self,
+    records: I,
+    sites: &[GenomicInterval],
+  ) -> anyResult<Vec<HashSet<String>>>
+  where
+    I: IntoIterator<Item = anyResult<RawValues>>,
+  {
+    let site_index = site_index(sites);
+    let mut reference = vec![HashSet::new(); sites.len()];
+    for values in records {
+      let values = values?;
+
+      // unmapped, secondary & supplementary records
+      if values.interpret(3) || values.interpret(9) || values.interpret(12) {
+        continue;
+      }
+      if values.quality < self.config.min_mapq {
+        continue;
+      }
+
+      let (start, end) = template(&values);
+      for (site, ix) in site_index.overlaps(&values.scaffold, start, end) {
+        if self.spans(&values, site) {
+          reference[*ix].insert(values.read_id.current.clone());
+        }
+      }
+    }
+    Ok(reference)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// alternate
+impl AlleleCounter {
+  ///
+  /// Collect mobile element chimeric pairs supporting alternate allele per
+  /// breakpoint.
+  ///
+  /// Pairs count on breakpoints within pair distance of their resolved
+  /// chromosomal anchor. Unresolved pairs are skipped.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_chimeric_pairs` - Chimeric pairs keyed by read ID.
+  ///
+  /// * `sites` - Breakpoints.
+  ///
+  /// # Returns
+  ///
+  /// Return read IDs in breakpoint order.
+  pub fn me_alternate(
+    &self,
+    me_chimeric_pairs: &HashMap<String, MEChimericPair>,
+    sites: &[GenomicInterval],
+  ) -> Vec<HashSet<String>> {
+    let site_index = site_index(sites);
+    let mut alternate = vec![HashSet::new(); sites.len()];
+    for (read_id, me_chimeric_pair) in me_chimeric_pairs.iter() {
+      let chr_anchor = match me_chimeric_pair
+        .get_chr_anchor()
+        .and_then(|me_chimeric_read| me_chimeric_read.chr_anchor())
+      {
+        Some(chr_anchor) => chr_anchor,
+        None => continue,
+      };
+      for (_, ix) in self.anchored(&site_index, chr_anchor) {
+        alternate[*ix].insert(read_id.clone());
+      }
+    }
+    alternate
+  }
+
+  ///
+  /// Collect structural variant chimeric pairs supporting alternate allele
+  /// per breakpoint.
+  ///
+  /// Split reads count on breakpoints within window of any clip junction.
+  /// Pairs identified as structural variants, see `SVChimericPair::identify`,
+  /// count on breakpoints within pair distance of either primary anchor.
+  ///
+  /// # Parameters
+  ///
+  /// * `sv_chimeric_pairs` - Chimeric pairs keyed by read ID.
+  ///
+  /// * `sites` - Breakpoints.
+  ///
+  /// # Returns
+  ///
+  /// Return read IDs in breakpoint order.
+  pub fn sv_alternate(
+    &self,
+    sv_chimeric_pairs: &HashMap<String, SVChimericPair>,
+    sites: &[GenomicInterval],
+  ) -> Vec<HashSet<String>> {
+    let site_index = site_index(sites);
+    let mut alternate = vec![HashSet::new(); sites.len()];
+    for (read_id, sv_chimeric_pair) in sv_chimeric_pairs.iter() {
+      for sv_chimeric_read in
+        [&sv_chimeric_pair.read1, &sv_chimeric_pair.read2].iter()
+      {
+        for chr_anchor in sv_chimeric_read.chr_read.iter() {
+          for side in [ClipSideEnum::Left, ClipSideEnum::Right].iter() {
+            if chr_anchor.cigar.clip_length(*side) < self.min_clip {
+              continue;
+            }
+            let junction = match chr_anchor.cigar.clip_junction(*side) {
+              Some(junction) => junction,
+              None => continue,
+            };
+            for (_, ix) in site_index.window(
+              &chr_anchor.chr,
+              junction,
+              junction,
+              self.window,
+            ) {
+              alternate[*ix].insert(read_id.clone());
+            }
+          }
+        }
+
+        if sv_chimeric_pair.svtag == SVType::None {
+          continue;
+        }
+        if let Some(chr_anchor) = sv_chimeric_read.chr_read.first() {
+          for (_, ix) in self.anchored(&site_index, chr_anchor) {
+            alternate[*ix].insert(read_id.clone());
+          }
+        }
+      }
+    }
+    alternate
+  }
+
+  // breakpoints within pair distance of anchor
+  fn anchored<'a>(
+    &self,
+    site_index: &'a IntervalIndex<usize>,
+    chr_anchor: &ChrAnchor,
+  ) -> Vec<&'a (GenomicInterval, usize)> {
+    site_index.window(
+      &chr_anchor.chr,
+      chr_anchor.position,
+      chr_anchor.reference_end(),
+      self.pair_distance,
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// tally
+impl AlleleCounter {
+  ///
+  /// Tally allele support per breakpoint.
+  ///
+  /// Fragments supporting both alleles, e.g., a read spanning the
+  /// breakpoint whose mate lies on a mobile element, are counted as
+  /// alternate.
+  ///
+  /// # Parameters
+  ///
+  /// * `reference` - Read IDs supporting reference allele per breakpoint.
+  ///
+  /// * `alternate` - Read IDs supporting alternate allele per breakpoint.
+  ///
+  /// # Returns
+  ///
+  /// Return allele counts in breakpoint order.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AlleleCount,
+  ///   AlleleCounter,
+  /// };
+  /// use std::collections::HashSet;
+  ///
+  /// let read_ids = |read_ids: &[&str]| {
+  ///   read_ids
+  ///     .iter()
+  ///     .map(|read_id| read_id.to_string())
+  ///     .collect::<HashSet<String>>()
+  /// };
+  ///
+  /// let allele_counts = AlleleCounter::tally(
+  ///   &[read_ids(&["pair1", "pair2", "pair3"])],
+  ///   &[read_ids(&["pair3", "pair4"])],
+  /// );
+  ///
+  /// assert_eq!(allele_counts, vec![AlleleCount::new(2, 2)]);
+  /// ```
+  pub fn tally(
+    reference: &[HashSet<String>],
+    alternate: &[HashSet<String>],
+  ) -> Vec<AlleleCount> {
+    reference
+      .iter()
+      .zip(alternate.iter())
+      .map(|(reference, alternate)| {
+        AlleleCount::new(
+          reference.difference(alternate).count(),
+          alternate.len(),
+        )
+      })
+      .collect()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// breakpoints labeled by order
+fn site_index(sites: &[GenomicInterval]) -> IntervalIndex<usize> {
+  let mut site_index = IntervalIndex::new();
+  for (ix, site) in sites.iter().enumerate() {
+    site_index.insert(site.clone(), ix);
+  }
+  site_index.index();
+  site_index
+}
+
+// aligned span, extended over template on leftmost mate of proper pairs
+fn template(values: &RawValues) -> (Position, Position) {
+  let start = values.position;
+  if values.interpret(2) && values.tlen > 0 {
+    return (start, start + values.tlen as u64 - 1);
+  }
+  let aligned = values.cigar.align.iter().sum::<i32>() +
+    values.cigar.deletion.iter().sum::<i32>();
+  (start, start + aligned as u64 - 1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
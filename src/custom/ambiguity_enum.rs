@@ -0,0 +1,18 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Annotate reason for ambiguous chromosomal anchor.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum AmbiguityEnum {
+  /// Read aligned to both mobile element ends.
+  Palindromic,
+  /// Both reads on mobile element with equal outermost edges.
+  EqualEdges,
+  /// Both reads on mobile element with opposing orientations.
+  BothMobileElement,
+  /// Neither read oriented on mobile element.
+  Unoriented,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
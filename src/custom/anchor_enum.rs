@@ -1,22 +1,14 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Label anchor by strand and orientation.
-#[derive(Debug, new, Clone, PartialEq)]
+#[derive(Debug, new, Default, Clone, PartialEq)]
 pub enum AnchorEnum {
   Foward5,
   Forward3,
   Reverse5,
   Reverse3,
+  #[default]
   None,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// manual default trait implementation
-impl Default for AnchorEnum {
-  fn default() -> Self {
-    Self::None
-  }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,61 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  ambiguity_enum::AmbiguityEnum,
+  chr_anchor_enum::ChrAnchorEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Chromosomal anchor resolution on chimeric pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnchorResolution {
+  /// Chromosomal anchor read.
+  Resolved(ChrAnchorEnum),
+  /// Reason for ambiguity.
+  Ambiguous(AmbiguityEnum),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl AnchorResolution {
+  /// Whether chromosomal anchor is resolved.
+  pub fn is_resolved(&self) -> bool {
+    matches!(self, AnchorResolution::Resolved(_))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// default trait implementation
+impl Default for AnchorResolution {
+  fn default() -> Self {
+    AnchorResolution::Ambiguous(AmbiguityEnum::None)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for AnchorResolution {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    match self {
+      AnchorResolution::Resolved(chranch) => write!(f, "{:?}", chranch),
+      AnchorResolution::Ambiguous(reason) => {
+        write!(f, "Ambiguous({:?})", reason)
+      }
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,213 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    genomic_interval::GenomicInterval,
+    interval_index::IntervalIndex,
+    position::Position,
+    region::Region,
+    repeat_masker_record::RepeatMaskerRecord,
+  },
+  error::common_error::CommonError,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Annotated regions per chromosome indexed for overlap queries.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct AnnotationIndex {
+  /// Regions labeled by annotation.
+  #[new(default)]
+  pub regions: IntervalIndex<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & update
+impl AnnotationIndex {
+  ///
+  /// Load BED regions.
+  ///
+  /// Coordinates are converted from 0-based, half-open to 1-based, inclusive.
+  /// Regions are labeled by name column, when present. Commented (`#`),
+  /// `track`, `browser` & blank lines are skipped.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - BED records.
+  ///
+  /// # Returns
+  ///
+  /// Return indexed regions. Error on malformed records.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AnnotationIndex,
+  ///   Position,
+  /// };
+  ///
+  /// let bed = "track name=blacklist\nchr1\t1000\t2000\tcentromere\n";
+  /// let annotation_index =
+  ///   AnnotationIndex::load_bed(bed.as_bytes()).expect("BED loading failed!");
+  ///
+  /// let regions = annotation_index.overlaps(
+  ///   "chr1",
+  ///   Position::OneBased(1),
+  ///   Position::OneBased(5000),
+  /// );
+  /// assert_eq!(regions.len(), 1);
+  /// assert_eq!(regions[0].0.start, Position::OneBased(1001));
+  /// assert_eq!(regions[0].1, "centromere");
+  /// ```
+  pub fn load_bed<R: BufRead>(reader: R) -> anyResult<Self> {
+    let mut annotation_index = Self::new();
+    for line in reader.lines() {
+      let line = line.context(CommonError::Reading)?;
+      if line.trim().is_empty() ||
+        line.starts_with('#') ||
+        line.starts_with("track") ||
+        line.starts_with("browser")
+      {
+        continue;
+      }
+      let flines = line.split('\t').collect::<Vec<&str>>();
+      if flines.len() < 3 {
+        return Err(CommonError::Parsing.into());
+      }
+      let start = flines[1].parse::<u64>().context(CommonError::Parsing)?;
+      let end = flines[2].parse::<u64>().context(CommonError::Parsing)?;
+      let label = flines.get(3).copied().unwrap_or_default();
+      let region = Region::zero_based(flines[0], start, end);
+      annotation_index
+        .regions
+        .insert(GenomicInterval::from(&region), label.to_string());
+    }
+    annotation_index.index();
+    Ok(annotation_index)
+  }
+
+  ///
+  /// Load RepeatMasker annotations, labeled by family.
+  ///
+  /// # Parameters
+  ///
+  /// * `records` - RepeatMasker records.
+  ///
+  /// # Returns
+  ///
+  /// Return indexed annotations.
+  pub fn load_repeat_masker(records: &[RepeatMaskerRecord]) -> Self {
+    let mut annotation_index = Self::new();
+    for record in records.iter() {
+      annotation_index.insert(
+        &record.scaffold,
+        record.start,
+        record.end,
+        &record.classification.family,
+      );
+    }
+    annotation_index.index();
+    annotation_index
+  }
+
+  ///
+  /// Insert region. Index is invalidated until `index` is called.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// * `label` - Region annotation.
+  pub fn insert(
+    &mut self,
+    chr: &str,
+    start: Position,
+    end: Position,
+    label: &str,
+  ) {
+    self.regions.insert(
+      GenomicInterval::new(chr.to_string(), start, end),
+      label.to_string(),
+    );
+  }
+
+  /// Sort regions & build index on chromosomes updated since last call.
+  pub fn index(&mut self) {
+    self.regions.index();
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl AnnotationIndex {
+  ///
+  /// Retrieve regions overlapping query.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return overlapping regions sorted by start.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AnnotationIndex,
+  ///   Position,
+  /// };
+  ///
+  /// let mut annotation_index = AnnotationIndex::new();
+  /// annotation_index.insert(
+  ///   "chr1",
+  ///   Position::OneBased(1001),
+  ///   Position::OneBased(7000),
+  ///   "L1",
+  /// );
+  /// annotation_index.insert(
+  ///   "chr1",
+  ///   Position::OneBased(2001),
+  ///   Position::OneBased(2300),
+  ///   "Alu",
+  /// );
+  /// annotation_index.index();
+  ///
+  /// let labels = annotation_index
+  ///   .overlaps("chr1", Position::OneBased(6500), Position::OneBased(8000))
+  ///   .iter()
+  ///   .map(|(_, label)| label.as_str())
+  ///   .collect::<Vec<&str>>();
+  /// assert_eq!(labels, vec!["L1"]);
+  /// ```
+  pub fn overlaps(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> Vec<&(GenomicInterval, String)> {
+    self.regions.overlaps(chr, start, end)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,353 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::convert::TryInto;
+use std::io::{
+  self,
+  ErrorKind,
+  Read,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::error::common_error::CommonError;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// BAM magic after BGZF decompression
+pub const BAM_MAGIC: [u8; 4] = [b'B', b'A', b'M', 1];
+
+// CIGAR operations by BAM code
+const CIGAR_OPERATIONS: &[u8; 9] = b"MIDNSHP=X";
+
+// bases by BAM 4 bit code
+const SEQUENCE_CODES: &[u8; 16] = b"=ACMGRSVTWYHKDBN";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Decompressed BAM stream read as SAM text.
+///
+/// Header text is emitted first, followed by one tab separated line per
+/// record, so BAM input is consumed by the same line parsers as SAM.
+#[derive(Debug)]
+pub struct BamReader<R: Read> {
+  /// Decompressed BAM stream.
+  reader: R,
+
+  /// Reference names by BAM reference id.
+  references: Vec<String>,
+
+  /// Pending SAM text.
+  buffer: Vec<u8>,
+
+  /// Bytes of pending SAM text already read.
+  offset: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl<R: Read> BamReader<R> {
+  ///
+  /// Load BAM header onto `BamReader`.
+  ///
+  /// References absent from header text are emitted as `@SQ` lines.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - Decompressed BAM stream, i.e., after BGZF decoding.
+  ///
+  /// # Returns
+  ///
+  /// Return an instance of `BamReader` positioned at first record. Error on
+  /// missing BAM magic or truncated header.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::BamReader;
+  /// use std::io::Read;
+  ///
+  /// let mut bam = b"BAM\x01".to_vec();
+  /// bam.extend(&0i32.to_le_bytes());
+  /// bam.extend(&1i32.to_le_bytes());
+  /// bam.extend(&5i32.to_le_bytes());
+  /// bam.extend(b"chr1\0");
+  /// bam.extend(&1000i32.to_le_bytes());
+  ///
+  /// let mut sam = String::new();
+  /// BamReader::load(&bam[..])
+  ///   .expect("BAM loading failed!")
+  ///   .read_to_string(&mut sam)
+  ///   .expect("BAM reading failed!");
+  ///
+  /// assert_eq!(sam, "@SQ\tSN:chr1\tLN:1000\n");
+  /// ```
+  pub fn load(mut reader: R) -> anyResult<Self> {
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic).context(CommonError::Reading)?;
+    if magic != BAM_MAGIC {
+      return Err(CommonError::Parsing.into());
+    }
+
+    let length = read_length(&mut reader)?;
+    let mut text = vec![0; length];
+    reader.read_exact(&mut text).context(CommonError::Reading)?;
+    // header text may be padded with nulls
+    while text.last() == Some(&0) {
+      text.pop();
+    }
+    let text = String::from_utf8(text).context(CommonError::Parsing)?;
+
+    let mut references = Vec::new();
+    let mut buffer = text.clone();
+    if !buffer.is_empty() && !buffer.ends_with('\n') {
+      buffer.push('\n');
+    }
+    for _ in 0..read_length(&mut reader)? {
+      let mut name = vec![0; read_length(&mut reader)?];
+      reader.read_exact(&mut name).context(CommonError::Reading)?;
+      name.pop();
+      let name = String::from_utf8(name).context(CommonError::Parsing)?;
+      let length = read_length(&mut reader)?;
+      if !text.contains(&format!("SN:{}\t", name)) &&
+        !text.contains(&format!("SN:{}\n", name))
+      {
+        buffer.push_str(&format!("@SQ\tSN:{}\tLN:{}\n", name, length));
+      }
+      references.push(name);
+    }
+
+    Ok(Self {
+      reader,
+      references,
+      buffer: buffer.into_bytes(),
+      offset: 0,
+    })
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// read
+impl<R: Read> BamReader<R> {
+  // queue next record as SAM line. false at end of stream
+  fn next_record(&mut self) -> io::Result<bool> {
+    let mut size = [0; 4];
+    let mut filled = 0;
+    while filled < size.len() {
+      match self.reader.read(&mut size[filled..]) {
+        Ok(0) if filled == 0 => return Ok(false),
+        Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+        Ok(read) => filled += read,
+        Err(error) if error.kind() == ErrorKind::Interrupted => (),
+        Err(error) => return Err(error),
+      }
+    }
+
+    let mut block = vec![0; u32::from_le_bytes(size) as usize];
+    self.reader.read_exact(&mut block)?;
+    let line = self.decode(&block).ok_or_else(|| {
+      io::Error::new(ErrorKind::InvalidData, "malformed BAM record")
+    })?;
+
+    self.buffer = line.into_bytes();
+    self.buffer.push(b'\n');
+    self.offset = 0;
+    Ok(true)
+  }
+
+  // decode record block onto SAM fields
+  fn decode(
+    &self,
+    mut block: &[u8],
+  ) -> Option<String> {
+    let block = &mut block;
+    let ref_id = integer(take(block, 4)?)?;
+    let pos = integer(take(block, 4)?)?;
+    let name_length = take(block, 1)?[0] as usize;
+    let mapq = take(block, 1)?[0];
+    take(block, 2)?;
+    let cigar_length = short(take(block, 2)?)? as usize;
+    let flag = short(take(block, 2)?)?;
+    let sequence_length = integer(take(block, 4)?)? as usize;
+    let next_ref_id = integer(take(block, 4)?)?;
+    let next_pos = integer(take(block, 4)?)?;
+    let tlen = integer(take(block, 4)?)?;
+
+    let name = take(block, name_length)?;
+    let name = std::str::from_utf8(&name[..name_length.checked_sub(1)?]).ok()?;
+
+    let mut cigar = String::new();
+    for _ in 0..cigar_length {
+      let operation = integer(take(block, 4)?)? as u32;
+      let code = *CIGAR_OPERATIONS.get((operation & 0xf) as usize)?;
+      cigar.push_str(&format!("{}{}", operation >> 4, code as char));
+    }
+    if cigar.is_empty() {
+      cigar.push('*');
+    }
+
+    let mut sequence = take(block, (sequence_length + 1) / 2)?
+      .iter()
+      .flat_map(|byte| vec![byte >> 4, byte & 0xf])
+      .map(|code| SEQUENCE_CODES[code as usize] as char)
+      .take(sequence_length)
+      .collect::<String>();
+    if sequence.is_empty() {
+      sequence.push('*');
+    }
+
+    let quality = take(block, sequence_length)?;
+    let quality = match quality.first() {
+      None | Some(255) => "*".to_string(),
+      // phred scores clamped onto printable range
+      Some(_) => quality
+        .iter()
+        .map(|q| q.saturating_add(33).min(b'~') as char)
+        .collect(),
+    };
+
+    let mut fields = vec![
+      name.to_string(),
+      flag.to_string(),
+      self.reference(ref_id)?.to_string(),
+      (pos + 1).to_string(),
+      mapq.to_string(),
+      cigar,
+      match next_ref_id {
+        id if id >= 0 && id == ref_id => "=".to_string(),
+        id => self.reference(id)?.to_string(),
+      },
+      (next_pos + 1).to_string(),
+      tlen.to_string(),
+      sequence,
+      quality,
+    ];
+
+    while !block.is_empty() {
+      fields.push(tag(block)?);
+    }
+
+    Some(fields.join("\t"))
+  }
+
+  // reference name by id, unmapped as `*`
+  fn reference(
+    &self,
+    id: i32,
+  ) -> Option<&str> {
+    if id < 0 {
+      Some("*")
+    } else {
+      self.references.get(id as usize).map(String::as_str)
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// read trait implementation
+impl<R: Read> Read for BamReader<R> {
+  fn read(
+    &mut self,
+    bytes: &mut [u8],
+  ) -> io::Result<usize> {
+    while self.offset >= self.buffer.len() {
+      if !self.next_record()? {
+        return Ok(0);
+      }
+    }
+    let pending = &self.buffer[self.offset..];
+    let length = pending.len().min(bytes.len());
+    bytes[..length].copy_from_slice(&pending[..length]);
+    self.offset += length;
+    Ok(length)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// non negative header length
+fn read_length<R: Read>(reader: &mut R) -> anyResult<usize> {
+  let mut bytes = [0; 4];
+  reader.read_exact(&mut bytes).context(CommonError::Reading)?;
+  let length = i32::from_le_bytes(bytes);
+  if length < 0 {
+    return Err(CommonError::Parsing.into());
+  }
+  Ok(length as usize)
+}
+
+// split leading bytes off block
+fn take<'a>(
+  block: &mut &'a [u8],
+  length: usize,
+) -> Option<&'a [u8]> {
+  if block.len() < length {
+    return None;
+  }
+  let (head, tail) = block.split_at(length);
+  *block = tail;
+  Some(head)
+}
+
+fn integer(bytes: &[u8]) -> Option<i32> {
+  Some(i32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn short(bytes: &[u8]) -> Option<u16> {
+  Some(u16::from_le_bytes(bytes.try_into().ok()?))
+}
+
+// numeric tag value by BAM type
+fn number(
+  block: &mut &[u8],
+  kind: u8,
+) -> Option<String> {
+  let value = match kind {
+    b'c' => (take(block, 1)?[0] as i8).to_string(),
+    b'C' => take(block, 1)?[0].to_string(),
+    b's' => i16::from_le_bytes(take(block, 2)?.try_into().ok()?).to_string(),
+    b'S' => short(take(block, 2)?)?.to_string(),
+    b'i' => integer(take(block, 4)?)?.to_string(),
+    b'I' => u32::from_le_bytes(take(block, 4)?.try_into().ok()?).to_string(),
+    b'f' => f32::from_le_bytes(take(block, 4)?.try_into().ok()?).to_string(),
+    _ => return None,
+  };
+  Some(value)
+}
+
+// optional field as SAM `TAG:TYPE:VALUE`
+fn tag(block: &mut &[u8]) -> Option<String> {
+  let name = std::str::from_utf8(take(block, 2)?).ok()?.to_string();
+  let kind = take(block, 1)?[0];
+  let (kind, value) = match kind {
+    b'A' => ('A', (take(block, 1)?[0] as char).to_string()),
+    b'c' | b'C' | b's' | b'S' | b'i' | b'I' => ('i', number(block, kind)?),
+    b'f' => ('f', number(block, kind)?),
+    b'Z' | b'H' => {
+      let length = block.iter().position(|byte| *byte == 0)?;
+      let value = std::str::from_utf8(take(block, length)?).ok()?.to_string();
+      take(block, 1)?;
+      (kind as char, value)
+    }
+    b'B' => {
+      let subtype = take(block, 1)?[0];
+      let count = integer(take(block, 4)?)?;
+      let mut value = (subtype as char).to_string();
+      for _ in 0..count {
+        value.push(',');
+        value.push_str(&number(block, subtype)?);
+      }
+      ('B', value)
+    }
+    _ => return None,
+  };
+  Some(format!("{}:{}:{}", name, kind, value))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
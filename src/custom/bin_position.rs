@@ -5,13 +5,18 @@ use std::collections::HashMap;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// crate utilities
+use crate::custom::position::Position;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, new, Default, PartialEq)]
 pub struct BinPosition {
   #[new(default)]
   pub count: i32,
 
   #[new(default)]
-  pub position: HashMap<i32, Vec<String>>,
+  pub position: HashMap<Position, Vec<String>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,10 +1,22 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // standard libraries
+use anyhow::Result as anyResult;
 use std::fmt;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    position::Position,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Determine breaking point.
 #[derive(Debug, new, Default, PartialEq)]
 pub struct BreakPoint {
@@ -12,9 +24,13 @@ pub struct BreakPoint {
   #[new(default)]
   pub sequence: String,
 
-  /// Coordinates.
+  /// First base after junction on extracted sequence.
+  #[new(default)]
+  pub coordinate: Position,
+
+  /// Read clip side.
   #[new(default)]
-  pub coordinate: f64,
+  pub side: ClipSideEnum,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -28,69 +44,143 @@ impl BreakPoint {
   ///
   /// * `sequence` - Original read sequence.
   ///
-  /// * `offset` - Mobile element estimated boundry to offset sequence.
+  /// * `offset` - Read overhang beyond mobile element ends. Zero or
+  ///   negative before mobile element start, positive past its end.
+  ///
+  /// * `flank` - Sequence length to extract beyond break point.
+  ///
+  /// # Returns
+  ///
+  /// Return an instance of `BreakPoint`. Error on empty sequence or break
+  /// point outside read.
   ///
   /// # Examples
   ///
   /// ```
-  /// use genomic_structures::BreakPoint;
+  /// use genomic_structures::{
+  ///   BreakPoint,
+  ///   ClipSideEnum,
+  ///   Position,
+  /// };
   ///
-  /// let breakpoint = BreakPoint::load("GATTACAAAAA", 0.);
+  /// let breakpoint =
+  ///   BreakPoint::load("GATTACAAAAA", 0, 10).expect("BreakPoint failed!");
   ///
   /// assert_eq!(breakpoint, BreakPoint {
   ///   sequence:   "GATTACAAAAA".to_string(),
-  ///   coordinate: 1.,
+  ///   coordinate: Position::OneBased(1),
+  ///   side:       ClipSideEnum::Left,
   /// })
   /// ```
   pub fn load(
     sequence: &str,
-    offset: f64,
-  ) -> Self {
+    offset: i64,
+    flank: usize,
+  ) -> anyResult<Self> {
     let mut breakpoint = BreakPoint::new();
-    breakpoint.update(sequence, offset);
-    breakpoint
+    breakpoint.update(sequence, offset, flank)?;
+    Ok(breakpoint)
   }
 
   ///
   /// Update  values of `BreakPoint`.
   ///
+  /// Flanking sequence is clamped at read ends. Values remain unaltered on
+  /// error.
+  ///
   /// # Parameters
   ///
   /// * `sequence` - Original read sequence.
   ///
-  /// * `offset` - Mobile element estimated boundry to offset sequence.
+  /// * `offset` - Read overhang beyond mobile element ends. Zero or
+  ///   negative before mobile element start, positive past its end.
+  ///
+  /// * `flank` - Sequence length to extract beyond break point.
   ///
   /// # Examples
   ///
   /// ```
-  /// use genomic_structures::BreakPoint;
+  /// use genomic_structures::{
+  ///   BreakPoint,
+  ///   ClipSideEnum,
+  ///   Position,
+  /// };
   ///
   /// let mut breakpoint = BreakPoint::new();
-  /// breakpoint.update("GATTACAAAAA", 0.);
+  /// breakpoint
+  ///   .update("GATTACAAAAA", 0, 3)
+  ///   .expect("BreakPoint failed!");
   ///
   /// assert_eq!(breakpoint, BreakPoint {
-  ///   sequence:   "GATTACAAAAA".to_string(),
-  ///   coordinate: 1.,
+  ///   sequence:   "GATT".to_string(),
+  ///   coordinate: Position::OneBased(1),
+  ///   side:       ClipSideEnum::Left,
   /// })
   /// ```
   pub fn update(
     &mut self,
     sequence: &str,
-    offset: f64,
-  ) {
-    // TODO: be aware of too large cleavage
-    let cleave = 10.;
-    // determine coordinate
-    self.coordinate = (offset * -1.) + 1.;
+    offset: i64,
+    flank: usize,
+  ) -> anyResult<()> {
+    // primary record never registered
+    if sequence.is_empty() {
+      return Err(CommonError::EmptySequence.into());
+    }
+
+    // clipped bases
+    let boundry = offset.unsigned_abs() as usize;
+    if boundry > sequence.len() || (offset <= 0 && boundry == sequence.len()) {
+      return Err(CommonError::OutOfBounds.into());
+    }
+
     // left break point. upstream from mobile element
-    if offset <= 0. {
-      self.sequence =
-        sequence[..(self.coordinate + cleave) as usize].to_string();
+    if offset <= 0 {
+      let end = (boundry + 1 + flank).min(sequence.len());
+      self.sequence = sequence[..end].to_string();
+      self.coordinate = Position::OneBased(boundry as u64 + 1);
+      self.side = ClipSideEnum::Left;
     // right break point. downstream from mobile element
     } else {
-      self.sequence = sequence
-        [(sequence.len() as f64 - offset - cleave) as usize..]
-        .to_string();
+      let start = sequence.len().saturating_sub(boundry + flank);
+      self.sequence = sequence[start..].to_string();
+      let junction = self.sequence.len() - boundry;
+      self.coordinate = Position::OneBased(junction as u64 + 1);
+      self.side = ClipSideEnum::Right;
+    }
+
+    Ok(())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl BreakPoint {
+  ///
+  /// Retrieve read bases beyond break point, i.e., clipped bases.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::BreakPoint;
+  ///
+  /// let left =
+  ///   BreakPoint::load("GATTACAAAAA", -3, 2).expect("BreakPoint failed!");
+  /// let right =
+  ///   BreakPoint::load("GATTACAAAAA", 4, 2).expect("BreakPoint failed!");
+  ///
+  /// assert_eq!(left.clipped(), "GAT");
+  /// assert_eq!(right.clipped(), "AAAA");
+  /// assert_eq!(BreakPoint::new().clipped(), "");
+  /// ```
+  pub fn clipped(&self) -> &str {
+    let junction =
+      (self.coordinate.zero_based() as usize).min(self.sequence.len());
+    match self.side {
+      ClipSideEnum::Left => &self.sequence[..junction],
+      ClipSideEnum::Right => &self.sequence[junction..],
+      ClipSideEnum::None => "",
     }
   }
 }
@@ -0,0 +1,293 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  merge_mode_enum::MergeModeEnum,
+  merged_call::MergedCall,
+  position::Position,
+  sv_type::SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Merge call sets across samples & callers, after SURVIVOR & Jasmine.
+///
+/// Calls match when both breakends lie within maximum distance on the same
+/// chromosomes, types agree, mobile element families agree & spanning
+/// variants reach minimum reciprocal overlap. Calls are clustered onto the
+/// first call of each cluster, taking at most one call per input.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct CallMerger {
+  /// Maximum distance between matching breakends.
+  #[new(value = "1000")]
+  pub max_distance: u64,
+
+  /// Minimum reciprocal overlap between spanning variants, i.e., deletions,
+  /// duplications & inversions.
+  #[new(value = "0.5")]
+  pub min_overlap: f64,
+
+  /// Whether variant types must agree.
+  #[new(value = "true")]
+  pub match_type: bool,
+
+  /// Whether mobile element families must agree. Calls without family match
+  /// any family.
+  #[new(value = "true")]
+  pub match_family: bool,
+
+  /// Merged call set, i.e., union or consensus.
+  #[new(default)]
+  pub mode: MergeModeEnum,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Expose calls to merging.
+pub trait Mergeable {
+  /// Retrieve variant type.
+  fn variant_type(&self) -> SVType;
+
+  /// Retrieve breakends as chromosome & position, i.e., start & end on
+  /// intrachromosomal variants.
+  fn breakends(&self) -> ((&str, Position), (&str, Position));
+
+  /// Retrieve mobile element family. `None` on structural variants.
+  fn element_family(&self) -> Option<&str> {
+    None
+  }
+
+  /// Retrieve reads supporting call, electing cluster representatives.
+  fn read_support(&self) -> usize;
+}
+
+// merge heterogeneous call sets as trait objects
+impl<T: Mergeable + ?Sized> Mergeable for &T {
+  fn variant_type(&self) -> SVType {
+    (**self).variant_type()
+  }
+
+  fn breakends(&self) -> ((&str, Position), (&str, Position)) {
+    (**self).breakends()
+  }
+
+  fn element_family(&self) -> Option<&str> {
+    (**self).element_family()
+  }
+
+  fn read_support(&self) -> usize {
+    (**self).read_support()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// merge
+impl CallMerger {
+  ///
+  /// Check whether calls match.
+  ///
+  /// # Parameters
+  ///
+  /// * `call` - Call.
+  ///
+  /// * `other` - Call compared against.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   CallMerger,
+  ///   Position,
+  ///   SVCall,
+  ///   SVType,
+  /// };
+  ///
+  /// let deletion = |position, end| {
+  ///   let mut sv_call = SVCall::new(SVType::Deletion);
+  ///   sv_call.chr = "chr1".to_string();
+  ///   sv_call.chr2 = "chr1".to_string();
+  ///   sv_call.position = Position::OneBased(position);
+  ///   sv_call.end = Position::OneBased(end);
+  ///   sv_call
+  /// };
+  ///
+  /// let call_merger = CallMerger::new();
+  /// let (call, other) = (deletion(1001, 3000), deletion(1101, 3200));
+  /// assert!(call_merger.matches(&call, &other));
+  ///
+  /// // breakends within distance, without overlap
+  /// let (call, other) = (deletion(1001, 1200), deletion(1801, 2000));
+  /// assert!(!call_merger.matches(&call, &other));
+  /// ```
+  pub fn matches<T: Mergeable + ?Sized>(
+    &self,
+    call: &T,
+    other: &T,
+  ) -> bool {
+    let ((chr, start), (chr2, end)) = call.breakends();
+    let ((other_chr, other_start), (other_chr2, other_end)) = other.breakends();
+    if chr != other_chr ||
+      chr2 != other_chr2 ||
+      (start - other_start).unsigned_abs() > self.max_distance ||
+      (end - other_end).unsigned_abs() > self.max_distance
+    {
+      return false;
+    }
+
+    if self.match_type && call.variant_type() != other.variant_type() {
+      return false;
+    }
+    if self.match_family {
+      if let (Some(family), Some(other_family)) =
+        (call.element_family(), other.element_family())
+      {
+        if family != other_family {
+          return false;
+        }
+      }
+    }
+
+    match reciprocal_overlap(call, other) {
+      Some(overlap) => overlap >= self.min_overlap,
+      None => true,
+    }
+  }
+
+  ///
+  /// Merge call sets.
+  ///
+  /// Calls are visited by chromosome & position, joining the closest
+  /// upstream cluster whose first call they match & holding no call from
+  /// their input, or opening a new cluster.
+  ///
+  /// # Parameters
+  ///
+  /// * `inputs` - Call sets, e.g., per sample or caller.
+  ///
+  /// # Returns
+  ///
+  /// Return merged calls supported by the minimum inputs on merge mode,
+  /// represented by their best supported member, first on ties, sorted by
+  /// chromosome & position.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   CallMerger,
+  ///   MergeModeEnum,
+  ///   Position,
+  ///   SVCall,
+  ///   SVType,
+  /// };
+  ///
+  /// let deletion = |position, end, support| {
+  ///   let mut sv_call = SVCall::new(SVType::Deletion);
+  ///   sv_call.chr = "chr1".to_string();
+  ///   sv_call.chr2 = "chr1".to_string();
+  ///   sv_call.position = Position::OneBased(position);
+  ///   sv_call.end = Position::OneBased(end);
+  ///   sv_call.support = support;
+  ///   sv_call
+  /// };
+  ///
+  /// let inputs = vec![
+  ///   vec![deletion(1001, 3000, 4), deletion(50001, 52000, 6)],
+  ///   vec![deletion(1051, 3020, 9)],
+  /// ];
+  ///
+  /// let mut call_merger = CallMerger::new();
+  /// let merged_calls = call_merger.merge(&inputs);
+  /// assert_eq!(merged_calls.len(), 2);
+  /// assert_eq!(merged_calls[0].call.position, Position::OneBased(1051));
+  /// assert_eq!(merged_calls[0].support_vector(2), "11");
+  ///
+  /// call_merger.mode = MergeModeEnum::Consensus(2);
+  /// assert_eq!(call_merger.merge(&inputs).len(), 1);
+  /// ```
+  pub fn merge<T: Mergeable + Clone>(
+    &self,
+    inputs: &[Vec<T>],
+  ) -> Vec<MergedCall<T>> {
+    let mut calls = inputs
+      .iter()
+      .enumerate()
+      .flat_map(|(input, calls)| {
+        calls.iter().enumerate().map(move |(ix, call)| (input, ix, call))
+      })
+      .collect::<Vec<(usize, usize, &T)>>();
+    calls.sort_by(|(a_input, a_ix, a), (b_input, b_ix, b)| {
+      (a.breakends().0, a_input, a_ix).cmp(&(b.breakends().0, b_input, b_ix))
+    });
+
+    let mut clusters: Vec<Vec<(usize, usize, &T)>> = Vec::new();
+    for (input, ix, call) in calls {
+      let (chr, start) = call.breakends().0;
+      let cluster = clusters
+        .iter_mut()
+        .rev()
+        .take_while(|cluster| {
+          let (seed_chr, seed_start) = cluster[0].2.breakends().0;
+          seed_chr == chr && start - seed_start <= self.max_distance as i64
+        })
+        .find(|cluster| {
+          cluster.iter().all(|(member_input, ..)| *member_input != input) &&
+            self.matches(cluster[0].2, call)
+        });
+      match cluster {
+        Some(cluster) => cluster.push((input, ix, call)),
+        None => clusters.push(vec![(input, ix, call)]),
+      }
+    }
+
+    let mut merged_calls = clusters
+      .into_iter()
+      .filter(|cluster| cluster.len() >= self.mode.min_inputs())
+      .filter_map(|cluster| {
+        let (_, _, representative) = cluster
+          .iter()
+          .rev()
+          .max_by_key(|(_, _, call)| call.read_support())?;
+        let mut merged_call = MergedCall::new((*representative).clone());
+        merged_call.members = cluster
+          .iter()
+          .map(|(input, ix, _)| (*input, *ix))
+          .collect();
+        merged_call.members.sort();
+        Some(merged_call)
+      })
+      .collect::<Vec<MergedCall<T>>>();
+    merged_calls.sort_by(|a, b| a.call.breakends().cmp(&b.call.breakends()));
+    merged_calls
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// reciprocal overlap on spanning variants, `None` on insertions & breakends
+fn reciprocal_overlap<T: Mergeable + ?Sized>(
+  call: &T,
+  other: &T,
+) -> Option<f64> {
+  let spanning = |call: &T| {
+    let ((chr, start), (chr2, end)) = call.breakends();
+    let svtype = call.variant_type();
+    if chr != chr2 ||
+      end <= start ||
+      svtype == SVType::Insertion ||
+      svtype.is_breakend()
+    {
+      None
+    } else {
+      Some((start, end))
+    }
+  };
+  let (start, end) = spanning(call)?;
+  let (other_start, other_end) = spanning(other)?;
+  let overlap = (end.min(other_end) - start.max(other_start) + 1).max(0);
+  let length = (end - start + 1).max(other_end - other_start + 1);
+  Some(overlap as f64 / length as f64)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
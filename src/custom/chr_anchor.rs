@@ -14,6 +14,7 @@ use crate::{
   custom::{
     anchor_enum::AnchorEnum,
     cigar::CIGAR,
+    position::Position,
   },
 };
 
@@ -44,11 +45,23 @@ pub struct ChrAnchor {
 
   /// Alignment position.
   #[new(default)]
-  pub position: i32,
+  pub position: Position,
 
   /// Template length (TLEN).
   #[new(default)]
   pub tlen: i32,
+
+  /// Alignment score (AS tag).
+  #[new(default)]
+  pub alignment_score: Option<i32>,
+
+  /// Suboptimal alignment score (XS tag).
+  #[new(default)]
+  pub suboptimal_score: Option<i32>,
+
+  /// Whether alignment is multi-mapped.
+  #[new(default)]
+  pub ambiguous: bool,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -82,13 +95,14 @@ impl ChrAnchor {
   /// use genomic_structures::{
   ///   AnchorEnum,
   ///   ChrAnchor,
+  ///   Position,
   ///   CIGAR,
   /// };
   ///
   /// let cigar = "100M";
   /// let chr = "chr7".to_string();
   /// let flag = 56;
-  /// let position = 2099;
+  /// let position = Position::OneBased(2099);
   /// let mapq = 60;
   /// let tlen = 100;
   ///
@@ -102,13 +116,17 @@ impl ChrAnchor {
   /// );
   ///
   /// let manual = ChrAnchor {
-  ///   anchor:   AnchorEnum::None,
-  ///   cigar:    CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
-  ///   chr:      chr.clone(),
-  ///   flag:     flag,
-  ///   position: position,
-  ///   mapq:     mapq,
-  ///   tlen:     tlen,
+  ///   anchor:           AnchorEnum::None,
+  ///   cigar:            CIGAR::load(cigar, position)
+  ///     .expect("CIGAR loading failed!"),
+  ///   chr:              chr.clone(),
+  ///   flag:             flag,
+  ///   position:         position,
+  ///   mapq:             mapq,
+  ///   tlen:             tlen,
+  ///   alignment_score:  None,
+  ///   suboptimal_score: None,
+  ///   ambiguous:        false,
   /// };
   ///
   /// assert_eq!(produced, manual);
@@ -118,7 +136,7 @@ impl ChrAnchor {
     chr: String,
     flag: i32,
     mapq: i32,
-    position: i32,
+    position: Position,
     tlen: i32,
   ) -> Self {
     let mut chr_anchor = Self::new();
@@ -154,13 +172,14 @@ impl ChrAnchor {
   /// use genomic_structures::{
   ///   AnchorEnum,
   ///   ChrAnchor,
+  ///   Position,
   ///   CIGAR,
   /// };
   ///
   /// let cigar = "100M";
   /// let chr = "chr7".to_string();
   /// let flag = 56;
-  /// let position = 2099;
+  /// let position = Position::OneBased(2099);
   /// let mapq = 60;
   /// let tlen = 100;
   ///
@@ -175,13 +194,17 @@ impl ChrAnchor {
   /// );
   ///
   /// let manual = ChrAnchor {
-  ///   anchor:   AnchorEnum::None,
-  ///   cigar:    CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
-  ///   chr:      chr.clone(),
-  ///   flag:     flag,
-  ///   position: position,
-  ///   mapq:     mapq,
-  ///   tlen:     tlen,
+  ///   anchor:           AnchorEnum::None,
+  ///   cigar:            CIGAR::load(cigar, position)
+  ///     .expect("CIGAR loading failed!"),
+  ///   chr:              chr.clone(),
+  ///   flag:             flag,
+  ///   position:         position,
+  ///   mapq:             mapq,
+  ///   tlen:             tlen,
+  ///   alignment_score:  None,
+  ///   suboptimal_score: None,
+  ///   ambiguous:        false,
   /// };
   ///
   /// assert_eq!(produced, manual);
@@ -192,7 +215,7 @@ impl ChrAnchor {
     chr: String,
     flag: i32,
     mapq: i32,
-    position: i32,
+    position: Position,
     tlen: i32,
   ) {
     // TODO: ignore anchor for now self.anchor = AnchorEnum::None;
@@ -207,9 +230,92 @@ impl ChrAnchor {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// score
+impl ChrAnchor {
+  /// Retrieve aligned length, i.e., matched bases on CIGAR.
+  pub fn aligned_length(&self) -> i32 {
+    self.cigar.align.iter().sum()
+  }
+
+  ///
+  /// Retrieve last aligned base on reference, i.e., position spanned by
+  /// matched & deleted bases.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   ChrAnchor,
+  ///   Position,
+  ///   CIGAR,
+  /// };
+  ///
+  /// let mut chr_anchor = ChrAnchor::new();
+  /// chr_anchor.position = Position::OneBased(101);
+  /// chr_anchor.cigar = CIGAR::load("10S40M2I5D48M", chr_anchor.position)
+  ///   .expect("CIGAR loading failed!");
+  ///
+  /// assert_eq!(chr_anchor.reference_end(), Position::OneBased(193));
+  /// ```
+  pub fn reference_end(&self) -> Position {
+    let deleted: i32 = self.cigar.deletion.iter().sum();
+    self.position + (self.aligned_length() + deleted) as u64 - 1
+  }
+
+  ///
+  /// Retrieve difference between alignment & suboptimal scores (AS - XS).
+  ///
+  /// # Returns
+  ///
+  /// Return score difference. Alignment score when no suboptimal alignment
+  /// is reported. `None` when alignment score is not reported.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::ChrAnchor;
+  ///
+  /// let mut chr_anchor = ChrAnchor::new();
+  /// assert_eq!(chr_anchor.score_gap(), None);
+  ///
+  /// chr_anchor.alignment_score = Some(100);
+  /// assert_eq!(chr_anchor.score_gap(), Some(100));
+  ///
+  /// chr_anchor.suboptimal_score = Some(95);
+  /// assert_eq!(chr_anchor.score_gap(), Some(5));
+  /// ```
+  pub fn score_gap(&self) -> Option<i32> {
+    self
+      .alignment_score
+      .map(|score| score - self.suboptimal_score.unwrap_or(0))
+  }
+
+  ///
+  /// Tag alignment as ambiguous.
+  ///
+  /// Alignments below minimum mapping quality, or with suboptimal score
+  /// equal to or above alignment score, are considered multi-mapped.
+  ///
+  /// # Parameters
+  ///
+  /// * `min_mapq` - Minimum mapping quality (MAPQ).
+  pub fn tag_ambiguous(
+    &mut self,
+    min_mapq: i32,
+  ) {
+    let multi_mapped = match (self.alignment_score, self.suboptimal_score) {
+      (Some(score), Some(suboptimal)) => suboptimal >= score,
+      (_, _) => false,
+    };
+    self.ambiguous = self.mapq < min_mapq || multi_mapped;
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // bin
 impl Anchor for ChrAnchor {
-  fn get_position(&self) -> i32 {
+  fn get_position(&self) -> Position {
     self.position
   }
 }
@@ -11,20 +11,12 @@
 // | partial [50S50M] (ME limit) {inwards}       | partial [50S50M]  (ME limit) {outwards}     |
 // |---------------------------------------------|---------------------------------------------|
 /// Define chromosomal anchor.
-#[derive(Debug, new, PartialEq)]
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
 pub enum ChrAnchorEnum {
   Read1,
   Read2,
+  #[default]
   None,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// manual default trait implementation
-impl Default for ChrAnchorEnum {
-  fn default() -> Self {
-    Self::None
-  }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,13 @@ use std::fmt;
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::error::common_error::CommonError;
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    position::Position,
+  },
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -27,17 +33,17 @@ pub struct CIGAR {
   #[new(default)]
   pub insertion: Vec<i32>,
 
-  /// Left boundry.
+  /// Left boundry, including left clip. Clamped at coordinate origin.
   #[new(default)]
-  pub left_boundry: i32,
+  pub left_boundry: Position,
 
   /// Left clip position coordinate.
   #[new(default)]
   pub left_clip: i32,
 
-  /// Right boundry.
+  /// Right boundry, including right clip.
   #[new(default)]
-  pub right_boundry: i32,
+  pub right_boundry: Position,
 
   /// Right clip position coordinate.
   #[new(default)]
@@ -59,7 +65,7 @@ impl CIGAR {
   ///
   /// * `to_interpret` - String to parse.
   ///
-  /// * `position` - 1-based coordinate.
+  /// * `position` - Alignment position.
   ///
   /// # Returns
   ///
@@ -68,24 +74,27 @@ impl CIGAR {
   /// # Examples
   ///
   /// ```
-  /// use genomic_structures::CIGAR;
+  /// use genomic_structures::{
+  ///   Position,
+  ///   CIGAR,
+  /// };
   ///
-  /// let cigar =
-  ///   CIGAR::load("10H1I2M2D80M5H", 101).expect("CIGAR loading failed!");
+  /// let cigar = CIGAR::load("10H1I2M2D80M5H", Position::OneBased(101))
+  ///   .expect("CIGAR loading failed!");
   /// assert_eq!(cigar, CIGAR {
   ///   align:         vec![2, 80],
   ///   deletion:      vec![2],
   ///   insertion:     vec![1],
-  ///   left_boundry:  91,
+  ///   left_boundry:  Position::OneBased(91),
   ///   left_clip:     10,
-  ///   right_boundry: 190,
+  ///   right_boundry: Position::OneBased(190),
   ///   rigth_clip:    5,
   ///   signature:     "10H1I2M2D80M5H".to_string(),
   /// });
   /// ```
   pub fn load(
     to_interpret: &str,
-    position: i32,
+    position: Position,
   ) -> anyResult<Self> {
     let mut cigar_out = Self::new();
     cigar_out.update(to_interpret, position)?;
@@ -99,7 +108,7 @@ impl CIGAR {
   ///
   /// * `to_interpret` - String to parse.
   ///
-  /// * `position` - 1-based coordinate.
+  /// * `position` - Alignment position.
   ///
   /// # Returns
   ///
@@ -108,20 +117,23 @@ impl CIGAR {
   /// # Examples
   ///
   /// ```
-  /// use genomic_structures::CIGAR;
+  /// use genomic_structures::{
+  ///   Position,
+  ///   CIGAR,
+  /// };
   ///
   /// let mut cigar = CIGAR::new();
   /// cigar
-  ///   .update("10H1I2M2D80M5H", 101)
+  ///   .update("10H1I2M2D80M5H", Position::OneBased(101))
   ///   .expect("CIGAR loading failed!");
   ///
   /// assert_eq!(cigar, CIGAR {
   ///   align:         vec![2, 80],
   ///   deletion:      vec![2],
   ///   insertion:     vec![1],
-  ///   left_boundry:  91,
+  ///   left_boundry:  Position::OneBased(91),
   ///   left_clip:     10,
-  ///   right_boundry: 190,
+  ///   right_boundry: Position::OneBased(190),
   ///   rigth_clip:    5,
   ///   signature:     "10H1I2M2D80M5H".to_string(),
   /// });
@@ -129,7 +141,7 @@ impl CIGAR {
   pub fn update(
     &mut self,
     to_interpret: &str,
-    position: i32,
+    position: Position,
   ) -> anyResult<()> {
     self.signature = to_interpret.to_string().clone();
     // identify no CIGAR annotation
@@ -140,7 +152,7 @@ impl CIGAR {
       let mut char_vec = vec![];
       for ix in to_interpret.char_indices() {
         match ix.1 {
-          'H' | 'S' | 'M' | 'D' | 'I' => {
+          'H' | 'S' | 'M' | '=' | 'X' | 'D' | 'N' | 'I' | 'P' => {
             char_vec.push(ix.0);
           }
           _ => (),
@@ -156,32 +168,34 @@ impl CIGAR {
           "H" | "S" => {
             // indicate whether aligned bases have been recorded
             if self.align.iter().sum::<i32>() == 0 {
-              self.left_clip = (&to_interpret[j..*i])
+              self.left_clip = to_interpret[j..*i]
                 .parse::<i32>()
                 .context(CommonError::Parsing)?;
             } else {
-              self.rigth_clip = (&to_interpret[j..*i])
+              self.rigth_clip = to_interpret[j..*i]
                 .parse::<i32>()
                 .context(CommonError::Parsing)?;
             }
           }
-          "M" => {
+          // sequence matches & mismatches are aligned
+          "M" | "=" | "X" => {
             self.align.push(
-              (&to_interpret[j..*i])
+              to_interpret[j..*i]
                 .parse::<i32>()
                 .context(CommonError::Parsing)?,
             );
           }
           "I" => {
             self.insertion.push(
-              (&to_interpret[j..*i])
+              to_interpret[j..*i]
                 .parse::<i32>()
                 .context(CommonError::Parsing)?,
             );
           }
-          "D" => {
+          // reference skips consume reference as deletions
+          "D" | "N" => {
             self.deletion.push(
-              (&to_interpret[j..*i])
+              to_interpret[j..*i]
                 .parse::<i32>()
                 .context(CommonError::Parsing)?,
             );
@@ -206,7 +220,7 @@ impl CIGAR {
   // both boundries
   fn calculate_boundries(
     &mut self,
-    position: i32,
+    position: Position,
   ) {
     self.left_boundry = self.calculate_left_boundry(position);
     self.right_boundry = self.calculate_right_boundry(position);
@@ -221,22 +235,111 @@ impl CIGAR {
     align_sum + ins_sum + del_sum
   }
 
+  // reference consumed by matched & deleted bases
+  fn reference_span(&self) -> i32 {
+    let align_sum: i32 = self.align.iter().sum();
+    let del_sum: i32 = self.deletion.iter().sum();
+    align_sum + del_sum
+  }
+
   // left boundry
   fn calculate_left_boundry(
     &self,
-    position: i32,
-  ) -> i32 {
-    position - self.left_clip
+    position: Position,
+  ) -> Position {
+    position - self.left_clip as u64
   }
 
   // right boundry
   fn calculate_right_boundry(
     &self,
-    position: i32,
+    position: Position,
+  ) -> Position {
+    // accomodate inclusive coordinate
+    position + (self.total_alignment() + self.rigth_clip) as u64 - 1
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// clip junction
+impl CIGAR {
+  ///
+  /// Calculate clip junction coordinate.
+  ///
+  /// Junction lies immediately before returned coordinate, i.e., first
+  /// aligned base on left clips & first base after alignment on right clips.
+  ///
+  /// # Parameters
+  ///
+  /// * `side` - Read clip side.
+  ///
+  /// # Returns
+  ///
+  /// Return junction coordinate. `None` on unclipped side.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   ClipSideEnum,
+  ///   Position,
+  ///   CIGAR,
+  /// };
+  ///
+  /// let cigar = CIGAR::load("10S80M10S", Position::OneBased(101))
+  ///   .expect("CIGAR loading failed!");
+  ///
+  /// assert_eq!(
+  ///   cigar.clip_junction(ClipSideEnum::Left),
+  ///   Some(Position::OneBased(101))
+  /// );
+  /// assert_eq!(
+  ///   cigar.clip_junction(ClipSideEnum::Right),
+  ///   Some(Position::OneBased(181))
+  /// );
+  /// assert_eq!(cigar.clip_junction(ClipSideEnum::None), None);
+  ///
+  /// // inserted bases do not consume reference
+  /// let cigar = CIGAR::load("40M2I48M10S", Position::OneBased(101))
+  ///   .expect("CIGAR loading failed!");
+  ///
+  /// assert_eq!(
+  ///   cigar.clip_junction(ClipSideEnum::Right),
+  ///   Some(Position::OneBased(189))
+  /// );
+  /// ```
+  pub fn clip_junction(
+    &self,
+    side: ClipSideEnum,
+  ) -> Option<Position> {
+    // left boundry may be clamped at coordinate origin
+    let aligned = (self.total_alignment() + self.rigth_clip) as u64;
+    let position = self.right_boundry - aligned + 1;
+    match side {
+      ClipSideEnum::Left if self.left_clip > 0 => Some(position),
+      ClipSideEnum::Right if self.rigth_clip > 0 => {
+        Some(position + self.reference_span() as u64)
+      }
+      _ => None,
+    }
+  }
+
+  ///
+  /// Retrieve clip length.
+  ///
+  /// # Parameters
+  ///
+  /// * `side` - Read clip side.
+  pub fn clip_length(
+    &self,
+    side: ClipSideEnum,
   ) -> i32 {
-    let leftmost = self.calculate_left_boundry(position);
-    // accomodate 1-based coordinate system
-    leftmost + (self.left_clip + self.total_alignment() + self.rigth_clip) - 1
+    match side {
+      ClipSideEnum::Left => self.left_clip,
+      ClipSideEnum::Right => self.rigth_clip,
+      ClipSideEnum::None => 0,
+    }
   }
 }
 
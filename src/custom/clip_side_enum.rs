@@ -0,0 +1,12 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label read clip side.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum ClipSideEnum {
+  Left,
+  Right,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
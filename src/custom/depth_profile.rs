@@ -0,0 +1,302 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  function::sequences::gc_content,
+  custom::{
+    indexed_fasta::IndexedFasta,
+    position::Position,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// bins fetched from reference at once on GC correction
+const GC_CHUNK: usize = 10_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Binned read depth along chromosomes.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct DepthProfile {
+  /// Bin size.
+  pub bin_size: u64,
+
+  /// Mean read depth per bin, keyed by chromosome.
+  #[new(default)]
+  pub bins: HashMap<String, Vec<f64>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & correct
+impl DepthProfile {
+  ///
+  /// Add aligned span onto overlapping bins.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First aligned base.
+  ///
+  /// * `end` - Last aligned base, inclusive.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   DepthProfile,
+  ///   Position,
+  /// };
+  ///
+  /// let mut depth_profile = DepthProfile::new(100);
+  /// depth_profile.add(
+  ///   "chr1",
+  ///   Position::OneBased(51),
+  ///   Position::OneBased(250),
+  /// );
+  ///
+  /// assert_eq!(depth_profile.bins["chr1"], vec![0.5, 1., 0.5]);
+  /// ```
+  pub fn add(
+    &mut self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) {
+    let start = start.one_based().max(1);
+    let end = end.one_based();
+    if end < start || self.bin_size == 0 {
+      return;
+    }
+    let bins = self.bins.entry(chr.to_string()).or_default();
+    let last = ((end - 1) / self.bin_size) as usize;
+    if bins.len() <= last {
+      bins.resize(last + 1, 0.);
+    }
+    let first = ((start - 1) / self.bin_size) as usize;
+    for (ix, bin) in bins.iter_mut().enumerate().take(last + 1).skip(first) {
+      let bin_start = ix as u64 * self.bin_size + 1;
+      let bin_end = bin_start + self.bin_size - 1;
+      let overlap = end.min(bin_end) - start.max(bin_start) + 1;
+      *bin += overlap as f64 / self.bin_size as f64;
+    }
+  }
+
+  ///
+  /// Correct depth for GC content bias.
+  ///
+  /// Bins are stratified by GC percent on reference. Each bin is scaled by
+  /// median depth over median depth on its stratum. Bins without GC content,
+  /// e.g., assembly gaps, remain unaltered.
+  ///
+  /// # Parameters
+  ///
+  /// * `reference` - Reference assembly.
+  ///
+  /// # Returns
+  ///
+  /// Error on chromosomes missing from reference.
+  pub fn gc_correct(
+    &mut self,
+    reference: &IndexedFasta,
+  ) -> anyResult<()> {
+    // GC percent per bin
+    let mut gc_bins: HashMap<String, Vec<Option<usize>>> = HashMap::new();
+    for (chr, bins) in self.bins.iter() {
+      let length = reference
+        .get_record(chr)
+        .context(CommonError::MissingSequence)?
+        .length as usize;
+      let bin_size = self.bin_size as usize;
+      let mut gc_percent = Vec::with_capacity(bins.len());
+      for chunk in (0..bins.len()).step_by(GC_CHUNK) {
+        let start = chunk * bin_size + 1;
+        let end = ((chunk + GC_CHUNK) * bin_size).min(length);
+        if start > end {
+          break;
+        }
+        let sequence = reference.fetch(
+          chr,
+          Position::OneBased(start as u64),
+          Position::OneBased(end as u64),
+        )?;
+        gc_percent.extend(sequence.as_bytes().chunks(bin_size).map(|bin| {
+          gc_content(&String::from_utf8_lossy(bin))
+            .map(|gc| (gc * 100.).round() as usize)
+        }));
+      }
+      gc_percent.resize(bins.len(), None);
+      gc_bins.insert(chr.clone(), gc_percent);
+    }
+
+    // median depth, overall & per stratum
+    let mut strata: HashMap<usize, Vec<f64>> = HashMap::new();
+    for (chr, bins) in self.bins.iter() {
+      for (depth, gc) in bins.iter().zip(gc_bins[chr].iter()) {
+        if let Some(gc) = gc {
+          strata.entry(*gc).or_default().push(*depth);
+        }
+      }
+    }
+    let overall = median(strata.values().flatten().copied().collect());
+    let strata = strata
+      .into_iter()
+      .map(|(gc, depths)| (gc, median(depths)))
+      .collect::<HashMap<usize, f64>>();
+
+    for (chr, bins) in self.bins.iter_mut() {
+      for (depth, gc) in bins.iter_mut().zip(gc_bins[chr].iter()) {
+        match gc.map(|gc| strata[&gc]) {
+          Some(stratum) if stratum > 0. => *depth *= overall / stratum,
+          _ => (),
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl DepthProfile {
+  ///
+  /// Retrieve mean depth over region.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return mean depth on bins overlapping region. `None` when region lies
+  /// beyond profiled bins.
+  pub fn depth(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> Option<f64> {
+    let bins = self.bins.get(chr)?;
+    let start = start.one_based().max(1);
+    let end = end.one_based();
+    if end < start || self.bin_size == 0 {
+      return None;
+    }
+    let first = ((start - 1) / self.bin_size) as usize;
+    let last = ((end - 1) / self.bin_size) as usize;
+    let last = last.min(bins.len().checked_sub(1)?);
+    if first > last {
+      return None;
+    }
+    let region = &bins[first..=last];
+    Some(region.iter().sum::<f64>() / region.len() as f64)
+  }
+
+  ///
+  /// Retrieve depth ratio of region against flanking regions.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// * `flank` - Flanking region length on each side.
+  ///
+  /// # Returns
+  ///
+  /// Return region over flanking depth. `None` when depth is missing or flanks
+  /// are not covered.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   DepthProfile,
+  ///   Position,
+  /// };
+  ///
+  /// let mut depth_profile = DepthProfile::new(100);
+  /// for start in (1..3000).step_by(50) {
+  ///   let start = Position::OneBased(start);
+  ///   depth_profile.add("chr1", start, start + 99);
+  /// }
+  /// // duplicated segment
+  /// for start in (1001..2000).step_by(100) {
+  ///   let start = Position::OneBased(start);
+  ///   depth_profile.add("chr1", start, start + 99);
+  /// }
+  ///
+  /// let depth_ratio = depth_profile
+  ///   .depth_ratio(
+  ///     "chr1",
+  ///     Position::OneBased(1001),
+  ///     Position::OneBased(2000),
+  ///     1000,
+  ///   )
+  ///   .expect("Depth ratio failed!");
+  /// assert!((depth_ratio - 1.5).abs() < 0.05);
+  /// ```
+  pub fn depth_ratio(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+    flank: u64,
+  ) -> Option<f64> {
+    let region = self.depth(chr, start, end)?;
+    let flanks = [
+      self.depth(chr, start - flank, start - 1),
+      self.depth(chr, end + 1, end + flank),
+    ]
+    .iter()
+    .flatten()
+    .copied()
+    .collect::<Vec<f64>>();
+    if flanks.is_empty() {
+      return None;
+    }
+    let flanking = flanks.iter().sum::<f64>() / flanks.len() as f64;
+    if flanking > 0. {
+      Some(region / flanking)
+    } else {
+      None
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// median, zero on empty values
+fn median(mut values: Vec<f64>) -> f64 {
+  if values.is_empty() {
+    return 0.;
+  }
+  values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+  let middle = values.len() / 2;
+  if values.len() % 2 == 0 {
+    (values[middle - 1] + values[middle]) / 2.
+  } else {
+    values[middle]
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,116 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::error::common_error::CommonError;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Detection parameters.
+///
+/// Missing values on configuration files fall back to crate defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DetectionConfig {
+  /// Distance from mobile element ends to tag anchor orientation.
+  pub me_limit: i32,
+
+  /// Distance from mobile element ends to tag mate anchor orientation.
+  pub anchor_limit: i32,
+
+  /// Position bin size.
+  pub bin_size: i32,
+
+  /// Position bin overlap.
+  pub bin_overlap: i32,
+
+  /// Distance between reads to call translocation.
+  pub translocation_distance: i32,
+
+  /// Sequence length to extract beyond break point.
+  pub breakpoint_flank: usize,
+
+  /// Minimum chromosomal anchor mapping quality (MAPQ).
+  pub min_mapq: i32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// default values
+impl DetectionConfig {
+  /// Default detection parameters, usable on constant contexts.
+  pub const DEFAULT: Self = Self {
+    me_limit:               200,
+    anchor_limit:           50,
+    bin_size:               100,
+    bin_overlap:            50,
+    translocation_distance: 1000,
+    breakpoint_flank:       10,
+    min_mapq:               20,
+  };
+}
+
+// default trait implementation
+impl Default for DetectionConfig {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl DetectionConfig {
+  ///
+  /// Parse `DetectionConfig` from TOML.
+  ///
+  /// # Parameters
+  ///
+  /// * `toml` - TOML formatted parameters.
+  ///
+  /// # Returns
+  ///
+  /// Return detection parameters. Error on malformed or unknown fields.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::DetectionConfig;
+  ///
+  /// let config = DetectionConfig::from_toml("me_limit = 150\nbin_size = 50\n")
+  ///   .expect("DetectionConfig parsing failed!");
+  ///
+  /// assert_eq!(config.me_limit, 150);
+  /// assert_eq!(config.bin_size, 50);
+  /// assert_eq!(config.anchor_limit, DetectionConfig::default().anchor_limit);
+  /// ```
+  pub fn from_toml(toml: &str) -> anyResult<Self> {
+    toml::from_str(toml).context(CommonError::Parsing)
+  }
+
+  ///
+  /// Load `DetectionConfig` from TOML file.
+  ///
+  /// # Parameters
+  ///
+  /// * `path` - TOML file path.
+  ///
+  /// # Returns
+  ///
+  /// Return detection parameters. Error on unreadable file, malformed or
+  /// unknown fields.
+  pub fn load<P: AsRef<Path>>(path: P) -> anyResult<Self> {
+    let toml = fs::read_to_string(path).context(CommonError::Reading)?;
+    Self::from_toml(&toml)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
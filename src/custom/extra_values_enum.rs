@@ -1,19 +1,11 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Annotate extra information.
-#[derive(Debug, new, PartialEq)]
+#[derive(Debug, new, Default, PartialEq)]
 pub enum ExtraValuesEnum {
   MobelSize(f64),
+  #[default]
   None,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// manual default trait implementation
-impl Default for ExtraValuesEnum {
-  fn default() -> Self {
-    Self::None
-  }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
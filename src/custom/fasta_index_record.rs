@@ -0,0 +1,122 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::error::common_error::CommonError;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// FASTA index (.fai) record.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct FastaIndexRecord {
+  /// Sequence name.
+  pub name: String,
+
+  /// Sequence length in bases.
+  #[new(default)]
+  pub length: u64,
+
+  /// Byte offset of first base on uncompressed file.
+  pub offset: u64,
+
+  /// Bases per line.
+  #[new(default)]
+  pub line_bases: u64,
+
+  /// Bytes per line, including line terminator.
+  #[new(default)]
+  pub line_width: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl FastaIndexRecord {
+  ///
+  /// Load `FastaIndexRecord` from .fai line.
+  ///
+  /// # Parameters
+  ///
+  /// * `line` - Tab separated name, length, offset, line bases & line width.
+  ///
+  /// # Returns
+  ///
+  /// Return index record. Error on malformed lines.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::FastaIndexRecord;
+  ///
+  /// let record = FastaIndexRecord::load("chr1\t248956422\t6\t60\t61")
+  ///   .expect("Index record loading failed!");
+  ///
+  /// assert_eq!(record, FastaIndexRecord {
+  ///   name:       "chr1".to_string(),
+  ///   length:     248956422,
+  ///   offset:     6,
+  ///   line_bases: 60,
+  ///   line_width: 61,
+  /// });
+  /// assert_eq!(record.to_string(), "chr1\t248956422\t6\t60\t61");
+  /// ```
+  pub fn load(line: &str) -> anyResult<Self> {
+    let flines = line.trim_end().split('\t').collect::<Vec<&str>>();
+    if flines.len() != 5 {
+      return Err(CommonError::Parsing.into());
+    }
+    let parse =
+      |field: &str| field.parse::<u64>().context(CommonError::Parsing);
+    Ok(Self {
+      name:       flines[0].to_string(),
+      length:     parse(flines[1])?,
+      offset:     parse(flines[2])?,
+      line_bases: parse(flines[3])?,
+      line_width: parse(flines[4])?,
+    })
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl FastaIndexRecord {
+  ///
+  /// Retrieve byte offset of base on uncompressed file.
+  ///
+  /// # Parameters
+  ///
+  /// * `base` - 0-based base coordinate.
+  pub fn byte_offset(
+    &self,
+    base: u64,
+  ) -> u64 {
+    self.offset +
+      (base / self.line_bases) * self.line_width +
+      base % self.line_bases
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as .fai line
+impl fmt::Display for FastaIndexRecord {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(
+      f,
+      "{}\t{}\t{}\t{}\t{}",
+      self.name, self.length, self.offset, self.line_bases, self.line_width
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
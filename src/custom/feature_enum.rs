@@ -0,0 +1,39 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label gene feature hit by call. Variants are ordered by precedence on
+/// calls hitting several features.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FeatureEnum {
+  UTR,
+  Exon,
+  Intron,
+  Promoter,
+  #[default]
+  Intergenic,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF FEATURE
+impl fmt::Display for FeatureEnum {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    let feature = match self {
+      FeatureEnum::UTR => "UTR",
+      FeatureEnum::Exon => "exon",
+      FeatureEnum::Intron => "intron",
+      FeatureEnum::Promoter => "promoter",
+      FeatureEnum::Intergenic => "intergenic",
+    };
+    write!(f, "{}", feature)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
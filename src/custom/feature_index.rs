@@ -0,0 +1,323 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::collections::{
+  BTreeMap,
+  HashMap,
+};
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    feature_enum::FeatureEnum,
+    gene_annotation::GeneAnnotation,
+    genomic_interval::GenomicInterval,
+    interval_index::IntervalIndex,
+    position::Position,
+    strand_enum::StrandEnum,
+  },
+  error::common_error::CommonError,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// deepest GFF3 parent chain followed up to gene
+const MAX_DEPTH: usize = 8;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Gene features per chromosome loaded from GTF or GFF3, indexed to annotate
+/// calls.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct FeatureIndex {
+  /// Gene spans carrying gene name & strand.
+  #[new(default)]
+  pub genes: IntervalIndex<(String, StrandEnum)>,
+
+  /// Exons & UTRs carrying gene name.
+  #[new(default)]
+  pub features: IntervalIndex<(String, FeatureEnum)>,
+
+  /// Promoter length upstream of gene start.
+  #[new(value = "2000")]
+  pub promoter: u64,
+}
+
+// GTF / GFF3 record
+struct FeatureRecord {
+  chr: String,
+  kind: String,
+  start: Position,
+  end: Position,
+  strand: StrandEnum,
+  attributes: HashMap<String, String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl FeatureIndex {
+  ///
+  /// Load GTF or GFF3 annotation.
+  ///
+  /// Genes span every feature assigned to them, i.e., by `gene_id` on GTF, or
+  /// through `Parent` up to gene records on GFF3, keyed by gene `ID`. Loci
+  /// sharing a gene name are kept apart, the name being only the label. Exons
+  /// & UTRs are indexed as features. Comments are skipped & embedded GFF3
+  /// sequences are ignored.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - GTF or GFF3 records.
+  ///
+  /// # Returns
+  ///
+  /// Return indexed features. Error on malformed records.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   FeatureEnum,
+  ///   FeatureIndex,
+  ///   Position,
+  /// };
+  ///
+  /// let gtf = "chr1\tsrc\texon\t1001\t1200\t.\t+\t.\tgene_id \"G1\"; \
+  ///            gene_name \"TP53\";\n\
+  ///            chr1\tsrc\texon\t2001\t2500\t.\t+\t.\tgene_id \"G1\"; \
+  ///            gene_name \"TP53\";\n";
+  /// let feature_index =
+  ///   FeatureIndex::load(gtf.as_bytes()).expect("Annotation loading failed!");
+  ///
+  /// let gene_annotation = feature_index
+  ///   .annotate("chr1", Position::OneBased(1500), Position::OneBased(1500))
+  ///   .expect("Annotation missing!");
+  /// assert_eq!(gene_annotation.gene, "TP53");
+  /// assert_eq!(gene_annotation.feature, FeatureEnum::Intron);
+  /// ```
+  pub fn load<R: BufRead>(reader: R) -> anyResult<Self> {
+    let mut records = Vec::new();
+    for line in reader.lines() {
+      let line = line.context(CommonError::Reading)?;
+      if line.starts_with("##FASTA") {
+        break;
+      }
+      if line.trim().is_empty() || line.starts_with('#') {
+        continue;
+      }
+      records.push(FeatureRecord::load(&line)?);
+    }
+
+    let ids = records
+      .iter()
+      .filter_map(|record| {
+        Some((record.attributes.get("ID")?.as_str(), record))
+      })
+      .collect::<HashMap<&str, &FeatureRecord>>();
+
+    // gene spans over assigned features, covering GTF without gene records
+    let mut spans: BTreeMap<
+      (String, String),
+      (Position, Position, StrandEnum, String),
+    > = BTreeMap::new();
+    let mut feature_index = Self::new();
+    for record in records.iter() {
+      let (key, gene) = match record.gene(&ids) {
+        Some(gene) => gene,
+        None => continue,
+      };
+      let span = spans
+        .entry((record.chr.clone(), key))
+        .or_insert((record.start, record.end, record.strand, gene.clone()));
+      span.0 = span.0.min(record.start);
+      span.1 = span.1.max(record.end);
+      if let Some(feature) = record.feature() {
+        feature_index.features.insert(
+          GenomicInterval::new(record.chr.clone(), record.start, record.end),
+          (gene, feature),
+        );
+      }
+    }
+    for ((chr, _), (start, end, strand, gene)) in spans {
+      feature_index
+        .genes
+        .insert(GenomicInterval::new(chr, start, end), (gene, strand));
+    }
+
+    feature_index.genes.index();
+    feature_index.features.index();
+    Ok(feature_index)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// annotate
+impl FeatureIndex {
+  ///
+  /// Annotate span with gene feature.
+  ///
+  /// Spans hitting several features are labeled by precedence, i.e., UTR,
+  /// exon, intron. Spans outside genes are labeled promoter within promoter
+  /// length upstream of a gene start, or intergenic against the nearest gene.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return gene annotation. `None` on chromosomes without genes.
+  pub fn annotate(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> Option<GeneAnnotation> {
+    let hit = self
+      .features
+      .overlaps(chr, start, end)
+      .into_iter()
+      .min_by_key(|(_, (_, feature))| *feature);
+    if let Some((_, (gene, feature))) = hit {
+      return Some(GeneAnnotation::new(gene.clone(), *feature, 0));
+    }
+
+    if let Some((_, (gene, _))) = self.genes.overlaps(chr, start, end).first() {
+      return Some(GeneAnnotation::new(gene.clone(), FeatureEnum::Intron, 0));
+    }
+
+    // gene start downstream on gene strand
+    let promoter = self
+      .genes
+      .window(chr, start, end, self.promoter)
+      .into_iter()
+      .filter_map(|(interval, (gene, strand))| {
+        let distance = match strand {
+          StrandEnum::Forward if interval.start > end => interval.start - end,
+          StrandEnum::Reverse if interval.end < start => start - interval.end,
+          _ => return None,
+        };
+        Some((distance as u64, gene))
+      })
+      .min_by_key(|(distance, _)| *distance);
+    if let Some((distance, gene)) = promoter {
+      return Some(GeneAnnotation::new(
+        gene.clone(),
+        FeatureEnum::Promoter,
+        distance,
+      ));
+    }
+
+    let (interval, (gene, _)) = self.genes.nearest(chr, start, end)?;
+    let distance =
+      interval.distance(&GenomicInterval::new(chr.to_string(), start, end))?;
+    Some(GeneAnnotation::new(
+      gene.clone(),
+      FeatureEnum::Intergenic,
+      distance,
+    ))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FeatureRecord {
+  // parse tab separated record, GTF or GFF3 attributes
+  fn load(line: &str) -> anyResult<Self> {
+    let flines = line.split('\t').collect::<Vec<&str>>();
+    if flines.len() < 9 {
+      return Err(CommonError::Parsing.into());
+    }
+    let strand = match flines[6] {
+      "+" => StrandEnum::Forward,
+      "-" => StrandEnum::Reverse,
+      _ => StrandEnum::None,
+    };
+    Ok(Self {
+      chr: flines[0].to_string(),
+      kind: flines[2].to_string(),
+      start: Position::OneBased(
+        flines[3].parse::<u64>().context(CommonError::Parsing)?,
+      ),
+      end: Position::OneBased(
+        flines[4].parse::<u64>().context(CommonError::Parsing)?,
+      ),
+      strand,
+      attributes: attributes(flines[8]),
+    })
+  }
+
+  // indexed feature type
+  fn feature(&self) -> Option<FeatureEnum> {
+    let kind = self.kind.to_lowercase();
+    if kind == "exon" {
+      Some(FeatureEnum::Exon)
+    } else if kind.contains("utr") {
+      Some(FeatureEnum::UTR)
+    } else {
+      None
+    }
+  }
+
+  // gene key & name, following GFF3 parents up to gene record. genes are
+  // keyed by ID, or gene_id on GTF, since names are shared across loci
+  fn gene(
+    &self,
+    ids: &HashMap<&str, &FeatureRecord>,
+  ) -> Option<(String, String)> {
+    let mut current = self;
+    for _ in 0..MAX_DEPTH {
+      if current.kind.contains("gene") {
+        break;
+      }
+      match current
+        .attributes
+        .get("Parent")
+        .and_then(|parent| ids.get(parent.split(',').next()?))
+      {
+        Some(parent) => current = parent,
+        None => break,
+      }
+    }
+    let (record, keys): (&Self, &[&str]) = if current.kind.contains("gene") {
+      (current, &["ID", "gene_id", "gene_name", "Name"])
+    } else {
+      (self, &["gene_id", "gene_name"])
+    };
+    let key = keys.iter().find_map(|key| record.attributes.get(*key))?;
+    let name = ["gene_name", "Name", "gene"]
+      .iter()
+      .find_map(|key| record.attributes.get(*key))
+      .unwrap_or(key);
+    Some((key.clone(), name.clone()))
+  }
+}
+
+// attributes as GTF `key "value";` or GFF3 `key=value;`
+fn attributes(column: &str) -> HashMap<String, String> {
+  column
+    .split(';')
+    .filter_map(|attribute| {
+      let attribute = attribute.trim();
+      let separator = attribute.find([' ', '='])?;
+      let (key, value) = attribute.split_at(separator);
+      Some((
+        key.to_string(),
+        value[1..].trim().trim_matches('"').to_string(),
+      ))
+    })
+    .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,42 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::feature_enum::FeatureEnum;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Gene feature hit by call, or nearest gene on intergenic calls.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct GeneAnnotation {
+  /// Gene name, or gene ID when unnamed.
+  pub gene: String,
+
+  /// Feature hit by call.
+  pub feature: FeatureEnum,
+
+  /// Distance to gene. Zero on calls within gene.
+  pub distance: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF INFO
+impl fmt::Display for GeneAnnotation {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(
+      f,
+      "GENE={};FEATURE={};GENEDIST={}",
+      self.gene, self.feature, self.distance
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
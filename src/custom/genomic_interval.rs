@@ -0,0 +1,107 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::position::Position;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genomic range on a chromosome. 1-based, inclusive.
+#[derive(Debug, new, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GenomicInterval {
+  /// Chromosomal allocation.
+  pub chr: String,
+
+  /// First base.
+  pub start: Position,
+
+  /// Last base, inclusive.
+  pub end: Position,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl GenomicInterval {
+  /// Retrieve interval length. Zero on empty intervals.
+  pub fn length(&self) -> u64 {
+    (self.end - self.start + 1).max(0) as u64
+  }
+
+  /// Check whether interval contains position.
+  pub fn contains(
+    &self,
+    position: Position,
+  ) -> bool {
+    self.start <= position && position <= self.end
+  }
+
+  /// Check whether intervals share at least one base.
+  pub fn overlaps(
+    &self,
+    other: &GenomicInterval,
+  ) -> bool {
+    self.chr == other.chr && self.start <= other.end && other.start <= self.end
+  }
+
+  ///
+  /// Retrieve distance between intervals.
+  ///
+  /// # Parameters
+  ///
+  /// * `other` - Interval to compare against.
+  ///
+  /// # Returns
+  ///
+  /// Return distance between closest ends, zero on overlapping intervals.
+  /// None on different chromosomes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   GenomicInterval,
+  ///   Position,
+  /// };
+  ///
+  /// let interval = |chr: &str, start, end| {
+  ///   GenomicInterval::new(
+  ///     chr.to_string(),
+  ///     Position::OneBased(start),
+  ///     Position::OneBased(end),
+  ///   )
+  /// };
+  /// let first = interval("chr1", 100, 200);
+  ///
+  /// assert_eq!(first.distance(&interval("chr1", 250, 300)), Some(50));
+  /// assert_eq!(first.distance(&interval("chr1", 150, 300)), Some(0));
+  /// assert_eq!(first.distance(&interval("chr2", 100, 200)), None);
+  /// ```
+  pub fn distance(
+    &self,
+    other: &GenomicInterval,
+  ) -> Option<u64> {
+    if self.chr != other.chr {
+      return None;
+    }
+    Some((other.start - self.end).max(self.start - other.end).max(0) as u64)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as region string
+impl fmt::Display for GenomicInterval {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(f, "{}:{}-{}", self.chr, self.start, self.end)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
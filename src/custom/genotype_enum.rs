@@ -0,0 +1,36 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Biallelic genotype.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum GenotypeEnum {
+  HomozygousReference,
+  Heterozygous,
+  HomozygousAlternate,
+  #[default]
+  NoCall,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF GT
+impl fmt::Display for GenotypeEnum {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    let genotype = match self {
+      GenotypeEnum::HomozygousReference => "0/0",
+      GenotypeEnum::Heterozygous => "0/1",
+      GenotypeEnum::HomozygousAlternate => "1/1",
+      GenotypeEnum::NoCall => "./.",
+    };
+    write!(f, "{}", genotype)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,326 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use flate2::read::MultiGzDecoder;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{
+  self,
+  BufReader,
+  BufWriter,
+  Read,
+  Seek,
+  SeekFrom,
+  Write,
+};
+use std::path::{
+  Path,
+  PathBuf,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  function::fasta_indexer::{
+    index_bgzf,
+    index_fasta,
+    is_bgzf,
+    load_fai,
+    load_gzi,
+    write_fai,
+    write_gzi,
+  },
+  custom::{
+    fasta_index_record::FastaIndexRecord,
+    position::Position,
+    region::Region,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Random access reference FASTA, plain or BGZF compressed.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct IndexedFasta {
+  /// FASTA path.
+  pub path: PathBuf,
+
+  /// Sequence index (.fai) records.
+  #[new(default)]
+  pub records: Vec<FastaIndexRecord>,
+
+  /// BGZF block index (.gzi). `None` on uncompressed FASTA.
+  #[new(default)]
+  pub blocks: Option<Vec<(u64, u64)>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & index
+impl IndexedFasta {
+  ///
+  /// Open indexed FASTA.
+  ///
+  /// Existing .fai & .gzi files next to FASTA are loaded. Otherwise, indexes
+  /// are built in memory, see `IndexedFasta::index` to write them out.
+  ///
+  /// # Parameters
+  ///
+  /// * `path` - FASTA path, optionally bgzip compressed.
+  ///
+  /// # Returns
+  ///
+  /// Return indexed FASTA. Error on unreadable or malformed files.
+  pub fn open<P: AsRef<Path>>(path: P) -> anyResult<Self> {
+    let mut indexed_fasta = Self::new(path.as_ref().to_path_buf());
+    let compressed = is_bgzf(&leading_bytes(&indexed_fasta.path)?);
+
+    let gzi = extend_path(&indexed_fasta.path, "gzi");
+    if compressed {
+      indexed_fasta.blocks = Some(if gzi.exists() {
+        load_gzi(open_file(&gzi)?)?
+      } else {
+        index_bgzf(open_file(&indexed_fasta.path)?)?
+      });
+    }
+
+    let fai = extend_path(&indexed_fasta.path, "fai");
+    indexed_fasta.records = if fai.exists() {
+      load_fai(open_file(&fai)?)?
+    } else {
+      index_fasta(BufReader::new(indexed_fasta.stream()?))?
+    };
+    Ok(indexed_fasta)
+  }
+
+  ///
+  /// Index FASTA & write .fai, plus .gzi on BGZF compressed files.
+  ///
+  /// # Parameters
+  ///
+  /// * `path` - FASTA path, optionally bgzip compressed.
+  ///
+  /// # Returns
+  ///
+  /// Return indexed FASTA.
+  pub fn index<P: AsRef<Path>>(path: P) -> anyResult<Self> {
+    let mut indexed_fasta = Self::new(path.as_ref().to_path_buf());
+    if is_bgzf(&leading_bytes(&indexed_fasta.path)?) {
+      let blocks = index_bgzf(open_file(&indexed_fasta.path)?)?;
+      let gzi = extend_path(&indexed_fasta.path, "gzi");
+      let mut writer =
+        BufWriter::new(File::create(gzi).context(CommonError::Writing)?);
+      write_gzi(&mut writer, &blocks)?;
+      writer.flush().context(CommonError::Writing)?;
+      indexed_fasta.blocks = Some(blocks);
+    }
+
+    indexed_fasta.records =
+      index_fasta(BufReader::new(indexed_fasta.stream()?))?;
+    let fai = extend_path(&indexed_fasta.path, "fai");
+    let mut writer =
+      BufWriter::new(File::create(fai).context(CommonError::Writing)?);
+    write_fai(&mut writer, &indexed_fasta.records)?;
+    writer.flush().context(CommonError::Writing)?;
+    Ok(indexed_fasta)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fetch
+impl IndexedFasta {
+  ///
+  /// Fetch subsequence.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Sequence name.
+  ///
+  /// * `start` - First base, as `ChrAnchor::position`.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return bases as stored on FASTA. Error on unknown sequence or
+  /// coordinates out of sequence bounds.
+  pub fn fetch(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> anyResult<String> {
+    self.fetch_span(&Region::one_based(
+      chr,
+      start.one_based(),
+      end.one_based(),
+    ))
+  }
+
+  ///
+  /// Fetch subsequence spanning region.
+  ///
+  /// # Parameters
+  ///
+  /// * `region` - Region to fetch.
+  ///
+  /// # Returns
+  ///
+  /// Return bases as stored on FASTA. Error on unknown sequence or
+  /// coordinates out of sequence bounds.
+  pub fn fetch_span(
+    &self,
+    region: &Region,
+  ) -> anyResult<String> {
+    let record = self
+      .get_record(&region.chr)
+      .context(CommonError::MissingSequence)?;
+    let (start, end) = region.span();
+    if start < 1 || end < start || end > record.length {
+      return Err(CommonError::OutOfBounds.into());
+    }
+
+    // byte span on uncompressed file
+    let first = record.byte_offset(start - 1);
+    let last = record.byte_offset(end - 1);
+    let mut bytes = vec![0; (last - first + 1) as usize];
+    self.read_at(first, &mut bytes)?;
+
+    bytes.retain(|byte| *byte != b'\n' && *byte != b'\r');
+    String::from_utf8(bytes).context(CommonError::Parsing)
+  }
+
+  ///
+  /// Fetch subsequence by region.
+  ///
+  /// # Parameters
+  ///
+  /// * `region` - Region as `chr:start-end`, 1-based inclusive. Sequence name
+  ///   alone fetches the whole sequence.
+  ///
+  /// # Returns
+  ///
+  /// Return bases as stored on FASTA. Error on malformed region, unknown
+  /// sequence or coordinates out of sequence bounds.
+  pub fn fetch_region(
+    &self,
+    region: &str,
+  ) -> anyResult<String> {
+    match region.rsplit_once(':') {
+      Some((chr, _)) if self.get_record(chr).is_some() => {
+        self.fetch_span(&region.parse::<Region>()?)
+      }
+      _ => {
+        let record =
+          self.get_record(region).context(CommonError::MissingSequence)?;
+        self.fetch_span(&Region::one_based(region, 1, record.length))
+      }
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl IndexedFasta {
+  /// Retrieve index record by sequence name.
+  pub fn get_record(
+    &self,
+    chr: &str,
+  ) -> Option<&FastaIndexRecord> {
+    self.records.iter().find(|record| record.name == chr)
+  }
+
+  /// Retrieve sequence lengths, as chromosome sizes for `threshold`.
+  pub fn contig_lengths(&self) -> HashMap<String, f64> {
+    self
+      .records
+      .iter()
+      .map(|record| (record.name.clone(), record.length as f64))
+      .collect()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// read
+impl IndexedFasta {
+  // uncompressed stream from file start
+  fn stream(&self) -> anyResult<Box<dyn Read>> {
+    let file = File::open(&self.path).context(CommonError::Reading)?;
+    Ok(match self.blocks {
+      Some(_) => Box::new(MultiGzDecoder::new(BufReader::new(file))),
+      None => Box::new(file),
+    })
+  }
+
+  // read bytes at uncompressed offset
+  fn read_at(
+    &self,
+    offset: u64,
+    bytes: &mut [u8],
+  ) -> anyResult<()> {
+    let mut file = File::open(&self.path).context(CommonError::Reading)?;
+    match &self.blocks {
+      None => {
+        file.seek(SeekFrom::Start(offset)).context(CommonError::Reading)?;
+        file.read_exact(bytes).context(CommonError::Reading)
+      }
+      Some(blocks) => {
+        // closest block opening before offset. first block is implicit
+        let (compressed, uncompressed) = blocks
+          .iter()
+          .take_while(|(_, uncompressed)| *uncompressed <= offset)
+          .last()
+          .copied()
+          .unwrap_or((0, 0));
+        file
+          .seek(SeekFrom::Start(compressed))
+          .context(CommonError::Reading)?;
+        let mut decoder = MultiGzDecoder::new(BufReader::new(file));
+        io::copy(
+          &mut (&mut decoder).take(offset - uncompressed),
+          &mut io::sink(),
+        )
+        .context(CommonError::Reading)?;
+        decoder.read_exact(bytes).context(CommonError::Reading)
+      }
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// buffered file reader
+fn open_file(path: &Path) -> anyResult<BufReader<File>> {
+  Ok(BufReader::new(File::open(path).context(CommonError::Reading)?))
+}
+
+// leading bytes to detect compression
+fn leading_bytes(path: &Path) -> anyResult<Vec<u8>> {
+  let mut bytes = Vec::new();
+  File::open(path)
+    .context(CommonError::Reading)?
+    .take(4)
+    .read_to_end(&mut bytes)
+    .context(CommonError::Reading)?;
+  Ok(bytes)
+}
+
+// index path next to FASTA, e.g., reference.fa.fai
+fn extend_path(
+  path: &Path,
+  extension: &str,
+) -> PathBuf {
+  let mut extended = path.as_os_str().to_owned();
+  extended.push(".");
+  extended.push(extension);
+  PathBuf::from(extended)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,379 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  genomic_interval::GenomicInterval,
+  position::Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// subtrees at or below this level are scanned linearly
+const SCAN_LEVEL: usize = 3;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genomic intervals carrying values, indexed per chromosome for overlap,
+/// nearest-neighbor & window queries.
+///
+/// Intervals are kept sorted by start on an implicit interval tree, i.e.,
+/// the sorted array is read as a binary tree augmented with the furthest end
+/// on each subtree. Call `index` after insertion & before queries.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct IntervalIndex<T> {
+  /// Interval trees per chromosome.
+  #[new(default)]
+  trees: HashMap<String, IntervalTree<T>>,
+}
+
+// implicit interval tree on a single chromosome
+#[derive(Debug, Clone, PartialEq)]
+struct IntervalTree<T> {
+  // intervals sorted by start
+  intervals: Vec<(GenomicInterval, T)>,
+
+  // furthest end on subtree rooted at each node
+  max_end: Vec<Position>,
+
+  // interval reaching furthest up to each node, in start order
+  furthest: Vec<usize>,
+
+  // root level, i.e., trailing ones on root index
+  level: usize,
+
+  // whether tree is built after last insertion
+  indexed: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & update
+impl<T> IntervalIndex<T> {
+  ///
+  /// Insert interval. Index is invalidated until `index` is called.
+  ///
+  /// # Parameters
+  ///
+  /// * `interval` - Genomic interval. Empty intervals are discarded.
+  ///
+  /// * `value` - Value carried by interval.
+  pub fn insert(
+    &mut self,
+    interval: GenomicInterval,
+    value: T,
+  ) {
+    if interval.end < interval.start {
+      return;
+    }
+    let tree = self
+      .trees
+      .entry(interval.chr.clone())
+      .or_insert_with(IntervalTree::new);
+    tree.intervals.push((interval, value));
+    tree.indexed = false;
+  }
+
+  /// Sort intervals & build trees on chromosomes updated since last call.
+  pub fn index(&mut self) {
+    for tree in self.trees.values_mut().filter(|tree| !tree.indexed) {
+      tree.build();
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl<T> IntervalIndex<T> {
+  /// Retrieve interval count.
+  pub fn len(&self) -> usize {
+    self.trees.values().map(|tree| tree.intervals.len()).sum()
+  }
+
+  /// Check whether index holds no intervals.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  ///
+  /// Retrieve intervals overlapping query.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return overlapping intervals sorted by start.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   GenomicInterval,
+  ///   IntervalIndex,
+  ///   Position,
+  /// };
+  ///
+  /// let interval = |start, end| {
+  ///   GenomicInterval::new(
+  ///     "chr1".to_string(),
+  ///     Position::OneBased(start),
+  ///     Position::OneBased(end),
+  ///   )
+  /// };
+  ///
+  /// let mut interval_index = IntervalIndex::new();
+  /// interval_index.insert(interval(1, 900), 1);
+  /// interval_index.insert(interval(50, 60), 2);
+  /// interval_index.insert(interval(70, 80), 3);
+  /// interval_index.index();
+  ///
+  /// let values = interval_index
+  ///   .overlaps("chr1", Position::OneBased(55), Position::OneBased(75))
+  ///   .iter()
+  ///   .map(|(_, value)| *value)
+  ///   .collect::<Vec<i32>>();
+  /// assert_eq!(values, vec![1, 2, 3]);
+  /// ```
+  pub fn overlaps(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> Vec<&(GenomicInterval, T)> {
+    match self.trees.get(chr) {
+      Some(tree) => tree.overlaps(start, end),
+      None => Vec::new(),
+    }
+  }
+
+  ///
+  /// Retrieve intervals overlapping query extended by flank on both sides.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// * `flank` - Bases added around query.
+  ///
+  /// # Returns
+  ///
+  /// Return intervals within window sorted by start.
+  pub fn window(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+    flank: u64,
+  ) -> Vec<&(GenomicInterval, T)> {
+    self.overlaps(chr, start - flank, end + flank)
+  }
+
+  ///
+  /// Retrieve interval closest to query.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base.
+  ///
+  /// * `end` - Last base, inclusive.
+  ///
+  /// # Returns
+  ///
+  /// Return leftmost overlapping interval, or closest interval otherwise.
+  /// Upstream interval is preferred on ties. None on chromosomes without
+  /// intervals.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   GenomicInterval,
+  ///   IntervalIndex,
+  ///   Position,
+  /// };
+  ///
+  /// let interval = |start, end| {
+  ///   GenomicInterval::new(
+  ///     "chr1".to_string(),
+  ///     Position::OneBased(start),
+  ///     Position::OneBased(end),
+  ///   )
+  /// };
+  ///
+  /// let mut interval_index = IntervalIndex::new();
+  /// interval_index.insert(interval(100, 200), "upstream");
+  /// interval_index.insert(interval(400, 500), "downstream");
+  /// interval_index.index();
+  ///
+  /// let nearest = interval_index.nearest(
+  ///   "chr1",
+  ///   Position::OneBased(320),
+  ///   Position::OneBased(330),
+  /// );
+  /// assert_eq!(nearest.map(|(_, value)| *value), Some("downstream"));
+  /// ```
+  pub fn nearest(
+    &self,
+    chr: &str,
+    start: Position,
+    end: Position,
+  ) -> Option<&(GenomicInterval, T)> {
+    self.trees.get(chr)?.nearest(start, end)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> IntervalTree<T> {
+  fn new() -> Self {
+    Self {
+      intervals: Vec::new(),
+      max_end: Vec::new(),
+      furthest: Vec::new(),
+      level: 0,
+      indexed: false,
+    }
+  }
+
+  // sort intervals & augment implicit tree with furthest ends
+  fn build(&mut self) {
+    self.intervals.sort_by(|a, b| a.0.cmp(&b.0));
+    self.max_end = self
+      .intervals
+      .iter()
+      .map(|(interval, _)| interval.end)
+      .collect();
+    self.furthest = Vec::with_capacity(self.intervals.len());
+    for ix in 0..self.intervals.len() {
+      let reach = match self.furthest.last() {
+        Some(&jx) if self.max_end[jx] >= self.max_end[ix] => jx,
+        _ => ix,
+      };
+      self.furthest.push(reach);
+    }
+    self.level = 0;
+    self.indexed = true;
+
+    let size = self.intervals.len();
+    if size == 0 {
+      return;
+    }
+
+    // leaves are even indexes, internal nodes at level k carry k trailing ones
+    let mut last_ix = (size - 1) & !1;
+    let mut last = self.max_end[last_ix];
+    let mut level = 1;
+    while 1 << level <= size {
+      let half = 1 << (level - 1);
+      let mut ix = (half << 1) - 1;
+      while ix < size {
+        let left = self.max_end[ix - half];
+        let right = if ix + half < size {
+          self.max_end[ix + half]
+        } else {
+          last
+        };
+        self.max_end[ix] = self.intervals[ix].0.end.max(left).max(right);
+        ix += half << 2;
+      }
+      last_ix = if (last_ix >> level) & 1 == 1 {
+        last_ix - half
+      } else {
+        last_ix + half
+      };
+      if last_ix < size && self.max_end[last_ix] > last {
+        last = self.max_end[last_ix];
+      }
+      level += 1;
+    }
+    self.level = level - 1;
+  }
+
+  // top-down traversal pruning subtrees ending before query
+  fn overlaps(
+    &self,
+    start: Position,
+    end: Position,
+  ) -> Vec<&(GenomicInterval, T)> {
+    debug_assert!(self.indexed, "interval index queried before indexing");
+    let size = self.intervals.len();
+    let mut overlaps = Vec::new();
+    if size == 0 {
+      return overlaps;
+    }
+
+    // node index, level & whether left subtree is visited
+    let mut stack = vec![((1 << self.level) - 1, self.level, false)];
+    while let Some((ix, level, visited)) = stack.pop() {
+      if level <= SCAN_LEVEL {
+        let first = ix >> level << level;
+        let last = (first + (1 << (level + 1)) - 1).min(size);
+        overlaps.extend(
+          self.intervals[first.min(size)..last]
+            .iter()
+            .take_while(|(interval, _)| interval.start <= end)
+            .filter(|(interval, _)| interval.end >= start),
+        );
+      } else if !visited {
+        stack.push((ix, level, true));
+        let left = ix - (1 << (level - 1));
+        if left >= size || self.max_end[left] >= start {
+          stack.push((left, level - 1, false));
+        }
+      } else if ix < size && self.intervals[ix].0.start <= end {
+        if self.intervals[ix].0.end >= start {
+          overlaps.push(&self.intervals[ix]);
+        }
+        stack.push((ix + (1 << (level - 1)), level - 1, false));
+      }
+    }
+    overlaps
+  }
+
+  // closest interval on either side when none overlaps
+  fn nearest(
+    &self,
+    start: Position,
+    end: Position,
+  ) -> Option<&(GenomicInterval, T)> {
+    if let Some(overlap) = self.overlaps(start, end).first() {
+      return Some(overlap);
+    }
+    let next = self
+      .intervals
+      .partition_point(|(interval, _)| interval.start <= end);
+    let upstream = next
+      .checked_sub(1)
+      .map(|ix| &self.intervals[self.furthest[ix]]);
+    let downstream = self.intervals.get(next);
+    match (upstream, downstream) {
+      (Some(up), Some(down)) => {
+        if start - up.0.end <= down.0.start - end {
+          Some(up)
+        } else {
+          Some(down)
+        }
+      }
+      (up, down) => up.or(down),
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
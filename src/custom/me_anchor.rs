@@ -1,6 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // standard libraries
+use anyhow::Result as anyResult;
 use std::fmt;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,13 +12,11 @@ use crate::{
   custom::{
     break_point::BreakPoint,
     cigar::CIGAR,
+    detection_config::DetectionConfig,
     orientation_enum::OrientationEnum,
+    position::Position,
   },
 };
-use crate::{
-  ANCHOR_LIMIT,
-  ME_LIMIT,
-};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -46,7 +45,7 @@ pub struct MEAnchor {
 
   /// Alignment position.
   #[new(default)]
-  pub position: i32,
+  pub position: Position,
 
   /// Mobile element size.
   #[new(default)]
@@ -85,6 +84,7 @@ impl MEAnchor {
   ///   BreakPoint,
   ///   MEAnchor,
   ///   OrientationEnum,
+  ///   Position,
   ///   CIGAR,
   /// };
   ///
@@ -92,7 +92,7 @@ impl MEAnchor {
   /// let flag = 75;
   /// let mobel = "mobel77".to_string();
   /// let orientation = OrientationEnum::None;
-  /// let position = 2099;
+  /// let position = Position::OneBased(2099);
   /// let size = 11000.;
   ///
   /// let produced = MEAnchor::load(
@@ -121,7 +121,7 @@ impl MEAnchor {
     flag: i32,
     mobel: String,
     orientation: OrientationEnum,
-    position: i32,
+    position: Position,
     size: f64,
   ) -> Self {
     let mut me_anchor = Self::new();
@@ -157,6 +157,7 @@ impl MEAnchor {
   ///   BreakPoint,
   ///   MEAnchor,
   ///   OrientationEnum,
+  ///   Position,
   ///   CIGAR,
   /// };
   ///
@@ -164,7 +165,7 @@ impl MEAnchor {
   /// let flag = 75;
   /// let mobel = "mobel77".to_string();
   /// let orientation = OrientationEnum::None;
-  /// let position = 2099;
+  /// let position = Position::OneBased(2099);
   /// let size = 11000.;
   ///
   /// let mut produced = MEAnchor::new();
@@ -195,7 +196,7 @@ impl MEAnchor {
     flag: i32,
     mobel: String,
     orientation: OrientationEnum,
-    position: i32,
+    position: Position,
     size: f64,
   ) {
     self.cigar = cigar;
@@ -209,6 +210,43 @@ impl MEAnchor {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// get
+impl MEAnchor {
+  ///
+  /// Retrieve read overhang on mobile element edges, including clips.
+  ///
+  /// # Returns
+  ///
+  /// Return first read base on mobile element coordinates, zero or negative
+  /// when read starts before mobile element, & read bases past mobile
+  /// element end, negative when read ends within it.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   MEAnchor,
+  ///   Position,
+  ///   CIGAR,
+  /// };
+  ///
+  /// let mut me_anchor = MEAnchor::new();
+  /// me_anchor.position = Position::OneBased(6);
+  /// me_anchor.cigar = CIGAR::load("15S80M5S", me_anchor.position)
+  ///   .expect("CIGAR loading failed!");
+  /// me_anchor.size = 80.;
+  ///
+  /// assert_eq!(me_anchor.overhang(), (-9, 10));
+  /// ```
+  pub fn overhang(&self) -> (i64, i64) {
+    let left = self.position.one_based() as i64 - self.cigar.left_clip as i64;
+    let right = self.cigar.right_boundry.one_based() as i64 - self.size as i64;
+    (left, right)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // calculate break point
 impl MEAnchor {
   ///
@@ -218,13 +256,24 @@ impl MEAnchor {
   ///
   /// * `sequence` - Original read sequence.
   ///
+  /// * `config` - Detection parameters. Tags orientation & sets sequence
+  ///   length to extract beyond break point.
+  ///
+  /// # Returns
+  ///
+  /// Return error on empty sequence or break point outside read. Break point
+  /// remains unaltered on error.
+  ///
   /// # Examples
   ///
   /// ```
   /// use genomic_structures::{
   ///   BreakPoint,
+  ///   ClipSideEnum,
+  ///   DetectionConfig,
   ///   MEAnchor,
   ///   OrientationEnum,
+  ///   Position,
   ///   CIGAR,
   /// };
   ///
@@ -232,7 +281,7 @@ impl MEAnchor {
   /// let flag = 83;
   /// let mobel = "mobel77".to_string();
   /// let orientation = OrientationEnum::None;
-  /// let position = 1;
+  /// let position = Position::OneBased(1);
   /// let size = 11000.;
   ///
   /// let mut loaded = MEAnchor::load(
@@ -244,12 +293,18 @@ impl MEAnchor {
   ///   size,
   /// );
   ///
-  /// loaded.calculate_break_point("MMMM0987654321B1234567890OOOOO");
+  /// loaded
+  ///   .calculate_break_point(
+  ///     "MMMM0987654321B1234567890OOOOO",
+  ///     &DetectionConfig::default(),
+  ///   )
+  ///   .expect("BreakPoint failed!");
   ///
   /// let mut manual = MEAnchor::new();
   /// manual.breakpoint = BreakPoint {
   ///   sequence:   "MMMM0987654321B1234567890".to_string(),
-  ///   coordinate: 15.,
+  ///   coordinate: Position::OneBased(15),
+  ///   side:       ClipSideEnum::Left,
   /// };
   ///
   /// assert_eq!(loaded.breakpoint, manual.breakpoint);
@@ -257,22 +312,20 @@ impl MEAnchor {
   pub fn calculate_break_point(
     &mut self,
     sequence: &str,
-  ) {
+    config: &DetectionConfig,
+  ) -> anyResult<()> {
+    let flank = config.breakpoint_flank;
+
     // tag
-    self.tag();
-    if self.cigar.left_boundry <= 0 &&
-      self.orientation == OrientationEnum::Upstream
-    {
-      self
-        .breakpoint
-        .update(sequence, self.cigar.left_boundry as f64);
-    } else if self.cigar.right_boundry > self.size as i32 &&
-      self.orientation == OrientationEnum::Downstream
-    {
-      self
-        .breakpoint
-        .update(sequence, self.cigar.right_boundry as f64 - self.size);
+    self.tag(config);
+
+    let (left, right) = self.overhang();
+    if left <= 0 && self.orientation == OrientationEnum::Upstream {
+      self.breakpoint.update(sequence, left, flank)?;
+    } else if right > 0 && self.orientation == OrientationEnum::Downstream {
+      self.breakpoint.update(sequence, right, flank)?;
     }
+    Ok(())
   }
 }
 
@@ -315,12 +368,12 @@ impl TagME for MEAnchor {
   }
 
   // cigar left boundry
-  fn get_cigar_left_boundry(&self) -> i32 {
+  fn get_cigar_left_boundry(&self) -> Position {
     self.cigar.left_boundry
   }
 
   // cigar right boundry
-  fn get_cigar_rigth_boundry(&self) -> i32 {
+  fn get_cigar_rigth_boundry(&self) -> Position {
     self.cigar.right_boundry
   }
 }
@@ -334,13 +387,20 @@ pub trait TagME {
   ///
   /// Tag mobile element.
   ///
+  /// # Parameters
+  ///
+  /// * `config` - Detection parameters. Mobile element & anchor limits set
+  ///   distance from mobile element ends to tag orientation.
+  ///
   /// # Examples
   ///
   /// ```
   /// use genomic_structures::{
   ///   BreakPoint,
+  ///   DetectionConfig,
   ///   MEAnchor,
   ///   OrientationEnum,
+  ///   Position,
   ///   TagME,
   ///   CIGAR,
   /// };
@@ -349,7 +409,7 @@ pub trait TagME {
   /// let flag = 83;
   /// let mobel = "mobel77".to_string();
   /// let orientation = OrientationEnum::None;
-  /// let position = 1;
+  /// let position = Position::OneBased(1);
   /// let size = 11000.;
   ///
   /// let mut loaded = MEAnchor::load(
@@ -361,33 +421,36 @@ pub trait TagME {
   ///   size,
   /// );
   ///
-  /// loaded.tag();
+  /// loaded.tag(&DetectionConfig::default());
   ///
   /// let mut manual = MEAnchor::new();
   /// manual.orientation = OrientationEnum::Upstream;
   ///
   /// assert_eq!(loaded.orientation, manual.orientation);
   /// ```
-  fn tag(&mut self) {
+  fn tag(
+    &mut self,
+    config: &DetectionConfig,
+  ) {
     // upstream: read anchor reverse & mate unmapped
-    if self.get_cigar_left_boundry() <= ME_LIMIT && self.read_orientation() {
+    let left = self.get_cigar_left_boundry().one_based();
+    let right = self.get_cigar_rigth_boundry().one_based();
+    if left <= config.me_limit as u64 && self.read_orientation() {
       self.upstream();
     // upstream: read anchor & mate mapped reverse
-    } else if self.get_cigar_rigth_boundry() != 0 &&
-      self.get_cigar_rigth_boundry() <= ANCHOR_LIMIT &&
+    } else if right != 0 &&
+      right <= config.anchor_limit as u64 &&
       !self.read_orientation()
     {
       self.upstream();
     // downstream: read anchor & mate unmapped
-    } else if self.get_size() - self.get_cigar_rigth_boundry() as f64 <=
-      ME_LIMIT.into() &&
+    } else if self.get_size() - right as f64 <= config.me_limit.into() &&
       self.get_size() != 0. &&
       !self.read_orientation()
     {
       self.downstream();
     // downstream: read anchor reverse & mate mapped
-    } else if self.get_size() - self.get_cigar_left_boundry() as f64 <=
-      ANCHOR_LIMIT.into() &&
+    } else if self.get_size() - left as f64 <= config.anchor_limit.into() &&
       self.get_size() != 0. &&
       self.read_orientation()
     {
@@ -413,10 +476,10 @@ pub trait TagME {
   fn get_size(&self) -> f64;
 
   // cigar left boundry
-  fn get_cigar_left_boundry(&self) -> i32;
+  fn get_cigar_left_boundry(&self) -> Position;
 
   // cigar right boundry
-  fn get_cigar_rigth_boundry(&self) -> i32;
+  fn get_cigar_rigth_boundry(&self) -> Position;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
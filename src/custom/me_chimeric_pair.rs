@@ -7,11 +7,20 @@ use std::fmt;
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::custom::{
-  chr_anchor_enum::ChrAnchorEnum,
-  me_anchor::MEAnchor,
-  me_chimeric_read::MEChimericRead,
-  orientation_enum::OrientationEnum,
+use crate::{
+  function::flag_interpretor::SAMFlag,
+  custom::{
+    ambiguity_enum::AmbiguityEnum,
+    anchor_resolution::AnchorResolution,
+    chr_anchor_enum::ChrAnchorEnum,
+    detection_config::DetectionConfig,
+    me_anchor::MEAnchor,
+    me_chimeric_read::MEChimericRead,
+    orientation_enum::OrientationEnum,
+    origin_enum::OriginEnum,
+    raw_values::RawValues,
+    resolution_strategy_enum::ResolutionStrategyEnum,
+  },
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -31,22 +40,92 @@ pub struct MEChimericPair {
   /// Chromosomal anchor identifier.
   #[new(default)]
   pub chranch: ChrAnchorEnum,
+
+  /// Reason for ambiguous chromosomal anchor.
+  #[new(default)]
+  pub ambiguity: AmbiguityEnum,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // load & update
 impl MEChimericPair {
-  ///
+  /// Load mobile element anchor onto read 1 of `MEChimericPair`.
   pub fn load(me_anchor: MEAnchor) -> Self {
     let mut me_chimeric_pair = Self::new();
     me_chimeric_pair.read1 = MEChimericRead::load(me_anchor);
-    return me_chimeric_pair;
+    me_chimeric_pair
   }
 
   ///
-  pub fn update(&mut self) {
-    unimplemented!();
+  /// Update `MEChimericPair` with SAM record.
+  ///
+  /// Record is assigned to read 2 when flagged as second in pair, otherwise
+  /// to read 1. Mobile element records are loaded onto mobile element anchors
+  /// & chromosomal records onto chromosomal anchors, provided sequence
+  /// coincides with the one recorded from mobile element alignment.
+  ///
+  /// # Parameters
+  ///
+  /// * `values` - SAM record.
+  ///
+  /// * `origin` - Record alignment origin.
+  ///
+  /// * `config` - Detection parameters.
+  ///
+  /// # Returns
+  ///
+  /// Return whether record was loaded.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   DetectionConfig,
+  ///   MEChimericPair,
+  ///   OriginEnum,
+  ///   RawValues,
+  /// };
+  ///
+  /// let me_values = RawValues::load(vec![
+  ///   "ID", "129", "mobel77", "1", "60", "10M", "", "", "0", "GATTACAGAT", "",
+  /// ])
+  /// .expect("RawValues loading failed!");
+  ///
+  /// let chr_values = RawValues::load(vec![
+  ///   "ID", "129", "chr1", "1001", "60", "10M", "", "", "0", "GATTACAGAT", "",
+  /// ])
+  /// .expect("RawValues loading failed!");
+  ///
+  /// let config = DetectionConfig::default();
+  /// let mut me_chimeric_pair = MEChimericPair::new();
+  /// me_chimeric_pair.update(&me_values, OriginEnum::MobileElement, &config);
+  /// me_chimeric_pair.update(&chr_values, OriginEnum::Chromosomal, &config);
+  ///
+  /// assert_eq!(me_chimeric_pair.read1.me_read.len(), 0);
+  /// assert_eq!(me_chimeric_pair.read2.me_read.len(), 1);
+  /// assert_eq!(me_chimeric_pair.read2.chr_read.len(), 1);
+  /// ```
+  pub fn update(
+    &mut self,
+    values: &RawValues,
+    origin: OriginEnum,
+    config: &DetectionConfig,
+  ) -> bool {
+    let me_chimeric_read = if values.interpret(8) {
+      &mut self.read2
+    } else {
+      &mut self.read1
+    };
+
+    match origin {
+      OriginEnum::MobileElement => {
+        me_chimeric_read.load_me(values, config);
+        true
+      }
+      OriginEnum::Chromosomal => me_chimeric_read.load_chr(values, config),
+      OriginEnum::None => false,
+    }
   }
 }
 
@@ -57,35 +136,41 @@ impl MEChimericPair {
   ///
   /// Retrieve chromosomal anchor.
   ///
+  /// # Returns
+  ///
+  /// Return chromosomal anchor read. `None` when anchor is ambiguous.
+  ///
   /// # Examples
   ///
   /// ```
   /// use genomic_structures::{
   ///   ChrAnchorEnum,
   ///   MEChimericPair,
-  ///   MEChimericRead,
   /// };
   ///
   /// let mut to_retrieve = MEChimericPair::new();
   /// to_retrieve.read1.sequence = "GATTACA".to_string();
-  /// let retrieved = to_retrieve.get_chr_anchor();
+  /// assert!(to_retrieve.get_chr_anchor().is_none());
   ///
-  /// let mut predefined = MEChimericRead::new();
-  /// predefined.sequence = "GATTACA".to_string();
+  /// to_retrieve.chranch = ChrAnchorEnum::Read1;
+  /// let retrieved = to_retrieve.get_chr_anchor().expect("Ambiguous anchor!");
   ///
-  /// assert_eq!(retrieved.sequence, predefined.sequence);
+  /// assert_eq!(retrieved.sequence, "GATTACA");
   /// ```
-  pub fn get_chr_anchor(&self) -> &MEChimericRead {
+  pub fn get_chr_anchor(&self) -> Option<&MEChimericRead> {
     match self.chranch {
-      ChrAnchorEnum::Read1 => &self.read1,
-      ChrAnchorEnum::Read2 => &self.read2,
-      ChrAnchorEnum::None => {
-        // TODO: think about an alternative here
-        println!("This is a default value");
-        &self.read1
-      }
+      ChrAnchorEnum::Read1 => Some(&self.read1),
+      ChrAnchorEnum::Read2 => Some(&self.read2),
+      ChrAnchorEnum::None => None,
+    }
+  }
+
+  /// Retrieve chromosomal anchor resolution.
+  pub fn resolution(&self) -> AnchorResolution {
+    match self.chranch {
+      ChrAnchorEnum::None => AnchorResolution::Ambiguous(self.ambiguity),
+      chranch => AnchorResolution::Resolved(chranch),
     }
-    // .borrow()
   }
 
   // if both reads in the pair align to LTR at the same position that would
@@ -94,57 +179,203 @@ impl MEChimericPair {
   // if reads are palindromic & align to both ends of mobile element it becomes
   // ambigous to anchor
   ///
-  pub fn tag(&mut self) {
+  /// Tag chromosomal anchor.
+  ///
+  /// # Returns
+  ///
+  /// Return chromosomal anchor resolution, annotating reason on ambiguous
+  /// pairs.
+  pub fn tag(&mut self) -> AnchorResolution {
     // tag each read
     self.read1.tag();
     self.read2.tag();
     let edges = (self.read1.edge(), self.read2.edge());
 
-    self.chranch = match (self.read1.orientation, self.read2.orientation) {
-      // upstream
-      (OrientationEnum::Upstream, OrientationEnum::Upstream) => {
-        match edges {
-          (r1, r2) if r1 < r2 => ChrAnchorEnum::Read1,
-          (r1, r2) if r1 > r2 => ChrAnchorEnum::Read2,
-          (_, _) => ChrAnchorEnum::None,
-        }
+    // tail-only junction reads, i.e., oriented by poly-A / poly-T tail
+    // without mobile element anchors, pair as chromosomal reads
+    let orientation = |me_chimeric_read: &MEChimericRead| {
+      if tail_only(me_chimeric_read) {
+        OrientationEnum::None
+      } else {
+        me_chimeric_read.orientation
       }
-      (OrientationEnum::Upstream, OrientationEnum::None) => {
-        ChrAnchorEnum::Read2
-      }
-      (OrientationEnum::Upstream, _) => ChrAnchorEnum::None,
-
-      // downstream
-      (OrientationEnum::Downstream, OrientationEnum::Downstream) => {
-        match edges {
-          (r1, r2) if r1 < r2 => ChrAnchorEnum::Read2,
-          (r1, r2) if r1 > r2 => ChrAnchorEnum::Read1,
-          (_, _) => ChrAnchorEnum::None,
+    };
+
+    let (chranch, ambiguity) =
+      match (orientation(&self.read1), orientation(&self.read2)) {
+        // tail-only junction read evidences mobile element, anchored by
+        // chromosomal mate. otherwise, anchored by itself
+        (OrientationEnum::None, OrientationEnum::None)
+          if tail_only(&self.read1) != tail_only(&self.read2) =>
+        {
+          let mate_me = if tail_only(&self.read1) {
+            !self.read2.me_read.is_empty()
+          } else {
+            !self.read1.me_read.is_empty()
+          };
+          if tail_only(&self.read1) == mate_me {
+            resolved(ChrAnchorEnum::Read1)
+          } else {
+            resolved(ChrAnchorEnum::Read2)
+          }
+        }
+
+        // upstream
+        (OrientationEnum::Upstream, OrientationEnum::Upstream) => {
+          match edges {
+            (r1, r2) if r1 < r2 => resolved(ChrAnchorEnum::Read1),
+            (r1, r2) if r1 > r2 => resolved(ChrAnchorEnum::Read2),
+            (_, _) => ambiguous(AmbiguityEnum::EqualEdges),
+          }
+        }
+        (OrientationEnum::Upstream, OrientationEnum::None) => {
+          resolved(ChrAnchorEnum::Read2)
+        }
+        (OrientationEnum::Upstream, OrientationEnum::Palindromic) => {
+          ambiguous(AmbiguityEnum::Palindromic)
+        }
+        (OrientationEnum::Upstream, _) => {
+          ambiguous(AmbiguityEnum::BothMobileElement)
         }
-      }
-      (OrientationEnum::Downstream, OrientationEnum::None) => {
-        ChrAnchorEnum::Read2
-      }
-      (OrientationEnum::Downstream, _) => ChrAnchorEnum::None,
 
-      // palindromic
-      (OrientationEnum::Palindromic, _) => ChrAnchorEnum::None,
+        // downstream
+        (OrientationEnum::Downstream, OrientationEnum::Downstream) => {
+          match edges {
+            (r1, r2) if r1 < r2 => resolved(ChrAnchorEnum::Read2),
+            (r1, r2) if r1 > r2 => resolved(ChrAnchorEnum::Read1),
+            (_, _) => ambiguous(AmbiguityEnum::EqualEdges),
+          }
+        }
+        (OrientationEnum::Downstream, OrientationEnum::None) => {
+          resolved(ChrAnchorEnum::Read2)
+        }
+        (OrientationEnum::Downstream, OrientationEnum::Palindromic) => {
+          ambiguous(AmbiguityEnum::Palindromic)
+        }
+        (OrientationEnum::Downstream, _) => {
+          ambiguous(AmbiguityEnum::BothMobileElement)
+        }
 
-      // none
-      (OrientationEnum::None, OrientationEnum::Upstream) => {
-        ChrAnchorEnum::Read1
-      }
-      (OrientationEnum::None, OrientationEnum::Downstream) => {
-        ChrAnchorEnum::Read1
+        // palindromic
+        (OrientationEnum::Palindromic, _) => {
+          ambiguous(AmbiguityEnum::Palindromic)
+        }
+
+        // none
+        (OrientationEnum::None, OrientationEnum::Upstream) => {
+          resolved(ChrAnchorEnum::Read1)
+        }
+        (OrientationEnum::None, OrientationEnum::Downstream) => {
+          resolved(ChrAnchorEnum::Read1)
+        }
+        (OrientationEnum::None, OrientationEnum::Palindromic) => {
+          ambiguous(AmbiguityEnum::Palindromic)
+        }
+        (OrientationEnum::None, OrientationEnum::None) => {
+          ambiguous(AmbiguityEnum::Unoriented)
+        }
+      };
+
+    self.chranch = chranch;
+    self.ambiguity = ambiguity;
+    self.resolution()
+  }
+
+  ///
+  /// Resolve ambiguous chromosomal anchor.
+  ///
+  /// # Parameters
+  ///
+  /// * `strategy` - Resolution strategy.
+  ///   - `MateMapping` anchors on the read whose chosen chromosomal anchor is
+  ///     unambiguous & of highest mapping quality. Pairs remain ambiguous
+  ///     when reads cannot be distinguished.
+  ///   - `Exclude` & `None` leave ambiguous pairs untouched.
+  ///
+  /// # Returns
+  ///
+  /// Return chromosomal anchor resolution.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   AmbiguityEnum,
+  ///   AnchorResolution,
+  ///   ChrAnchor,
+  ///   ChrAnchorEnum,
+  ///   MEChimericPair,
+  ///   ResolutionStrategyEnum,
+  /// };
+  ///
+  /// let mut me_chimeric_pair = MEChimericPair::new();
+  /// let mut chr_anchor = ChrAnchor::new();
+  /// chr_anchor.mapq = 60;
+  /// me_chimeric_pair.read2.chr_read.push(chr_anchor);
+  ///
+  /// assert_eq!(
+  ///   me_chimeric_pair.tag(),
+  ///   AnchorResolution::Ambiguous(AmbiguityEnum::Unoriented)
+  /// );
+  /// assert_eq!(
+  ///   me_chimeric_pair.resolve(ResolutionStrategyEnum::Exclude),
+  ///   AnchorResolution::Ambiguous(AmbiguityEnum::Unoriented)
+  /// );
+  /// assert_eq!(
+  ///   me_chimeric_pair.resolve(ResolutionStrategyEnum::MateMapping),
+  ///   AnchorResolution::Resolved(ChrAnchorEnum::Read2)
+  /// );
+  /// ```
+  pub fn resolve(
+    &mut self,
+    strategy: ResolutionStrategyEnum,
+  ) -> AnchorResolution {
+    if self.chranch != ChrAnchorEnum::None {
+      return self.resolution();
+    }
+
+    if strategy == ResolutionStrategyEnum::MateMapping {
+      let mapq = |me_chimeric_read: &MEChimericRead| {
+        me_chimeric_read
+          .chr_anchor()
+          .filter(|chr_anchor| !chr_anchor.ambiguous)
+          .map(|chr_anchor| chr_anchor.mapq)
+      };
+      self.chranch = match (mapq(&self.read1), mapq(&self.read2)) {
+        (Some(r1), Some(r2)) if r1 > r2 => ChrAnchorEnum::Read1,
+        (Some(r1), Some(r2)) if r1 < r2 => ChrAnchorEnum::Read2,
+        (Some(_), None) => ChrAnchorEnum::Read1,
+        (None, Some(_)) => ChrAnchorEnum::Read2,
+        (_, _) => ChrAnchorEnum::None,
+      };
+      if self.chranch != ChrAnchorEnum::None {
+        self.ambiguity = AmbiguityEnum::None;
       }
-      (OrientationEnum::None, _) => ChrAnchorEnum::None,
     }
+    self.resolution()
   }
   // TODO: add trait implementation for mobile element retrieval
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// read oriented by poly-A / poly-T tail only
+fn tail_only(me_chimeric_read: &MEChimericRead) -> bool {
+  me_chimeric_read.me_read.is_empty() && me_chimeric_read.tail.is_some()
+}
+
+// resolved chromosomal anchor
+fn resolved(chranch: ChrAnchorEnum) -> (ChrAnchorEnum, AmbiguityEnum) {
+  (chranch, AmbiguityEnum::None)
+}
+
+// ambiguous chromosomal anchor
+fn ambiguous(ambiguity: AmbiguityEnum) -> (ChrAnchorEnum, AmbiguityEnum) {
+  (ChrAnchorEnum::None, ambiguity)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // display trait implementation
 impl fmt::Display for MEChimericPair {
   fn fmt(
@@ -7,20 +7,28 @@ use std::fmt;
 
 // crate utilities
 use crate::{
-  function::sequences::Sequence,
+  function::{
+    flag_interpretor::SAMFlag,
+    sequences::Sequence,
+    tail_detector::detect_poly_tail,
+  },
   custom::{
     chr_anchor::ChrAnchor,
+    clip_side_enum::ClipSideEnum,
+    detection_config::DetectionConfig,
     me_anchor::MEAnchor,
     orientation_enum::{
       OrientationEnum,
       OrientationPair,
     },
+    poly_tail::PolyTail,
+    raw_values::RawValues,
   },
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Contain primary (index 0) and secondary aligned read annotation.
+/// Contain chosen (index 0) and alternate aligned read annotation.
 #[derive(Debug, new, Default, PartialEq)]
 pub struct MEChimericRead {
   /// Chromosomal anchor.
@@ -41,17 +49,238 @@ pub struct MEChimericRead {
   /// Sequence.
   #[new(default)]
   pub sequence: String,
+
+  /// Poly-A / poly-T tail on chromosomal anchor clip.
+  #[new(default)]
+  pub tail: Option<PolyTail>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // load & update
 impl MEChimericRead {
-  ///
+  /// Load mobile element anchor onto `MEChimericRead`.
   pub fn load(me_anchor: MEAnchor) -> Self {
     let mut me_chimeric_read = Self::new();
     me_chimeric_read.me_read.push(me_anchor);
-    return me_chimeric_read;
+    me_chimeric_read
+  }
+
+  ///
+  /// Load mobile element aligned record onto `MEChimericRead`.
+  ///
+  /// Sequence is recorded on primary alignments. Break point is calculated
+  /// on recorded sequence, odd reads keep default break point.
+  ///
+  /// # Parameters
+  ///
+  /// * `values` - Record aligned to mobile element library.
+  ///
+  /// * `config` - Detection parameters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   DetectionConfig,
+  ///   MEChimericRead,
+  ///   RawValues,
+  /// };
+  ///
+  /// let values = RawValues::load(vec![
+  ///   "ID", "65", "mobel77", "1", "60", "4S6M", "", "", "0", "GATTACAGAT", "",
+  /// ])
+  /// .expect("RawValues loading failed!");
+  ///
+  /// let mut me_chimeric_read = MEChimericRead::new();
+  /// me_chimeric_read.load_me(&values, &DetectionConfig::default());
+  ///
+  /// assert_eq!(me_chimeric_read.sequence, "GATTACAGAT");
+  /// assert_eq!(me_chimeric_read.me_read.len(), 1);
+  /// assert_eq!(me_chimeric_read.me_read[0].mobel, "mobel77");
+  /// ```
+  pub fn load_me(
+    &mut self,
+    values: &RawValues,
+    config: &DetectionConfig,
+  ) {
+    // record data on primary alignment
+    if values.flag <= 255 {
+      self.sequence = values.sequence.clone();
+    }
+
+    // record mobile element data
+    let mut me_anchor = MEAnchor::load(
+      values.cigar.clone(),
+      values.flag,
+      values.scaffold.clone(),
+      values.orientation,
+      values.position,
+      values.get_extra(),
+    );
+
+    // calculate break point. odd reads keep default break point
+    me_anchor.calculate_break_point(&self.sequence, config).ok();
+    self.me_read.push(me_anchor);
+  }
+
+  ///
+  /// Load chromosomal aligned record onto `MEChimericRead`.
+  ///
+  /// Record is loaded only when its sequence, or reverse complement,
+  /// coincides with the sequence recorded from mobile element alignment.
+  /// Chromosomal anchors are ranked after loading.
+  ///
+  /// # Parameters
+  ///
+  /// * `values` - Record aligned to reference assembly.
+  ///
+  /// * `config` - Detection parameters.
+  ///
+  /// # Returns
+  ///
+  /// Return whether record was loaded.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   DetectionConfig,
+  ///   MEChimericRead,
+  ///   RawValues,
+  /// };
+  ///
+  /// let values = RawValues::load(vec![
+  ///   "ID", "81", "chr1", "1001", "37", "10M", "", "", "0", "ATCTGTAATC", "",
+  /// ])
+  /// .expect("RawValues loading failed!");
+  ///
+  /// let mut me_chimeric_read = MEChimericRead::new();
+  /// me_chimeric_read.sequence = "GATTACAGAT".to_string();
+  ///
+  /// assert!(me_chimeric_read.load_chr(&values, &DetectionConfig::default()));
+  /// assert_eq!(me_chimeric_read.quality, 37);
+  /// assert_eq!(me_chimeric_read.chr_read[0].chr, "chr1");
+  /// ```
+  pub fn load_chr(
+    &mut self,
+    values: &RawValues,
+    config: &DetectionConfig,
+  ) -> bool {
+    // check whether read sequence coincide
+    if self.sequence != values.sequence &&
+      self.reverse_sequence() != values.sequence
+    {
+      return false;
+    }
+
+    // load chromosomal anchor
+    let mut chr_anchor = ChrAnchor::load(
+      values.cigar.clone(),
+      values.scaffold.clone(),
+      values.flag,
+      values.quality,
+      values.position,
+      values.tlen,
+    );
+    chr_anchor.alignment_score = values.alignment_score;
+    chr_anchor.suboptimal_score = values.suboptimal_score;
+    self.chr_read.push(chr_anchor);
+
+    // rank chromosomal anchors
+    self.select_chr_anchor(config.min_mapq);
+    true
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// chromosomal anchor selection
+impl MEChimericRead {
+  ///
+  /// Rank chromosomal anchors & assign anchor mapping quality.
+  ///
+  /// Anchors are ranked by mapping quality, alignment & suboptimal score
+  /// difference and aligned length. Chosen anchor is placed at index 0.
+  /// Anchors are tagged as ambiguous when multi-mapped, including a chosen
+  /// anchor tied with its best alternate.
+  ///
+  /// # Parameters
+  ///
+  /// * `min_mapq` - Minimum mapping quality (MAPQ).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   ChrAnchor,
+  ///   MEChimericRead,
+  /// };
+  ///
+  /// let mut me_chimeric_read = MEChimericRead::new();
+  /// for (chr, mapq, alignment_score) in [("chr1", 0, 90), ("chr7", 0, 100)] {
+  ///   let mut chr_anchor = ChrAnchor::new();
+  ///   chr_anchor.chr = chr.to_string();
+  ///   chr_anchor.mapq = mapq;
+  ///   chr_anchor.alignment_score = Some(alignment_score);
+  ///   me_chimeric_read.chr_read.push(chr_anchor);
+  /// }
+  ///
+  /// me_chimeric_read.select_chr_anchor(20);
+  ///
+  /// let chosen = me_chimeric_read.chr_anchor().expect("No anchor found!");
+  /// assert_eq!(chosen.chr, "chr7");
+  /// assert!(chosen.ambiguous);
+  /// assert_eq!(me_chimeric_read.alternate_anchors().len(), 1);
+  /// ```
+  pub fn select_chr_anchor(
+    &mut self,
+    min_mapq: i32,
+  ) {
+    self.chr_read.sort_by(|a, b| {
+      (b.mapq, b.score_gap(), b.aligned_length()).cmp(&(
+        a.mapq,
+        a.score_gap(),
+        a.aligned_length(),
+      ))
+    });
+    self
+      .chr_read
+      .iter_mut()
+      .for_each(|chr_anchor| chr_anchor.tag_ambiguous(min_mapq));
+
+    // chosen anchor indistinguishable from best alternate
+    if let [chosen, alternate, ..] = self.chr_read.as_mut_slice() {
+      if (chosen.mapq, chosen.score_gap(), chosen.aligned_length()) ==
+        (alternate.mapq, alternate.score_gap(), alternate.aligned_length())
+      {
+        chosen.ambiguous = true;
+      }
+    }
+
+    self.quality = self
+      .chr_read
+      .first()
+      .map(|chr_anchor| chr_anchor.mapq)
+      .unwrap_or_default();
+  }
+
+  /// Retrieve chosen chromosomal anchor.
+  pub fn chr_anchor(&self) -> Option<&ChrAnchor> {
+    self.chr_read.first()
+  }
+
+  /// Retrieve alternate chromosomal anchors.
+  pub fn alternate_anchors(&self) -> &[ChrAnchor] {
+    self.chr_read.get(1..).unwrap_or(&[])
+  }
+
+  /// Whether chosen chromosomal anchor is missing or ambiguous.
+  pub fn is_ambiguous(&self) -> bool {
+    self
+      .chr_anchor()
+      .map(|chr_anchor| chr_anchor.ambiguous)
+      .unwrap_or(true)
   }
 }
 
@@ -59,7 +288,10 @@ impl MEChimericRead {
 
 // tag
 impl MEChimericRead {
+  /// Tag read orientation by mobile element anchor majority.
   ///
+  /// Reads without oriented mobile element anchors carrying a poly-A / poly-T
+  /// tail are tagged as downstream.
   pub fn tag(&mut self) {
     let orientation = OrientationPair(
       anchor_count!(self, Upstream),
@@ -70,38 +302,121 @@ impl MEChimericRead {
       OrientationPair(u, d) if u > d => OrientationEnum::Upstream,
       OrientationPair(u, d) if u < d => OrientationEnum::Downstream,
       OrientationPair(u, d) if u == d && u != 0 => OrientationEnum::Palindromic,
+      OrientationPair(_, _) if self.tail.is_some() => {
+        OrientationEnum::Downstream
+      }
       OrientationPair(_, _) => OrientationEnum::None,
     }
   }
 
   ///
-  pub fn edge(&self) -> i32 {
-    // TODO: better way to work with options?
+  /// Detect poly-A / poly-T tail on primary chromosomal anchor clip.
+  ///
+  /// Tail is searched adjacent to junction, i.e., sequence start on right
+  /// clips & sequence end on left clips. Longest tail is recorded.
+  ///
+  /// # Parameters
+  ///
+  /// * `min_length` - Minimum tail length.
+  ///
+  /// * `max_mismatch` - Maximum mismatch fraction within tail.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   ChrAnchor,
+  ///   MEChimericRead,
+  ///   OrientationEnum,
+  ///   Position,
+  ///   CIGAR,
+  /// };
+  ///
+  /// let mut me_chimeric_read = MEChimericRead::new();
+  /// me_chimeric_read.sequence =
+  ///   "GATTACAGATTACCAAAAAAAAAAAAAAACGT".to_string();
+  /// me_chimeric_read.chr_read.push(ChrAnchor::load(
+  ///   CIGAR::load("14M18S", Position::OneBased(1001))
+  ///     .expect("CIGAR loading failed!"),
+  ///   "chr1".to_string(),
+  ///   0,
+  ///   60,
+  ///   Position::OneBased(1001),
+  ///   0,
+  /// ));
+  ///
+  /// me_chimeric_read.detect_tail(10, 0.1);
+  /// me_chimeric_read.tag();
+  ///
+  /// assert_eq!(me_chimeric_read.tail.map(|tail| tail.length), Some(15));
+  /// assert_eq!(me_chimeric_read.orientation, OrientationEnum::Downstream);
+  /// ```
+  pub fn detect_tail(
+    &mut self,
+    min_length: i32,
+    max_mismatch: f64,
+  ) {
+    self.tail = None;
+    let chr_anchor = match self.chr_anchor() {
+      Some(chr_anchor) => chr_anchor,
+      None => return,
+    };
+    let sequence = self.chr_sequence(chr_anchor);
+
+    let mut tails = Vec::new();
+    // tail at clipped sequence start
+    let right_clip =
+      chr_anchor.cigar.clip_length(ClipSideEnum::Right) as usize;
+    if right_clip > 0 && right_clip <= sequence.len() {
+      tails.push(detect_poly_tail(
+        &sequence[sequence.len() - right_clip..],
+        true,
+        min_length,
+        max_mismatch,
+      ));
+    }
+    // tail at clipped sequence end
+    let left_clip =
+      chr_anchor.cigar.clip_length(ClipSideEnum::Left) as usize;
+    if left_clip > 0 && left_clip <= sequence.len() {
+      tails.push(detect_poly_tail(
+        &sequence[..left_clip],
+        false,
+        min_length,
+        max_mismatch,
+      ));
+    }
+
+    self.tail = tails
+      .into_iter()
+      .flatten()
+      .max_by(|a, b| a.length.cmp(&b.length));
+  }
+
+  /// Retrieve outermost mobile element boundry on read orientation, i.e.,
+  /// first read base on upstream & last read base on downstream anchors.
+  /// Read overhang before mobile element start is negative.
+  pub fn edge(&self) -> i64 {
     match self.orientation {
       OrientationEnum::Upstream => {
         let mut boundries = Vec::new();
         self.me_read.iter().for_each(|me_anchor| {
           if me_anchor.orientation == self.orientation {
-            boundries.push(me_anchor.cigar.left_boundry);
+            boundries.push(me_anchor.overhang().0);
           }
         });
-        match boundries.into_iter().min() {
-          Some(min) => min,
-          None => 0,
-        }
+        boundries.into_iter().min().unwrap_or_default()
       }
 
       OrientationEnum::Downstream => {
         let mut boundries = Vec::new();
         self.me_read.iter().for_each(|me_anchor| {
           if me_anchor.orientation == self.orientation {
-            boundries.push(me_anchor.cigar.right_boundry);
+            boundries
+              .push(me_anchor.cigar.right_boundry.one_based() as i64);
           }
         });
-        match boundries.into_iter().max() {
-          Some(max) => max,
-          None => 0,
-        }
+        boundries.into_iter().max().unwrap_or_default()
       }
 
       OrientationEnum::Palindromic => 0,
@@ -113,6 +428,54 @@ impl MEChimericRead {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// chromosomal sequence
+impl MEChimericRead {
+  ///
+  /// Retrieve read sequence oriented as chromosomal anchor alignment.
+  ///
+  /// Sequence is recorded from primary mobile element alignment, therefore
+  /// reverse complement is returned when strands disagree. Reads unmapped
+  /// to mobile elements carry sequence as sequenced, i.e., forward strand.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   ChrAnchor,
+  ///   MEAnchor,
+  ///   MEChimericRead,
+  /// };
+  ///
+  /// let mut me_anchor = MEAnchor::new();
+  /// me_anchor.flag = 97;
+  /// let mut me_chimeric_read = MEChimericRead::load(me_anchor);
+  /// me_chimeric_read.sequence = "GATTACA".to_string();
+  ///
+  /// let mut chr_anchor = ChrAnchor::new();
+  /// chr_anchor.flag = 113;
+  ///
+  /// assert_eq!(me_chimeric_read.chr_sequence(&chr_anchor), "TGTAATC");
+  /// ```
+  pub fn chr_sequence(
+    &self,
+    chr_anchor: &ChrAnchor,
+  ) -> String {
+    let me_reverse = self
+      .me_read
+      .iter()
+      .find(|me_anchor| me_anchor.flag <= 255)
+      .map(|me_anchor| me_anchor.interpret(5))
+      .unwrap_or(false);
+    if me_reverse == chr_anchor.interpret(5) {
+      self.sequence.clone()
+    } else {
+      self.reverse_sequence()
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // reverse sequence
 impl Sequence for MEChimericRead {
   fn get_sequence(&self) -> &str {
@@ -128,10 +491,19 @@ impl fmt::Display for MEChimericRead {
     &self,
     f: &mut fmt::Formatter,
   ) -> fmt::Result {
+    // partially loaded reads print placeholders
+    let chr_anchor = match self.chr_anchor() {
+      Some(chr_anchor) => chr_anchor.to_string(),
+      None => ".".to_string(),
+    };
+    let me_anchor = match self.me_read.first() {
+      Some(me_anchor) => me_anchor.to_string(),
+      None => ".".to_string(),
+    };
     writeln!(
       f,
       "{}\t{}\t{}\t{}\t",
-      self.chr_read[0], self.me_read[0], self.quality, self.sequence,
+      chr_anchor, me_anchor, self.quality, self.sequence,
     )
   }
 }
@@ -0,0 +1,228 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::me_level_enum::MELevelEnum;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Mobile element classification hierarchy, e.g., LINE/L1/L1HS.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct MEClassification {
+  /// Mobile element class.
+  #[new(default)]
+  pub class: String,
+
+  /// Mobile element family.
+  #[new(default)]
+  pub family: String,
+
+  /// Mobile element subfamily.
+  #[new(default)]
+  pub subfamily: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & update
+impl MEClassification {
+  ///
+  /// Load values onto `MEClassification`.
+  ///
+  /// # Parameters
+  ///
+  /// * `subfamily` - Repeat name, e.g., L1HS.
+  ///
+  /// * `class_family` - RepeatMasker class / family annotation, e.g.,
+  ///   LINE/L1.
+  ///
+  /// # Returns
+  ///
+  /// Return an instance of `MEClassification`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::MEClassification;
+  ///
+  /// let produced = MEClassification::load("L1HS", "LINE/L1");
+  ///
+  /// assert_eq!(produced, MEClassification {
+  ///   class:     "LINE".to_string(),
+  ///   family:    "L1".to_string(),
+  ///   subfamily: "L1HS".to_string(),
+  /// });
+  /// ```
+  pub fn load(
+    subfamily: &str,
+    class_family: &str,
+  ) -> Self {
+    let mut me_classification = Self::new();
+    me_classification.update(subfamily, class_family);
+    me_classification
+  }
+
+  ///
+  /// Update values of `MEClassification`.
+  ///
+  /// Class without family annotation, e.g., SVA, is recorded as its own
+  /// family.
+  ///
+  /// # Parameters
+  ///
+  /// * `subfamily` - Repeat name, e.g., L1HS.
+  ///
+  /// * `class_family` - RepeatMasker class / family annotation, e.g.,
+  ///   LINE/L1.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::MEClassification;
+  ///
+  /// let mut produced = MEClassification::new();
+  /// produced.update("SVA_D", "Retroposon");
+  ///
+  /// assert_eq!(produced, MEClassification {
+  ///   class:     "Retroposon".to_string(),
+  ///   family:    "Retroposon".to_string(),
+  ///   subfamily: "SVA_D".to_string(),
+  /// });
+  /// ```
+  pub fn update(
+    &mut self,
+    subfamily: &str,
+    class_family: &str,
+  ) {
+    let mut levels = class_family.splitn(2, '/');
+    self.class = levels.next().unwrap_or_default().to_string();
+    self.family = match levels.next() {
+      Some(family) => family.to_string(),
+      None => self.class.clone(),
+    };
+    self.subfamily = subfamily.to_string();
+  }
+
+  ///
+  /// Load Dfam classification hierarchy onto `MEClassification`.
+  ///
+  /// Generic ranks, e.g., `root` or `Transposable_Element`, are dropped.
+  /// Class is recorded as the first remaining level & family as the last.
+  ///
+  /// # Parameters
+  ///
+  /// * `subfamily` - Repeat name, e.g., L1HS.
+  ///
+  /// * `hierarchy` - Dfam `;` separated classification, e.g.,
+  ///   root;Interspersed_Repeat;Transposable_Element;LINE;L1.
+  ///
+  /// # Returns
+  ///
+  /// Return an instance of `MEClassification`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::MEClassification;
+  ///
+  /// let produced = MEClassification::load_dfam(
+  ///   "L1HS",
+  ///   "root;Interspersed_Repeat;Transposable_Element;LINE;L1",
+  /// );
+  ///
+  /// assert_eq!(produced, MEClassification::load("L1HS", "LINE/L1"));
+  /// ```
+  pub fn load_dfam(
+    subfamily: &str,
+    hierarchy: &str,
+  ) -> Self {
+    let levels = hierarchy
+      .split(';')
+      .map(str::trim)
+      .filter(|level| !level.is_empty() && !DFAM_RANKS.contains(level))
+      .collect::<Vec<&str>>();
+    let class_family = match levels.as_slice() {
+      [] => String::new(),
+      [class] => class.to_string(),
+      [class, .., family] => format!("{}/{}", class, family),
+    };
+    Self::load(subfamily, &class_family)
+  }
+}
+
+// generic Dfam ranks above mobile element class
+const DFAM_RANKS: [&str; 7] = [
+  "root",
+  "Interspersed_Repeat",
+  "Transposable_Element",
+  "Class_I_Retrotransposition",
+  "Class_II_DNA_Transposition",
+  "Retrotransposed_Element",
+  "LINE-dependent_Retroposon",
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl MEClassification {
+  ///
+  /// Retrieve classification at hierarchy level.
+  ///
+  /// # Parameters
+  ///
+  /// * `level` - Hierarchy level.
+  ///
+  /// # Returns
+  ///
+  /// Return annotation at hierarchy level. Empty string on `MELevelEnum::None`
+  /// or unclassified mobile elements.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   MEClassification,
+  ///   MELevelEnum,
+  /// };
+  ///
+  /// let classification = MEClassification::load("L1HS", "LINE/L1");
+  ///
+  /// assert_eq!(classification.get_level(MELevelEnum::Class), "LINE");
+  /// assert_eq!(classification.get_level(MELevelEnum::Family), "L1");
+  /// assert_eq!(classification.get_level(MELevelEnum::Subfamily), "L1HS");
+  /// ```
+  pub fn get_level(
+    &self,
+    level: MELevelEnum,
+  ) -> &str {
+    match level {
+      MELevelEnum::Class => &self.class,
+      MELevelEnum::Family => &self.family,
+      MELevelEnum::Subfamily => &self.subfamily,
+      MELevelEnum::None => "",
+    }
+  }
+
+  /// Evaluate whether classification has been annotated.
+  pub fn is_classified(&self) -> bool {
+    !self.class.is_empty()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for MEClassification {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(f, "{}/{}/{}", self.class, self.family, self.subfamily)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,117 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  call_merger::Mergeable,
+  me_classification::MEClassification,
+  me_info::MEInfo,
+  position::Position,
+  sv_type::SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reference mobile element deletion call, i.e., polymorphic absence.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct MEDeletionCall {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Annotated element start. 1-based.
+  #[new(default)]
+  pub position: Position,
+
+  /// Annotated element end. 1-based, inclusive.
+  #[new(default)]
+  pub end: Position,
+
+  /// Deleted length between observed breakends.
+  #[new(default)]
+  pub deleted_length: u64,
+
+  /// Whether breakends are resolved at base-pair by split reads.
+  #[new(default)]
+  pub precise: bool,
+
+  /// Deleted element summary, i.e., VCF MEINFO.
+  #[new(default)]
+  pub me_info: MEInfo,
+
+  /// Mobile element classification hierarchy.
+  #[new(default)]
+  pub classification: MEClassification,
+
+  /// Discordant pairs supporting deletion.
+  #[new(default)]
+  pub support: usize,
+
+  /// Split reads supporting deletion.
+  #[new(default)]
+  pub split_reads: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl MEDeletionCall {
+  /// Retrieve annotated element length.
+  pub fn length(&self) -> u64 {
+    (self.end - self.position + 1).max(0) as u64
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// merge
+impl Mergeable for MEDeletionCall {
+  fn variant_type(&self) -> SVType {
+    SVType::Deletion
+  }
+
+  fn breakends(&self) -> ((&str, Position), (&str, Position)) {
+    ((&self.chr, self.position), (&self.chr, self.end))
+  }
+
+  // family, or element name when unclassified
+  fn element_family(&self) -> Option<&str> {
+    match self.classification.family.as_str() {
+      "" => Some(&self.me_info.mobel),
+      family => Some(family),
+    }
+  }
+
+  fn read_support(&self) -> usize {
+    self.support + self.split_reads
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for MEDeletionCall {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t",
+      self.chr,
+      self.position,
+      self.end,
+      self.deleted_length,
+      self.me_info,
+      self.classification,
+      self.support,
+      self.split_reads
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,248 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Result as anyResult;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    allele_count::AlleleCount,
+    allele_counter::AlleleCounter,
+    genomic_interval::GenomicInterval,
+    me_insertion_call::MEInsertionCall,
+    me_insertion_caller::MEInsertionCaller,
+    me_library::MELibrary,
+    origin_enum::OriginEnum,
+    raw_values::RawValues,
+    sample_genotype::SampleGenotype,
+  },
+  function::flag_interpretor::SAMFlag,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Joint genotyping of mobile element insertions across samples.
+///
+/// Candidate sites called on each sample are merged onto a site catalog.
+/// Support is then re-counted on every sample as fragments, i.e., read
+/// pairs, supporting the insertion (chimeric pairs with a mate on a mobile
+/// element anchored at the site) or the reference (unclipped reads & proper
+/// pair templates spanning the site).
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct MEGenotyper {
+  /// Allele support counting parameters.
+  #[new(value = "AlleleCounter::new()")]
+  pub allele_counter: AlleleCounter,
+
+  /// Maximum distance between sample sites merged onto a catalog site.
+  #[new(value = "100")]
+  pub merge_distance: u64,
+
+  /// Sequencing & alignment error rate on genotype likelihoods.
+  #[new(value = "0.01")]
+  pub error_rate: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// catalog
+impl MEGenotyper {
+  ///
+  /// Merge candidate sites from all samples onto a site catalog.
+  ///
+  /// Sites on the same chromosome & mobile element family are merged when
+  /// within merge distance of the first site on cluster. Each catalog site is
+  /// represented by its precise & best supported sample call.
+  ///
+  /// # Parameters
+  ///
+  /// * `sample_calls` - Insertion calls per sample.
+  ///
+  /// # Returns
+  ///
+  /// Return catalog sites sorted by chromosome & position, without filters
+  /// or genotypes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   MEGenotyper,
+  ///   MEInsertionCall,
+  ///   Position,
+  /// };
+  ///
+  /// let site = |position, five_prime| {
+  ///   let mut me_insertion_call = MEInsertionCall::new();
+  ///   me_insertion_call.chr = "chr1".to_string();
+  ///   me_insertion_call.position = Position::OneBased(position);
+  ///   me_insertion_call.five_prime = five_prime;
+  ///   me_insertion_call.me_info.mobel = "AluY".to_string();
+  ///   me_insertion_call
+  /// };
+  ///
+  /// let catalog = MEGenotyper::new()
+  ///   .catalog(&[vec![site(10000, 3)], vec![site(10040, 8), site(25000, 2)]]);
+  ///
+  /// assert_eq!(catalog.len(), 2);
+  /// assert_eq!(catalog[0].position, Position::OneBased(10040));
+  /// ```
+  pub fn catalog(
+    &self,
+    sample_calls: &[Vec<MEInsertionCall>],
+  ) -> Vec<MEInsertionCall> {
+    let mut me_insertion_calls =
+      sample_calls.iter().flatten().collect::<Vec<&MEInsertionCall>>();
+    me_insertion_calls.sort_by(|a, b| {
+      (&a.chr, a.family(), a.position).cmp(&(&b.chr, b.family(), b.position))
+    });
+
+    let mut clusters: Vec<Vec<&MEInsertionCall>> = Vec::new();
+    for me_insertion_call in me_insertion_calls {
+      match clusters.last_mut() {
+        Some(cluster)
+          if cluster[0].chr == me_insertion_call.chr &&
+            cluster[0].family() == me_insertion_call.family() &&
+            me_insertion_call.position - cluster[0].position <=
+              self.merge_distance as i64 =>
+        {
+          cluster.push(me_insertion_call)
+        }
+        _ => clusters.push(vec![me_insertion_call]),
+      }
+    }
+
+    let mut catalog = clusters
+      .iter()
+      .filter_map(|cluster| {
+        cluster
+          .iter()
+          .max_by_key(|me_insertion_call| {
+            (me_insertion_call.precise, me_insertion_call.support())
+          })
+          .map(|me_insertion_call| {
+            let mut site = (*me_insertion_call).clone();
+            site.filters.clear();
+            site.genotypes.clear();
+            site
+          })
+      })
+      .collect::<Vec<MEInsertionCall>>();
+    catalog.sort_by(|a, b| (&a.chr, a.position).cmp(&(&b.chr, b.position)));
+    catalog
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// genotype
+impl MEGenotyper {
+  ///
+  /// Count fragments supporting reference & alternate alleles per site.
+  ///
+  /// Chimeric pairs are loaded & resolved as on insertion calling, see
+  /// `MEInsertionCaller::resolve_pair`, & counted as alternate by
+  /// `AlleleCounter::me_alternate`. Reference support is counted by
+  /// `AlleleCounter::reference` on the same pass over chromosomal records.
+  /// Fragments supporting both alleles are counted as alternate.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_reader` - Sample records aligned to mobile element library.
+  ///
+  /// * `chr_reader` - Sample records aligned to reference assembly.
+  ///
+  /// * `library` - Mobile element library.
+  ///
+  /// * `sites` - Catalog sites.
+  ///
+  /// # Returns
+  ///
+  /// Return allele counts in site order. Error on malformed chromosomal
+  /// records.
+  pub fn count<M: BufRead, C: BufRead>(
+    &self,
+    me_reader: M,
+    chr_reader: C,
+    library: &HashMap<String, MELibrary>,
+    sites: &[MEInsertionCall],
+  ) -> anyResult<Vec<AlleleCount>> {
+    let allele_counter = &self.allele_counter;
+    let mut me_insertion_caller = MEInsertionCaller::new();
+    me_insertion_caller.config = allele_counter.config;
+    let mut me_chimeric_pairs =
+      me_insertion_caller.load_me(me_reader, library)?;
+
+    // chromosomal alignments loaded onto pairs while counting reference
+    let records = RawValues::records(chr_reader).inspect(|values| {
+      if let Ok(values) = values {
+        if let Some(me_chimeric_pair) =
+          me_chimeric_pairs.get_mut(&values.read_id.current)
+        {
+          if !values.interpret(3) {
+            me_chimeric_pair.update(
+              values,
+              OriginEnum::Chromosomal,
+              &allele_counter.config,
+            );
+          }
+        }
+      }
+    });
+    let sites = sites
+      .iter()
+      .map(|site| {
+        GenomicInterval::new(site.chr.clone(), site.position, site.position)
+      })
+      .collect::<Vec<GenomicInterval>>();
+    let reference = allele_counter.reference(records, &sites)?;
+
+    for me_chimeric_pair in me_chimeric_pairs.values_mut() {
+      me_insertion_caller.resolve_pair(me_chimeric_pair);
+    }
+    let alternate = allele_counter.me_alternate(&me_chimeric_pairs, &sites);
+
+    Ok(AlleleCounter::tally(&reference, &alternate))
+  }
+
+  ///
+  /// Genotype sample on catalog sites.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_reader` - Sample records aligned to mobile element library.
+  ///
+  /// * `chr_reader` - Sample records aligned to reference assembly.
+  ///
+  /// * `library` - Mobile element library.
+  ///
+  /// * `sites` - Catalog sites.
+  ///
+  /// # Returns
+  ///
+  /// Return sample genotypes in site order. Error on malformed chromosomal
+  /// records.
+  pub fn genotype<M: BufRead, C: BufRead>(
+    &self,
+    me_reader: M,
+    chr_reader: C,
+    library: &HashMap<String, MELibrary>,
+    sites: &[MEInsertionCall],
+  ) -> anyResult<Vec<SampleGenotype>> {
+    Ok(
+      self
+        .count(me_reader, chr_reader, library, sites)?
+        .into_iter()
+        .map(|allele_count| allele_count.genotype(self.error_rate))
+        .collect(),
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,81 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  position::Position,
+  strand_enum::StrandEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Mobile element insertion summary, i.e., VCF MEINFO.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct MEInfo {
+  /// Mobile element library entry.
+  #[new(default)]
+  pub mobel: String,
+
+  /// Covered consensus start. 1-based.
+  #[new(default)]
+  pub start: Position,
+
+  /// Covered consensus end. 1-based, inclusive.
+  #[new(default)]
+  pub end: Position,
+
+  /// Insertion strand.
+  #[new(default)]
+  pub strand: StrandEnum,
+
+  /// Estimated inserted length.
+  #[new(default)]
+  pub length: u64,
+
+  /// Estimated 5' truncation.
+  #[new(default)]
+  pub truncation: u64,
+
+  /// Mobile element anchors supporting summary.
+  #[new(default)]
+  pub support: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl MEInfo {
+  /// Retrieve insertion polarity, i.e., `+`, `-` or `.` when undetermined.
+  pub fn polarity(&self) -> char {
+    match self.strand {
+      StrandEnum::Forward => '+',
+      StrandEnum::Reverse => '-',
+      StrandEnum::None => '.',
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF MEINFO
+impl fmt::Display for MEInfo {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(
+      f,
+      "{},{},{},{}",
+      self.mobel,
+      self.start,
+      self.end,
+      self.polarity()
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
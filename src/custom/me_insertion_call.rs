@@ -0,0 +1,149 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  call_merger::Mergeable,
+  gene_annotation::GeneAnnotation,
+  me_classification::MEClassification,
+  me_info::MEInfo,
+  position::Position,
+  sample_genotype::SampleGenotype,
+  site_filter_enum::SiteFilterEnum,
+  sv_type::SVType,
+  target_site_duplication::TargetSiteDuplication,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Mobile element insertion call.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct MEInsertionCall {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Insertion position. 1-based.
+  #[new(default)]
+  pub position: Position,
+
+  /// Confidence interval start. 1-based.
+  #[new(default)]
+  pub ci_start: Position,
+
+  /// Confidence interval end. 1-based, inclusive.
+  #[new(default)]
+  pub ci_end: Position,
+
+  /// Whether position is resolved at base-pair by split reads.
+  #[new(default)]
+  pub precise: bool,
+
+  /// Insertion summary, i.e., VCF MEINFO.
+  #[new(default)]
+  pub me_info: MEInfo,
+
+  /// Mobile element classification hierarchy.
+  #[new(default)]
+  pub classification: MEClassification,
+
+  /// Target site duplication.
+  #[new(default)]
+  pub tsd: Option<TargetSiteDuplication>,
+
+  /// Median poly-A / poly-T tail length.
+  #[new(default)]
+  pub tail_length: Option<i32>,
+
+  /// Discordant pairs supporting 5' junction.
+  #[new(default)]
+  pub five_prime: usize,
+
+  /// Discordant pairs supporting 3' junction.
+  #[new(default)]
+  pub three_prime: usize,
+
+  /// Split reads supporting insertion position.
+  #[new(default)]
+  pub split_reads: usize,
+
+  /// Filter reasons tagged by annotation. Empty on passing calls.
+  #[new(default)]
+  pub filters: Vec<SiteFilterEnum>,
+
+  /// Gene feature hit by insertion. `None` when not annotated.
+  #[new(default)]
+  pub gene_annotation: Option<GeneAnnotation>,
+
+  /// Genotypes per sample on joint genotyping. Empty on single sample calls.
+  #[new(default)]
+  pub genotypes: Vec<SampleGenotype>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl MEInsertionCall {
+  /// Retrieve discordant pairs supporting insertion.
+  pub fn support(&self) -> usize {
+    self.five_prime + self.three_prime
+  }
+
+  /// Retrieve mobile element family, or element name when unclassified.
+  pub fn family(&self) -> &str {
+    match self.classification.family.as_str() {
+      "" => &self.me_info.mobel,
+      family => family,
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// merge
+impl Mergeable for MEInsertionCall {
+  fn variant_type(&self) -> SVType {
+    SVType::Insertion
+  }
+
+  fn breakends(&self) -> ((&str, Position), (&str, Position)) {
+    ((&self.chr, self.position), (&self.chr, self.position))
+  }
+
+  fn element_family(&self) -> Option<&str> {
+    Some(self.family())
+  }
+
+  fn read_support(&self) -> usize {
+    self.support() + self.split_reads
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for MEInsertionCall {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t",
+      self.chr,
+      self.position,
+      self.ci_start,
+      self.ci_end,
+      self.me_info,
+      self.classification,
+      self.support(),
+      self.split_reads
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,598 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use colored::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  function::{
+    break_point_refiner::refine_break_point,
+    flag_interpretor::SAMFlag,
+    me_summarizer::summarize_me,
+    position_binner::Anchor,
+    tail_detector::site_tail_length,
+    thresholder::threshold,
+    tsd_detector::detect_tsd,
+  },
+  custom::{
+    anchor_resolution::AnchorResolution,
+    chr_anchor_enum::ChrAnchorEnum,
+    clip_side_enum::ClipSideEnum,
+    detection_config::DetectionConfig,
+    extra_values_enum::ExtraValuesEnum,
+    me_chimeric_pair::MEChimericPair,
+    me_chimeric_read::MEChimericRead,
+    me_insertion_call::MEInsertionCall,
+    me_level_enum::MELevelEnum,
+    me_library::MELibrary,
+    orientation_enum::OrientationEnum,
+    origin_enum::OriginEnum,
+    position::Position,
+    raw_values::RawValues,
+    resolution_strategy_enum::ResolutionStrategyEnum,
+    strand_direction::StrandDirection,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genome-wide mobile element insertion calling pipeline.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct MEInsertionCaller {
+  /// Detection parameters.
+  #[new(default)]
+  pub config: DetectionConfig,
+
+  /// Mobile element hierarchy level aggregating anchor support.
+  #[new(default)]
+  pub level: MELevelEnum,
+
+  /// Strategy to resolve ambiguous chromosomal anchors.
+  #[new(value = "ResolutionStrategyEnum::Exclude")]
+  pub strategy: ResolutionStrategyEnum,
+
+  /// False discovery tolerance on binned read pile up.
+  #[new(value = "0.001")]
+  pub false_discovery_tolerance: f64,
+
+  /// Maximum pile up evaluated on thresholding.
+  #[new(value = "25")]
+  pub psize: usize,
+
+  /// Maximum distance between bins clustered onto a site.
+  #[new(value = "500")]
+  pub cluster_distance: u64,
+
+  /// Distance from consensus to collect supporting split reads.
+  #[new(value = "10")]
+  pub window: u64,
+
+  /// Minimum clipped bases to consider read as split read.
+  #[new(value = "5")]
+  pub min_clip: i32,
+
+  /// Maximum target site duplication length.
+  #[new(value = "50")]
+  pub max_tsd: u64,
+
+  /// Minimum poly-A / poly-T tail length.
+  #[new(value = "10")]
+  pub tail_length: i32,
+
+  /// Maximum mismatch fraction within poly-A / poly-T tail.
+  #[new(value = "0.1")]
+  pub tail_mismatch: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl MEInsertionCaller {
+  ///
+  /// Load mobile element alignment onto chimeric pairs.
+  ///
+  /// Mobile element size is annotated from library. Unmapped records only
+  /// contribute their sequence to the read, so chromosomal alignments can be
+  /// matched later on. Malformed & unsupported records are skipped & counted
+  /// on a single warning. Only reading failures are returned as errors.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - SAM records aligned to mobile element library.
+  ///
+  /// * `library` - Mobile element library.
+  ///
+  /// # Returns
+  ///
+  /// Return chimeric pairs with at least one mobile element anchor or
+  /// poly-A / poly-T run, i.e., candidate tail junction read, keyed by read
+  /// ID.
+  pub fn load_me<R: BufRead>(
+    &self,
+    reader: R,
+    library: &HashMap<String, MELibrary>,
+  ) -> anyResult<HashMap<String, MEChimericPair>> {
+    let mut me_chimeric_pairs: HashMap<String, MEChimericPair> = HashMap::new();
+    let mut skipped = 0;
+    for line in reader.lines() {
+      let line = line.context(CommonError::Reading)?;
+      let mut values = match RawValues::from_line(&line) {
+        Ok(Some(values)) => values,
+        Ok(None) => continue,
+        Err(_) => {
+          skipped += 1;
+          continue;
+        }
+      };
+
+      let me_chimeric_pair = me_chimeric_pairs
+        .entry(values.read_id.current.clone())
+        .or_default();
+
+      // unmapped records carry sequence only
+      if values.interpret(3) {
+        let me_chimeric_read = if values.interpret(8) {
+          &mut me_chimeric_pair.read2
+        } else {
+          &mut me_chimeric_pair.read1
+        };
+        if me_chimeric_read.sequence.is_empty() {
+          me_chimeric_read.sequence = values.sequence.clone();
+        }
+        continue;
+      }
+
+      // annotate mobile element size
+      if let Some(me_library) = library.get(&values.scaffold) {
+        values.extra = ExtraValuesEnum::MobelSize(me_library.size as f64);
+      }
+      me_chimeric_pair.update(&values, OriginEnum::MobileElement, &self.config);
+    }
+
+    // pairs with mobile element anchors or candidate poly-A / poly-T
+    // junction reads, whose tails are confirmed on chromosomal clips
+    me_chimeric_pairs.retain(|_, me_chimeric_pair| {
+      [&me_chimeric_pair.read1, &me_chimeric_pair.read2]
+        .iter()
+        .any(|me_chimeric_read| {
+          !me_chimeric_read.me_read.is_empty() ||
+            poly_run(&me_chimeric_read.sequence, self.tail_length)
+        })
+    });
+    warn_skipped(skipped, "mobile element");
+    Ok(me_chimeric_pairs)
+  }
+
+  ///
+  /// Load chromosomal alignment onto chimeric pairs.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - SAM records aligned to reference assembly.
+  ///
+  /// * `me_chimeric_pairs` - Chimeric pairs keyed by read ID.
+  ///
+  /// # Returns
+  ///
+  /// Return chromosome sizes from `@SQ` header lines. Chromosomes missing
+  /// from header are sized by the furthest alignment observed. Malformed &
+  /// unsupported records are skipped & counted on a single warning.
+  pub fn load_chr<R: BufRead>(
+    &self,
+    reader: R,
+    me_chimeric_pairs: &mut HashMap<String, MEChimericPair>,
+  ) -> anyResult<HashMap<String, f64>> {
+    let mut chromosome_sizes = HashMap::new();
+    let mut observed_sizes: HashMap<String, f64> = HashMap::new();
+    let mut skipped = 0;
+    for line in reader.lines() {
+      let line = line.context(CommonError::Reading)?;
+      if line.starts_with("@SQ") {
+        match sequence_header(&line) {
+          Ok((chr, size)) => {
+            chromosome_sizes.insert(chr, size);
+          }
+          Err(_) => skipped += 1,
+        }
+        continue;
+      }
+      let values = match RawValues::from_line(&line) {
+        Ok(Some(values)) => values,
+        Ok(None) => continue,
+        Err(_) => {
+          skipped += 1;
+          continue;
+        }
+      };
+      if values.interpret(3) {
+        continue;
+      }
+
+      let observed = observed_sizes.entry(values.scaffold.clone()).or_default();
+      *observed = observed.max(values.cigar.right_boundry.one_based() as f64);
+
+      if let Some(me_chimeric_pair) =
+        me_chimeric_pairs.get_mut(&values.read_id.current)
+      {
+        me_chimeric_pair.update(&values, OriginEnum::Chromosomal, &self.config);
+      }
+    }
+
+    for (chr, size) in observed_sizes {
+      chromosome_sizes.entry(chr).or_insert(size);
+    }
+    warn_skipped(skipped, "chromosomal");
+    Ok(chromosome_sizes)
+  }
+
+  ///
+  /// Tag chimeric pair & resolve chromosomal anchor.
+  ///
+  /// Poly-A / poly-T tails are detected before tagging, orienting junction
+  /// reads without mobile element anchors. Ambiguous anchors are resolved by
+  /// strategy.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_chimeric_pair` - Chimeric pair loaded from both alignments.
+  ///
+  /// # Returns
+  ///
+  /// Return anchor resolution.
+  pub fn resolve_pair(
+    &self,
+    me_chimeric_pair: &mut MEChimericPair,
+  ) -> AnchorResolution {
+    me_chimeric_pair
+      .read1
+      .detect_tail(self.tail_length, self.tail_mismatch);
+    me_chimeric_pair
+      .read2
+      .detect_tail(self.tail_length, self.tail_mismatch);
+    me_chimeric_pair.tag();
+    me_chimeric_pair.resolve(self.strategy)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// call
+impl MEInsertionCaller {
+  ///
+  /// Call mobile element insertions.
+  ///
+  /// Pipeline:
+  /// 1) Pair mobile element & chromosomal alignments.
+  /// 2) Tag read orientation & resolve chromosomal anchors.
+  /// 3) Bin anchors by chromosome, strand & mobile element end.
+  /// 4) Threshold bins on read pile up.
+  /// 5) Cluster bins onto candidate sites.
+  /// 6) Refine break points & summarize insertions.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_reader` - SAM records aligned to mobile element library.
+  ///
+  /// * `chr_reader` - SAM records aligned to reference assembly.
+  ///
+  /// * `library` - Mobile element library.
+  ///
+  /// # Returns
+  ///
+  /// Return insertion calls sorted by chromosome & position.
+  pub fn call<M: BufRead, C: BufRead>(
+    &self,
+    me_reader: M,
+    chr_reader: C,
+    library: &HashMap<String, MELibrary>,
+  ) -> anyResult<Vec<MEInsertionCall>> {
+    let mut me_chimeric_pairs = self.load_me(me_reader, library)?;
+    let chromosome_sizes = self.load_chr(chr_reader, &mut me_chimeric_pairs)?;
+
+    let strand_directions = self.anchor(&mut me_chimeric_pairs);
+
+    let mut me_insertion_calls = Vec::new();
+    for (chr, strand_direction) in strand_directions.iter() {
+      let chromosome_size =
+        chromosome_sizes.get(chr).copied().unwrap_or_default();
+      let bins = self.threshold_bins(strand_direction, chromosome_size);
+      for read_ids in self.cluster(bins) {
+        if let Some(me_insertion_call) =
+          self.summarize(chr, &read_ids, &me_chimeric_pairs, library)
+        {
+          me_insertion_calls.push(me_insertion_call);
+        }
+      }
+    }
+
+    me_insertion_calls.sort_by(|a, b| {
+      (&a.chr, a.position).cmp(&(&b.chr, b.position))
+    });
+    Ok(me_insertion_calls)
+  }
+
+  // tag, resolve & bin anchors by chromosome, strand & mobile element end
+  fn anchor(
+    &self,
+    me_chimeric_pairs: &mut HashMap<String, MEChimericPair>,
+  ) -> HashMap<String, StrandDirection> {
+    let mut strand_directions: HashMap<String, StrandDirection> =
+      HashMap::new();
+    for (read_id, me_chimeric_pair) in me_chimeric_pairs.iter_mut() {
+      if !self.resolve_pair(me_chimeric_pair).is_resolved() {
+        continue;
+      }
+      let (chr_anchor, me_read) = match anchor_reads(me_chimeric_pair) {
+        Some((anchor_read, me_read)) => match anchor_read.chr_anchor() {
+          Some(chr_anchor) if !chr_anchor.ambiguous => (chr_anchor, me_read),
+          _ => continue,
+        },
+        None => continue,
+      };
+
+      let strand_direction =
+        strand_directions.entry(chr_anchor.chr.clone()).or_default();
+      let bin_position = match (chr_anchor.interpret(5), me_read.orientation) {
+        (false, OrientationEnum::Upstream) => &mut strand_direction.fs5,
+        (false, OrientationEnum::Downstream) => &mut strand_direction.fs3,
+        (true, OrientationEnum::Upstream) => &mut strand_direction.rs5,
+        (true, OrientationEnum::Downstream) => &mut strand_direction.rs3,
+        // unoriented mobile element reads do not inform insertion end
+        (_, _) => continue,
+      };
+      bin_position.count += 1;
+      bin_position
+        .position
+        .entry(chr_anchor.bin(&self.config))
+        .or_default()
+        .push(read_id.clone());
+    }
+    strand_directions
+  }
+
+  // bins above pile up threshold
+  fn threshold_bins(
+    &self,
+    strand_direction: &StrandDirection,
+    chromosome_size: f64,
+  ) -> Vec<(Position, Vec<String>)> {
+    let mut bins = Vec::new();
+    for bin_position in [
+      &strand_direction.fs5,
+      &strand_direction.fs3,
+      &strand_direction.rs5,
+      &strand_direction.rs3,
+    ] {
+      if bin_position.count == 0 {
+        continue;
+      }
+      let pile_up = threshold(
+        bin_position.count as f64,
+        chromosome_size,
+        self.false_discovery_tolerance,
+        &bin_position.position,
+        self.psize,
+        &self.config,
+      );
+      // no pile up within false discovery tolerance
+      if pile_up == 0 {
+        continue;
+      }
+      for (bin, read_ids) in bin_position.position.iter() {
+        if read_ids.len() >= pile_up {
+          bins.push((*bin, read_ids.clone()));
+        }
+      }
+    }
+    bins
+  }
+
+  // cluster neighbouring bins onto candidate sites
+  fn cluster(
+    &self,
+    mut bins: Vec<(Position, Vec<String>)>,
+  ) -> Vec<Vec<String>> {
+    bins.sort_by_key(|(bin, _)| *bin);
+    let mut sites: Vec<Vec<String>> = Vec::new();
+    let mut last_bin: Option<Position> = None;
+    for (bin, read_ids) in bins {
+      match (last_bin, sites.last_mut()) {
+        (Some(last), Some(site))
+          if bin - last <= self.cluster_distance as i64 =>
+        {
+          site.extend(read_ids)
+        }
+        (_, _) => sites.push(read_ids),
+      }
+      last_bin = Some(bin);
+    }
+    sites.iter_mut().for_each(|site| {
+      site.sort();
+      site.dedup();
+    });
+    sites
+  }
+
+  // summarize candidate site
+  fn summarize(
+    &self,
+    chr: &str,
+    read_ids: &[String],
+    me_chimeric_pairs: &HashMap<String, MEChimericPair>,
+    library: &HashMap<String, MELibrary>,
+  ) -> Option<MEInsertionCall> {
+    let site_pairs = read_ids
+      .iter()
+      .filter_map(|read_id| me_chimeric_pairs.get(read_id))
+      .collect::<Vec<&MEChimericPair>>();
+    let site_reads = site_pairs
+      .iter()
+      .flat_map(|me_chimeric_pair| {
+        [&me_chimeric_pair.read1, &me_chimeric_pair.read2]
+      })
+      .collect::<Vec<&MEChimericRead>>();
+    // reads unambiguously anchored on site chromosome
+    let chr_reads = site_reads
+      .iter()
+      .copied()
+      .filter(|me_chimeric_read| {
+        me_chimeric_read
+          .chr_anchor()
+          .map(|chr_anchor| chr_anchor.chr == chr && !chr_anchor.ambiguous)
+          .unwrap_or(false)
+      })
+      .collect::<Vec<&MEChimericRead>>();
+
+    let mut me_insertion_call = MEInsertionCall::new();
+    me_insertion_call.chr = chr.to_string();
+    me_insertion_call.me_info =
+      summarize_me(site_pairs.iter().copied(), library, self.level)?;
+    if let Some(me_library) = library.get(&me_insertion_call.me_info.mobel) {
+      me_insertion_call.classification = me_library.classification.clone();
+    }
+
+    // discordant pair support by mobile element end
+    for me_chimeric_pair in site_pairs.iter() {
+      let orientation = anchor_reads(me_chimeric_pair)
+        .map(|(_, me_read)| me_read.orientation);
+      match orientation {
+        Some(OrientationEnum::Upstream) => me_insertion_call.five_prime += 1,
+        Some(OrientationEnum::Downstream) => {
+          me_insertion_call.three_prime += 1
+        }
+        _ => (),
+      }
+    }
+
+    // position
+    match refine_break_point(
+      chr_reads.iter().copied(),
+      ClipSideEnum::None,
+      self.window,
+      self.min_clip,
+    ) {
+      Some(refined) => {
+        me_insertion_call.position = refined.position;
+        me_insertion_call.ci_start = refined.ci_start;
+        me_insertion_call.ci_end = refined.ci_end;
+        me_insertion_call.precise = true;
+        me_insertion_call.split_reads = refined.split_reads;
+      }
+      None => {
+        let (ci_start, ci_end) = discordant_interval(&site_pairs)?;
+        me_insertion_call.position =
+          ci_start + (ci_end - ci_start) as u64 / 2;
+        me_insertion_call.ci_start = ci_start;
+        me_insertion_call.ci_end = ci_end;
+      }
+    }
+
+    me_insertion_call.tsd = detect_tsd(
+      chr_reads.iter().copied(),
+      self.window,
+      self.min_clip,
+      self.max_tsd,
+    );
+    me_insertion_call.tail_length = site_tail_length(chr_reads.iter().copied());
+    Some(me_insertion_call)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// homopolymer run of adenine or thymine
+fn poly_run(
+  sequence: &str,
+  length: i32,
+) -> bool {
+  let length = length.max(1) as usize;
+  sequence.contains(&"A".repeat(length)) ||
+    sequence.contains(&"T".repeat(length))
+}
+
+// warn once on skipped records
+fn warn_skipped(
+  skipped: usize,
+  origin: &str,
+) {
+  if skipped > 0 {
+    eprintln!(
+      "{} {} malformed or unsupported {} records skipped",
+      "warning:".yellow().bold(),
+      skipped,
+      origin
+    );
+  }
+}
+
+// parse SAM sequence header, i.e., @SQ SN:<chr> LN:<size>
+fn sequence_header(line: &str) -> anyResult<(String, f64)> {
+  let mut chr = None;
+  let mut size = None;
+  for field in line.split('\t').skip(1) {
+    if let Some(value) = field.strip_prefix("SN:") {
+      chr = Some(value.to_string());
+    } else if let Some(value) = field.strip_prefix("LN:") {
+      size = Some(value.parse::<f64>().context(CommonError::Parsing)?);
+    }
+  }
+  match (chr, size) {
+    (Some(chr), Some(size)) => Ok((chr, size)),
+    (_, _) => Err(CommonError::Parsing.into()),
+  }
+}
+
+// chromosomal anchor read & mobile element read on resolved pair
+fn anchor_reads(
+  me_chimeric_pair: &MEChimericPair
+) -> Option<(&MEChimericRead, &MEChimericRead)> {
+  match me_chimeric_pair.chranch {
+    ChrAnchorEnum::Read1 => {
+      Some((&me_chimeric_pair.read1, &me_chimeric_pair.read2))
+    }
+    ChrAnchorEnum::Read2 => {
+      Some((&me_chimeric_pair.read2, &me_chimeric_pair.read1))
+    }
+    ChrAnchorEnum::None => None,
+  }
+}
+
+// insertion interval delimited by discordant anchors. forward anchors lie
+// upstream & reverse anchors downstream of insertion
+fn discordant_interval(
+  site_pairs: &[&MEChimericPair],
+) -> Option<(Position, Position)> {
+  let mut forward: Option<Position> = None;
+  let mut reverse: Option<Position> = None;
+  for me_chimeric_pair in site_pairs.iter() {
+    let chr_anchor = match anchor_reads(me_chimeric_pair)
+      .and_then(|(anchor_read, _)| anchor_read.chr_anchor())
+    {
+      Some(chr_anchor) => chr_anchor,
+      None => continue,
+    };
+    if chr_anchor.interpret(5) {
+      let start = chr_anchor.position;
+      reverse = Some(reverse.map_or(start, |current| current.min(start)));
+    } else {
+      let end =
+        chr_anchor.cigar.right_boundry - chr_anchor.cigar.rigth_clip as u64;
+      forward = Some(forward.map_or(end, |current| current.max(end)));
+    }
+  }
+  match (forward, reverse) {
+    (Some(f), Some(r)) => Some((f.min(r), f.max(r))),
+    (Some(f), None) => Some((f, f)),
+    (None, Some(r)) => Some((r, r)),
+    (None, None) => None,
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,13 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label mobile element hierarchy level.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum MELevelEnum {
+  Class,
+  Family,
+  Subfamily,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,7 +1,10 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::custom::erv_annotations::ERVAnnotations;
+use crate::custom::{
+  erv_annotations::ERVAnnotations,
+  me_classification::MEClassification,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -20,6 +23,10 @@ pub struct MELibrary {
   /// ERV annotations.
   #[new(default)]
   pub annotations_erv: ERVAnnotations,
+
+  /// Mobile element classification hierarchy.
+  #[new(default)]
+  pub classification: MEClassification,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
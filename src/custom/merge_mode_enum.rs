@@ -0,0 +1,47 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label merged call set, i.e., every cluster (union) or clusters supported
+/// by a minimum number of inputs (consensus).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MergeModeEnum {
+  #[default]
+  Union,
+  Consensus(usize),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl MergeModeEnum {
+  /// Retrieve minimum inputs supporting a merged call.
+  pub fn min_inputs(&self) -> usize {
+    match self {
+      MergeModeEnum::Union => 1,
+      MergeModeEnum::Consensus(min_inputs) => *min_inputs,
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for MergeModeEnum {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    match self {
+      MergeModeEnum::Union => write!(f, "union"),
+      MergeModeEnum::Consensus(min_inputs) => {
+        write!(f, "consensus of {}", min_inputs)
+      }
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
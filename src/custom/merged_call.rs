@@ -0,0 +1,59 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Cluster of matching calls across inputs, i.e., samples or callers.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct MergedCall<T> {
+  /// Representative call, i.e., best supported member.
+  pub call: T,
+
+  /// Members as input index & call index within input, sorted by input.
+  #[new(default)]
+  pub members: Vec<(usize, usize)>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl<T> MergedCall<T> {
+  /// Retrieve inputs holding a member, sorted & unique.
+  pub fn inputs(&self) -> Vec<usize> {
+    let mut inputs = self
+      .members
+      .iter()
+      .map(|(input, _)| *input)
+      .collect::<Vec<usize>>();
+    inputs.dedup();
+    inputs
+  }
+
+  ///
+  /// Retrieve input provenance as support vector, e.g., `101` on calls
+  /// found on first & third inputs, as SURVIVOR `SUPP_VEC`.
+  ///
+  /// # Parameters
+  ///
+  /// * `inputs` - Number of merged inputs.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::MergedCall;
+  ///
+  /// let mut merged_call = MergedCall::new("DEL");
+  /// merged_call.members = vec![(0, 4), (2, 1)];
+  ///
+  /// assert_eq!(merged_call.inputs(), vec![0, 2]);
+  /// assert_eq!(merged_call.support_vector(3), "101");
+  /// ```
+  pub fn support_vector(
+    &self,
+    inputs: usize,
+  ) -> String {
+    let found = self.inputs();
+    (0..inputs)
+      .map(|input| if found.contains(&input) { '1' } else { '0' })
+      .collect()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -6,21 +6,13 @@ pub struct OrientationPair(pub usize, pub usize);
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Annotate orientation.
-#[derive(Debug, new, Clone, Copy, PartialEq)]
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
 pub enum OrientationEnum {
   Downstream,
   Upstream,
   Palindromic,
+  #[default]
   None,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// manual default trait implementation
-impl Default for OrientationEnum {
-  fn default() -> Self {
-    Self::None
-  }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,12 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Annotate record alignment origin.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum OriginEnum {
+  MobileElement,
+  Chromosomal,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
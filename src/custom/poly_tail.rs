@@ -0,0 +1,36 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Poly-A / poly-T tail on clipped sequence.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct PolyTail {
+  /// Tail nucleotide, i.e., `A` or `T`.
+  #[new(default)]
+  pub base: char,
+
+  /// Tail length.
+  #[new(default)]
+  pub length: i32,
+
+  /// Mismatches tolerated within tail.
+  #[new(default)]
+  pub mismatches: i32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for PolyTail {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(f, "{}\t{}\t{}", self.base, self.length, self.mismatches)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,206 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{
+  Hash,
+  Hasher,
+};
+use std::ops::{
+  Add,
+  Sub,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Base position on explicit coordinate system.
+///
+/// Positions are compared on the base they point to, i.e.,
+/// `Position::OneBased(1) == Position::ZeroBased(0)`. Offsets keep the
+/// coordinate system, and differences are taken on the pointed base.
+/// Positions are displayed as 1-based coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::Position;
+///
+/// let position = Position::OneBased(100);
+///
+/// assert_eq!(position + 50, Position::OneBased(150));
+/// assert_eq!(position - 150, Position::OneBased(0));
+/// assert_eq!(Position::ZeroBased(149) - position, 50);
+/// assert_eq!(Position::ZeroBased(99).to_string(), position.to_string());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Position {
+  /// 1-based coordinate, as on SAM, VCF & RepeatMasker.
+  OneBased(u64),
+
+  /// 0-based coordinate, as on BED & BAM.
+  ZeroBased(u64),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// conversion
+impl Position {
+  ///
+  /// Retrieve 1-based coordinate.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::Position;
+  ///
+  /// assert_eq!(Position::ZeroBased(99).one_based(), 100);
+  /// assert_eq!(Position::OneBased(100).one_based(), 100);
+  /// ```
+  pub fn one_based(&self) -> u64 {
+    match self {
+      Position::OneBased(position) => *position,
+      Position::ZeroBased(position) => position.saturating_add(1),
+    }
+  }
+
+  ///
+  /// Retrieve 0-based coordinate.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::Position;
+  ///
+  /// assert_eq!(Position::OneBased(100).zero_based(), 99);
+  /// assert_eq!(Position::ZeroBased(99).zero_based(), 99);
+  /// ```
+  pub fn zero_based(&self) -> u64 {
+    match self {
+      Position::OneBased(position) => position.saturating_sub(1),
+      Position::ZeroBased(position) => *position,
+    }
+  }
+
+  /// Convert onto 1-based coordinate system.
+  pub fn to_one_based(self) -> Self {
+    Position::OneBased(self.one_based())
+  }
+
+  /// Convert onto 0-based coordinate system.
+  pub fn to_zero_based(self) -> Self {
+    Position::ZeroBased(self.zero_based())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// unset position, as on unmapped SAM records
+impl Default for Position {
+  fn default() -> Self {
+    Position::OneBased(0)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// offset downstream
+impl Add<u64> for Position {
+  type Output = Self;
+
+  fn add(
+    self,
+    offset: u64,
+  ) -> Self {
+    match self {
+      Position::OneBased(position) => Position::OneBased(position + offset),
+      Position::ZeroBased(position) => Position::ZeroBased(position + offset),
+    }
+  }
+}
+
+// offset upstream, saturating at coordinate system origin
+impl Sub<u64> for Position {
+  type Output = Self;
+
+  fn sub(
+    self,
+    offset: u64,
+  ) -> Self {
+    match self {
+      Position::OneBased(position) => {
+        Position::OneBased(position.saturating_sub(offset))
+      }
+      Position::ZeroBased(position) => {
+        Position::ZeroBased(position.saturating_sub(offset))
+      }
+    }
+  }
+}
+
+// signed distance between pointed bases
+impl Sub for Position {
+  type Output = i64;
+
+  fn sub(
+    self,
+    other: Self,
+  ) -> i64 {
+    self.one_based() as i64 - other.one_based() as i64
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// comparison on pointed base
+impl PartialEq for Position {
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool {
+    self.one_based() == other.one_based()
+  }
+}
+
+impl Eq for Position {}
+
+impl PartialOrd for Position {
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Position {
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    self.one_based().cmp(&other.one_based())
+  }
+}
+
+impl Hash for Position {
+  fn hash<H: Hasher>(
+    &self,
+    state: &mut H,
+  ) {
+    self.one_based().hash(state);
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as 1-based coordinate, consistent with equality
+impl fmt::Display for Position {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(f, "{}", self.one_based())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -3,6 +3,7 @@
 // standard libraries
 use anyhow::Context;
 use anyhow::Result as anyResult;
+use std::io::BufRead;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -15,7 +16,9 @@ use crate::{
     extra_values_enum::ExtraValuesEnum,
     me_anchor::TagME,
     orientation_enum::OrientationEnum,
+    position::Position,
     read_control::ReadControl,
+    supplementary_alignment::SupplementaryAlignment,
   },
 };
 
@@ -40,9 +43,9 @@ pub struct RawValues {
   #[new(default)]
   pub scaffold: String,
 
-  /// Alignment position.
+  /// Alignment position. 1-based, zero on unmapped records.
   #[new(default)]
-  pub position: i32,
+  pub position: Position,
 
   /// Mapping quality (MAPQ).
   #[new(default)]
@@ -68,6 +71,18 @@ pub struct RawValues {
   /// Additional annotations.
   #[new(default)]
   pub extra: ExtraValuesEnum,
+
+  /// Alignment score (AS tag).
+  #[new(default)]
+  pub alignment_score: Option<i32>,
+
+  /// Suboptimal alignment score (XS tag).
+  #[new(default)]
+  pub suboptimal_score: Option<i32>,
+
+  /// Supplementary alignments (SA tag).
+  #[new(default)]
+  pub supplementary: Vec<SupplementaryAlignment>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -81,6 +96,8 @@ impl RawValues {
   ///     - Alignment flag and interprets orientation.
   ///     - Mobile element aligned.
   ///     - CIGAR calculating alignment coordinates and boundries.
+  ///     - Alignment & suboptimal scores from optional AS / XS tags.
+  ///     - Supplementary alignments from optional SA tag.
   ///   - Load mobile element features.
   ///   - Load structural variant features.
   pub fn load(flines: Vec<&str>) -> anyResult<Self> {
@@ -90,7 +107,81 @@ impl RawValues {
     // update values
     raw_values.update(flines)?;
 
-    return Ok(raw_values);
+    Ok(raw_values)
+  }
+
+  ///
+  /// Load `RawValues` from SAM line.
+  ///
+  /// # Parameters
+  ///
+  /// * `line` - Tab separated SAM record.
+  ///
+  /// # Returns
+  ///
+  /// Return `None` on header & blank lines. Error on records missing
+  /// mandatory fields or carrying malformed values.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   Position,
+  ///   RawValues,
+  /// };
+  ///
+  /// let line = "ID\t0\tchr1\t1001\t60\t100M\t*\t0\t0\tGATTACA\t*";
+  /// let values = RawValues::from_line(line)
+  ///   .expect("RawValues loading failed!")
+  ///   .expect("Record missing!");
+  ///
+  /// assert_eq!(values.position, Position::OneBased(1001));
+  /// let header = RawValues::from_line("@HD\tVN:1.6");
+  /// assert!(header.expect("Header loading failed!").is_none());
+  /// assert!(RawValues::from_line("ID\t0\tchr1").is_err());
+  /// ```
+  pub fn from_line(line: &str) -> anyResult<Option<Self>> {
+    if line.is_empty() || line.starts_with('@') {
+      return Ok(None);
+    }
+    let flines = line.split('\t').collect::<Vec<&str>>();
+    if flines.len() < 11 {
+      return Err(CommonError::Parsing.into());
+    }
+    Self::load(flines).map(Some)
+  }
+
+  ///
+  /// Iterate SAM records.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - SAM records.
+  ///
+  /// # Returns
+  ///
+  /// Return records in file order, skipping header & blank lines. Reading
+  /// failures & malformed records are yielded as errors.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::RawValues;
+  ///
+  /// let sam = "@HD\tVN:1.6\nID\t0\tchr1\t1001\t60\t100M\t*\t0\t0\tA\t*\n";
+  /// let records = RawValues::records(sam.as_bytes())
+  ///   .collect::<Result<Vec<RawValues>, _>>()
+  ///   .expect("RawValues loading failed!");
+  ///
+  /// assert_eq!(records.len(), 1);
+  /// ```
+  pub fn records<R: BufRead>(
+    reader: R
+  ) -> impl Iterator<Item = anyResult<Self>> {
+    reader.lines().filter_map(|line| match line {
+      Ok(line) => Self::from_line(&line).transpose(),
+      Err(error) => Some(Err(error).context(CommonError::Reading)),
+    })
   }
 
   /// Update records.
@@ -103,18 +194,23 @@ impl RawValues {
 
     // flag & read orientation
     self.flag = flines[1].parse::<i32>().context(CommonError::Parsing)?;
+    if !(0..4096).contains(&self.flag) {
+      return Err(CommonError::Parsing.into());
+    }
 
     // scaffold
     self.scaffold = flines[2].to_string();
 
     // position
-    self.position = flines[3].parse::<i32>().context(CommonError::Parsing)?;
+    self.position = Position::OneBased(
+      flines[3].parse::<u64>().context(CommonError::Parsing)?,
+    );
 
     //  quality
     self.quality = flines[4].parse::<i32>().context(CommonError::Parsing)?;
 
     // cigar
-    self.cigar = CIGAR::load(&flines[5].to_string(), self.position)?;
+    self.cigar = CIGAR::load(flines[5], self.position)?;
 
     // flines[6]
 
@@ -128,6 +224,29 @@ impl RawValues {
 
     // flines[10]
 
+    // optional fields
+    self.alignment_score = None;
+    self.suboptimal_score = None;
+    self.supplementary.clear();
+    for tag in flines.iter().skip(11) {
+      let mut fields = tag.splitn(3, ':');
+      let (score, value) =
+        match (fields.next(), fields.next(), fields.next()) {
+          (Some("SA"), Some("Z"), Some(value)) => {
+            self.supplementary = SupplementaryAlignment::load_tag(value);
+            continue;
+          }
+          (Some("AS"), Some("i"), Some(value)) => {
+            (&mut self.alignment_score, value)
+          }
+          (Some("XS"), Some("i"), Some(value)) => {
+            (&mut self.suboptimal_score, value)
+          }
+          _ => continue,
+        };
+      *score = Some(value.parse::<i32>().context(CommonError::Parsing)?);
+    }
+
     Ok(())
   }
 }
@@ -189,12 +308,12 @@ impl TagME for RawValues {
   }
 
   // cigar left boundry
-  fn get_cigar_left_boundry(&self) -> i32 {
+  fn get_cigar_left_boundry(&self) -> Position {
     self.cigar.left_boundry
   }
 
   // cigar right boundry
-  fn get_cigar_rigth_boundry(&self) -> i32 {
+  fn get_cigar_rigth_boundry(&self) -> Position {
     self.cigar.right_boundry
   }
 }
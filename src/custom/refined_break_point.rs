@@ -0,0 +1,74 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  clip_side_enum::ClipSideEnum,
+  position::Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Consensus genomic break point at base-pair resolution.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct RefinedBreakPoint {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Consensus break point. 1-based coordinate immediately after junction.
+  #[new(default)]
+  pub position: Position,
+
+  /// Confidence interval start.
+  #[new(default)]
+  pub ci_start: Position,
+
+  /// Confidence interval end.
+  #[new(default)]
+  pub ci_end: Position,
+
+  /// Read clip side.
+  #[new(default)]
+  pub side: ClipSideEnum,
+
+  /// Split reads supporting break point within window.
+  #[new(default)]
+  pub split_reads: usize,
+
+  /// Split reads clipped exactly at consensus break point.
+  #[new(default)]
+  pub exact_reads: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl RefinedBreakPoint {
+  /// Retrieve confidence interval relative to break point, e.g., VCF CIPOS.
+  pub fn cipos(&self) -> (i64, i64) {
+    (self.ci_start - self.position, self.ci_end - self.position)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for RefinedBreakPoint {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t{}",
+      self.chr, self.position, self.ci_start, self.ci_end, self.split_reads
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,203 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::fmt;
+use std::str::FromStr;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    genomic_interval::GenomicInterval,
+    position::Position,
+  },
+  error::common_error::CommonError,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genomic region held as 1-based, inclusive coordinates.
+///
+/// Use `Region::one_based` & `Region::zero_based` to build regions from
+/// either coordinate system, and `Region::span` & `Region::half_open` to read
+/// them back.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Region {
+  /// Chromosomal allocation.
+  pub chr: String,
+
+  /// First base. 1-based.
+  start: u64,
+
+  /// Last base. 1-based, inclusive. Below start on empty regions.
+  end: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl Region {
+  ///
+  /// Build region from 1-based, inclusive coordinates, as on SAM & VCF.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base. 1-based.
+  ///
+  /// * `end` - Last base. 1-based, inclusive.
+  pub fn one_based(
+    chr: &str,
+    start: u64,
+    end: u64,
+  ) -> Self {
+    Self {
+      chr: chr.to_string(),
+      start,
+      end,
+    }
+  }
+
+  ///
+  /// Build region from 0-based, half-open coordinates, as on BED.
+  ///
+  /// # Parameters
+  ///
+  /// * `chr` - Chromosomal allocation.
+  ///
+  /// * `start` - First base. 0-based.
+  ///
+  /// * `end` - Base after region. 0-based, exclusive.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::Region;
+  ///
+  /// let region = Region::zero_based("chr1", 1000, 2000);
+  ///
+  /// assert_eq!(region.span(), (1001, 2000));
+  /// assert_eq!(region.to_string(), "chr1:1001-2000");
+  /// ```
+  pub fn zero_based(
+    chr: &str,
+    start: u64,
+    end: u64,
+  ) -> Self {
+    Self::one_based(chr, start + 1, end)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl Region {
+  /// Retrieve first base.
+  pub fn start(&self) -> Position {
+    Position::OneBased(self.start)
+  }
+
+  /// Retrieve last base.
+  pub fn end(&self) -> Position {
+    Position::OneBased(self.end)
+  }
+
+  /// Retrieve 1-based, inclusive coordinates.
+  pub fn span(&self) -> (u64, u64) {
+    (self.start, self.end)
+  }
+
+  /// Retrieve 0-based, half-open coordinates.
+  pub fn half_open(&self) -> (u64, u64) {
+    (self.start.saturating_sub(1), self.end)
+  }
+
+  /// Retrieve region length. Zero on empty regions.
+  pub fn length(&self) -> u64 {
+    (self.end + 1).saturating_sub(self.start)
+  }
+
+  /// Check whether region holds no bases.
+  pub fn is_empty(&self) -> bool {
+    self.length() == 0
+  }
+
+  /// Check whether region contains position.
+  pub fn contains(
+    &self,
+    position: Position,
+  ) -> bool {
+    self.start() <= position && position <= self.end()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Parse region as `chr:start-end`, 1-based inclusive, or `chr:position`.
+/// Thousands separators are allowed, e.g., `chr1:1,000-2,000`.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::Region;
+///
+/// let region =
+///   "chr1:1,001-2,000".parse::<Region>().expect("Parsing failed!");
+///
+/// assert_eq!(region.chr, "chr1");
+/// assert_eq!(region.span(), (1001, 2000));
+/// assert_eq!(region.half_open(), (1000, 2000));
+/// ```
+impl FromStr for Region {
+  type Err = anyhow::Error;
+
+  fn from_str(region: &str) -> anyResult<Self> {
+    let (chr, span) = region.rsplit_once(':').context(CommonError::Parsing)?;
+    let span = span.replace(',', "");
+    let (start, end) = span.split_once('-').unwrap_or((&span, &span));
+    let start = start.parse::<u64>().context(CommonError::Parsing)?;
+    let end = end.parse::<u64>().context(CommonError::Parsing)?;
+    if chr.is_empty() || start == 0 || end < start {
+      return Err(CommonError::Parsing.into());
+    }
+    Ok(Self::one_based(chr, start, end))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// interval on alignment coordinates
+impl From<&GenomicInterval> for Region {
+  fn from(interval: &GenomicInterval) -> Self {
+    Self::one_based(
+      &interval.chr,
+      interval.start.one_based(),
+      interval.end.one_based(),
+    )
+  }
+}
+
+impl From<&Region> for GenomicInterval {
+  fn from(region: &Region) -> Self {
+    GenomicInterval::new(region.chr.clone(), region.start(), region.end())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as `chr:start-end`
+impl fmt::Display for Region {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    write!(f, "{}:{}-{}", self.chr, self.start, self.end)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
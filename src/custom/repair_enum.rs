@@ -0,0 +1,13 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label double strand break repair mechanism suggested by junction, i.e.,
+/// non-homologous (NHEJ) or microhomology mediated (MMEJ) end joining.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum RepairEnum {
+  EndJoining,
+  MicrohomologyMediated,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,211 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    me_classification::MEClassification,
+    position::Position,
+    strand_enum::StrandEnum,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// values ordered as RepeatMasker .out format
+/// Structural representation of RepeatMasker .out records.
+#[derive(Debug, new, Default, PartialEq)]
+pub struct RepeatMaskerRecord {
+  /// Smith-Waterman score.
+  #[new(default)]
+  pub score: i32,
+
+  /// Percentage of substitutions.
+  #[new(default)]
+  pub divergence: f64,
+
+  /// Percentage of deletions.
+  #[new(default)]
+  pub deletion: f64,
+
+  /// Percentage of insertions.
+  #[new(default)]
+  pub insertion: f64,
+
+  /// Scaffold alignment allocation.
+  #[new(default)]
+  pub scaffold: String,
+
+  /// Alignment start on scaffold. 1-based.
+  #[new(default)]
+  pub start: Position,
+
+  /// Alignment end on scaffold. 1-based.
+  #[new(default)]
+  pub end: Position,
+
+  /// Strand on scaffold.
+  #[new(default)]
+  pub strand: StrandEnum,
+
+  /// Mobile element classification hierarchy.
+  #[new(default)]
+  pub classification: MEClassification,
+
+  /// Alignment start on repeat consensus.
+  #[new(default)]
+  pub repeat_start: Position,
+
+  /// Alignment end on repeat consensus.
+  #[new(default)]
+  pub repeat_end: Position,
+
+  /// Repeat consensus bases left after alignment end.
+  #[new(default)]
+  pub repeat_left: u64,
+
+  /// Repeat identifier.
+  #[new(default)]
+  pub id: i32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & update
+impl RepeatMaskerRecord {
+  ///
+  /// Load record contained in RepeatMasker .out file.
+  ///
+  /// # Parameters
+  ///
+  /// * `flines` - Whitespace separated record fields.
+  ///
+  /// # Returns
+  ///
+  /// Return an instance of `RepeatMaskerRecord`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   MEClassification,
+  ///   Position,
+  ///   RepeatMaskerRecord,
+  ///   StrandEnum,
+  /// };
+  ///
+  /// let line = "1234 1.1 0.2 0.3 chr1 1001 7000 (100) C L1HS LINE/L1 (0) 6155 \
+  ///             157 7";
+  ///
+  /// let produced =
+  ///   RepeatMaskerRecord::load(line.split_whitespace().collect())
+  ///     .expect("RepeatMaskerRecord loading failed!");
+  ///
+  /// assert_eq!(produced, RepeatMaskerRecord {
+  ///   score:          1234,
+  ///   divergence:     1.1,
+  ///   deletion:       0.2,
+  ///   insertion:      0.3,
+  ///   scaffold:       "chr1".to_string(),
+  ///   start:          Position::OneBased(1001),
+  ///   end:            Position::OneBased(7000),
+  ///   strand:         StrandEnum::Reverse,
+  ///   classification: MEClassification::load("L1HS", "LINE/L1"),
+  ///   repeat_start:   Position::OneBased(157),
+  ///   repeat_end:     Position::OneBased(6155),
+  ///   repeat_left:    0,
+  ///   id:             7,
+  /// });
+  /// ```
+  pub fn load(flines: Vec<&str>) -> anyResult<Self> {
+    let mut repeat_masker_record = Self::new();
+    repeat_masker_record.update(flines)?;
+    Ok(repeat_masker_record)
+  }
+
+  /// Update records.
+  pub fn update(
+    &mut self,
+    flines: Vec<&str>,
+  ) -> anyResult<()> {
+    // mandatory fields
+    if flines.len() < 15 {
+      return Err(CommonError::Parsing.into());
+    }
+
+    // scores
+    self.score = flines[0].parse::<i32>().context(CommonError::Parsing)?;
+    self.divergence = flines[1].parse::<f64>().context(CommonError::Parsing)?;
+    self.deletion = flines[2].parse::<f64>().context(CommonError::Parsing)?;
+    self.insertion = flines[3].parse::<f64>().context(CommonError::Parsing)?;
+
+    // scaffold
+    self.scaffold = flines[4].to_string();
+    self.start = Position::OneBased(
+      flines[5].parse::<u64>().context(CommonError::Parsing)?,
+    );
+    self.end = Position::OneBased(
+      flines[6].parse::<u64>().context(CommonError::Parsing)?,
+    );
+
+    // flines[7] scaffold left
+
+    // strand
+    self.strand = match flines[8] {
+      "+" => StrandEnum::Forward,
+      "C" | "-" => StrandEnum::Reverse,
+      _ => StrandEnum::None,
+    };
+
+    // classification
+    self.classification.update(flines[9], flines[10]);
+
+    // repeat coordinates. complement matches list (left) end start
+    match self.strand {
+      StrandEnum::Reverse => {
+        self.repeat_left = parse_parenthesis(flines[11])?;
+        self.repeat_end = Position::OneBased(parse_parenthesis(flines[12])?);
+        self.repeat_start = Position::OneBased(parse_parenthesis(flines[13])?);
+      }
+      _ => {
+        self.repeat_start = Position::OneBased(parse_parenthesis(flines[11])?);
+        self.repeat_end = Position::OneBased(parse_parenthesis(flines[12])?);
+        self.repeat_left = parse_parenthesis(flines[13])?;
+      }
+    }
+
+    // identifier
+    self.id = flines[14].parse::<i32>().context(CommonError::Parsing)?;
+
+    Ok(())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl RepeatMaskerRecord {
+  /// Retrieve annotated element length on scaffold.
+  pub fn length(&self) -> u64 {
+    (self.end - self.start + 1).max(0) as u64
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// parse values enclosed on parenthesis, e.g., (100)
+fn parse_parenthesis(value: &str) -> anyResult<u64> {
+  value
+    .trim_start_matches('(')
+    .trim_end_matches(')')
+    .parse::<u64>()
+    .context(CommonError::Parsing)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,14 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Define strategy to resolve ambiguous chromosomal anchors.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum ResolutionStrategyEnum {
+  /// Anchor on read with unambiguous, highest quality chromosomal mapping.
+  MateMapping,
+  /// Exclude ambiguous pairs.
+  Exclude,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,152 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::genotype_enum::GenotypeEnum,
+  function::genotype_likelihood::genotype_likelihoods,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// highest reported genotype quality
+const MAX_QUALITY: i32 = 99;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genotype of a sample at a site, scored on reference & alternate allele
+/// support.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct SampleGenotype {
+  /// Fragments supporting reference allele.
+  #[new(default)]
+  pub reference: usize,
+
+  /// Fragments supporting alternate allele.
+  #[new(default)]
+  pub alternate: usize,
+
+  /// Log10 genotype likelihoods on 0/0, 0/1 & 1/1, scaled to most likely
+  /// genotype.
+  #[new(default)]
+  pub likelihoods: [f64; 3],
+
+  /// Most likely genotype. `GenotypeEnum::NoCall` without support.
+  #[new(default)]
+  pub genotype: GenotypeEnum,
+
+  /// Phred-scaled genotype quality.
+  #[new(default)]
+  pub quality: i32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl SampleGenotype {
+  ///
+  /// Genotype sample on allele support.
+  ///
+  /// Likelihoods are computed by `genotype_likelihoods`. Quality is
+  /// Phred-scaled probability of wrong genotype under flat prior, capped at
+  /// 99.
+  ///
+  /// # Parameters
+  ///
+  /// * `reference` - Fragments supporting reference allele.
+  ///
+  /// * `alternate` - Fragments supporting alternate allele.
+  ///
+  /// * `error_rate` - Sequencing & alignment error rate.
+  ///
+  /// # Returns
+  ///
+  /// Return scored genotype.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   GenotypeEnum,
+  ///   SampleGenotype,
+  /// };
+  ///
+  /// let sample_genotype = SampleGenotype::load(12, 10, 0.01);
+  ///
+  /// assert_eq!(sample_genotype.genotype, GenotypeEnum::Heterozygous);
+  /// assert_eq!(sample_genotype.quality, 99);
+  /// ```
+  pub fn load(
+    reference: usize,
+    alternate: usize,
+    error_rate: f64,
+  ) -> Self {
+    let mut sample_genotype = Self::new();
+    sample_genotype.reference = reference;
+    sample_genotype.alternate = alternate;
+    if reference + alternate == 0 {
+      return sample_genotype;
+    }
+
+    let mut likelihoods =
+      genotype_likelihoods(reference, alternate, error_rate);
+    let (best, max) = likelihoods.iter().enumerate().fold(
+      (0, f64::NEG_INFINITY),
+      |(best, max), (ix, likelihood)| {
+        if *likelihood > max {
+          (ix, *likelihood)
+        } else {
+          (best, max)
+        }
+      },
+    );
+    likelihoods.iter_mut().for_each(|likelihood| *likelihood -= max);
+
+    // posterior of wrong genotype under flat prior
+    let total = likelihoods.iter().map(|likelihood| 10f64.powf(*likelihood));
+    let error = 1. - 1. / total.sum::<f64>();
+    sample_genotype.quality = if error <= 0. {
+      MAX_QUALITY
+    } else {
+      ((-10. * error.log10()).round() as i32).min(MAX_QUALITY)
+    };
+    sample_genotype.likelihoods = likelihoods;
+    sample_genotype.genotype = match best {
+      0 => GenotypeEnum::HomozygousReference,
+      1 => GenotypeEnum::Heterozygous,
+      _ => GenotypeEnum::HomozygousAlternate,
+    };
+    sample_genotype
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF GT:GQ:GL:AD
+impl fmt::Display for SampleGenotype {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    if self.genotype == GenotypeEnum::NoCall {
+      return write!(f, "./.:.:.:{},{}", self.reference, self.alternate);
+    }
+    write!(
+      f,
+      "{}:{}:{:.2},{:.2},{:.2}:{},{}",
+      self.genotype,
+      self.quality,
+      self.likelihoods[0],
+      self.likelihoods[1],
+      self.likelihoods[2],
+      self.reference,
+      self.alternate
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,120 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  annotation_index::AnnotationIndex,
+  me_insertion_call::MEInsertionCall,
+  site_filter_enum::SiteFilterEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Annotation-aware filter on candidate mobile element insertion sites.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct SiteFilter {
+  /// Regions never called, e.g., centromeres & assembly gaps.
+  #[new(default)]
+  pub blacklist: AnnotationIndex,
+
+  /// Reference mobile elements labeled by family.
+  #[new(default)]
+  pub reference: AnnotationIndex,
+
+  /// Maximum distance from site to reference element of the same family.
+  #[new(value = "500")]
+  pub distance: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// filter
+impl SiteFilter {
+  ///
+  /// Tag insertion call with filter reasons.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_insertion_call` - Candidate insertion call.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   MEClassification,
+  ///   MEInsertionCall,
+  ///   Position,
+  ///   SiteFilter,
+  ///   SiteFilterEnum,
+  /// };
+  ///
+  /// let mut site_filter = SiteFilter::new();
+  /// site_filter.reference.insert(
+  ///   "chr1",
+  ///   Position::OneBased(12001),
+  ///   Position::OneBased(18000),
+  ///   "L1",
+  /// );
+  /// site_filter.reference.index();
+  ///
+  /// let mut me_insertion_call = MEInsertionCall::new();
+  /// me_insertion_call.chr = "chr1".to_string();
+  /// me_insertion_call.position = Position::OneBased(11800);
+  /// me_insertion_call.classification =
+  ///   MEClassification::load("L1HS", "LINE/L1");
+  ///
+  /// site_filter.tag(&mut me_insertion_call);
+  /// assert_eq!(me_insertion_call.filters, vec![
+  ///   SiteFilterEnum::ReferenceElement
+  /// ]);
+  /// ```
+  pub fn tag(
+    &self,
+    me_insertion_call: &mut MEInsertionCall,
+  ) {
+    let chr = &me_insertion_call.chr;
+    let position = me_insertion_call.position;
+    if !self.blacklist.overlaps(chr, position, position).is_empty() {
+      me_insertion_call.filters.push(SiteFilterEnum::Blacklist);
+    }
+
+    let family = me_insertion_call.family();
+    let same_family = self
+      .reference
+      .regions
+      .window(chr, position, position, self.distance)
+      .iter()
+      .any(|(_, label)| label == family);
+    if same_family {
+      me_insertion_call.filters.push(SiteFilterEnum::ReferenceElement);
+    }
+  }
+
+  ///
+  /// Tag or drop filtered insertion calls.
+  ///
+  /// # Parameters
+  ///
+  /// * `me_insertion_calls` - Candidate insertion calls.
+  ///
+  /// * `drop` - Whether to drop filtered calls instead of tagging them.
+  ///
+  /// # Returns
+  ///
+  /// Return tagged calls, or passing calls only when dropping.
+  pub fn apply(
+    &self,
+    me_insertion_calls: Vec<MEInsertionCall>,
+    drop: bool,
+  ) -> Vec<MEInsertionCall> {
+    me_insertion_calls
+      .into_iter()
+      .map(|mut me_insertion_call| {
+        self.tag(&mut me_insertion_call);
+        me_insertion_call
+      })
+      .filter(|me_insertion_call| !drop || me_insertion_call.filters.is_empty())
+      .collect()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,32 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Label reason to filter candidate site, i.e., overlap with blacklist region
+/// or proximity to reference element of the same family.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SiteFilterEnum {
+  Blacklist,
+  ReferenceElement,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF FILTER
+impl fmt::Display for SiteFilterEnum {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    let filter = match self {
+      SiteFilterEnum::Blacklist => "BLACKLIST",
+      SiteFilterEnum::ReferenceElement => "REF_ME",
+    };
+    write!(f, "{}", filter)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,12 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Annotate strand.
+#[derive(Debug, new, Default, Clone, Copy, PartialEq)]
+pub enum StrandEnum {
+  Forward,
+  Reverse,
+  #[default]
+  None,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
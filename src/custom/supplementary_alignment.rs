@@ -0,0 +1,165 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    cigar::CIGAR,
+    position::Position,
+    strand_enum::StrandEnum,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// SAM flag bits for supplementary alignments & reverse strand
+const SUPPLEMENTARY_FLAG: i32 = 2048;
+const REVERSE_FLAG: i32 = 16;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Supplementary alignment annotated on SA tag.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct SupplementaryAlignment {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Alignment position. 1-based.
+  #[new(default)]
+  pub position: Position,
+
+  /// Alignment strand.
+  #[new(default)]
+  pub strand: StrandEnum,
+
+  /// CIGAR string.
+  #[new(default)]
+  pub cigar: CIGAR,
+
+  /// Mapping quality (MAPQ).
+  #[new(default)]
+  pub mapq: i32,
+
+  /// Edit distance (NM).
+  #[new(default)]
+  pub mismatches: i32,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl SupplementaryAlignment {
+  ///
+  /// Load `SupplementaryAlignment` from SA tag entry.
+  ///
+  /// # Parameters
+  ///
+  /// * `entry` - Comma separated chromosome, position, strand, CIGAR, mapping
+  ///   quality & edit distance.
+  ///
+  /// # Returns
+  ///
+  /// Return supplementary alignment. Error on malformed entries.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   Position,
+  ///   StrandEnum,
+  ///   SupplementaryAlignment,
+  ///   CIGAR,
+  /// };
+  ///
+  /// let supplementary =
+  ///   SupplementaryAlignment::load("chr2,5001,-,60S40M,60,1")
+  ///     .expect("SupplementaryAlignment loading failed!");
+  /// let position = Position::OneBased(5001);
+  ///
+  /// assert_eq!(supplementary, SupplementaryAlignment {
+  ///   chr:        "chr2".to_string(),
+  ///   position:   position,
+  ///   strand:     StrandEnum::Reverse,
+  ///   cigar:      CIGAR::load("60S40M", position)
+  ///     .expect("CIGAR loading failed!"),
+  ///   mapq:       60,
+  ///   mismatches: 1,
+  /// });
+  /// ```
+  pub fn load(entry: &str) -> anyResult<Self> {
+    let fields = entry.split(',').collect::<Vec<&str>>();
+    if fields.len() != 6 {
+      return Err(CommonError::Parsing.into());
+    }
+    let position = Position::OneBased(
+      fields[1].parse::<u64>().context(CommonError::Parsing)?,
+    );
+    let strand = match fields[2] {
+      "+" => StrandEnum::Forward,
+      "-" => StrandEnum::Reverse,
+      _ => return Err(CommonError::Parsing.into()),
+    };
+    Ok(Self {
+      chr: fields[0].to_string(),
+      position,
+      strand,
+      cigar: CIGAR::load(fields[3], position)?,
+      mapq: fields[4].parse::<i32>().context(CommonError::Parsing)?,
+      mismatches: fields[5].parse::<i32>().context(CommonError::Parsing)?,
+    })
+  }
+
+  ///
+  /// Load supplementary alignments from SA tag value.
+  ///
+  /// # Parameters
+  ///
+  /// * `value` - Semicolon separated entries, i.e., `SA:Z:` tag value.
+  ///
+  /// # Returns
+  ///
+  /// Return supplementary alignments in tag order. Malformed entries are
+  /// dropped, so a bad tag never costs the primary alignment.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::SupplementaryAlignment;
+  ///
+  /// let supplementary = SupplementaryAlignment::load_tag(
+  ///   "chr2,5001,-,60S40M,60,1;chr3,bogus,+,40M,60,0;",
+  /// );
+  ///
+  /// assert_eq!(supplementary.len(), 1);
+  /// assert_eq!(supplementary[0].chr, "chr2");
+  /// ```
+  pub fn load_tag(value: &str) -> Vec<Self> {
+    value
+      .split(';')
+      .filter(|entry| !entry.is_empty())
+      .filter_map(|entry| Self::load(entry).ok())
+      .collect()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl SupplementaryAlignment {
+  /// Retrieve SAM flag as supplementary record.
+  pub fn flag(&self) -> i32 {
+    match self.strand {
+      StrandEnum::Reverse => SUPPLEMENTARY_FLAG + REVERSE_FLAG,
+      _ => SUPPLEMENTARY_FLAG,
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,175 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    call_merger::Mergeable,
+    clip_side_enum::ClipSideEnum,
+    gene_annotation::GeneAnnotation,
+    position::Position,
+    sv_junction::SVJunction,
+    sv_type::SVType,
+  },
+  MAX_DELETION_DEPTH,
+  MIN_DUPLICATION_DEPTH,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Structural variant call.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct SVCall {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Variant start. 1-based.
+  #[new(default)]
+  pub position: Position,
+
+  /// Mate breakend chromosome. Differs from `chr` on translocations.
+  #[new(default)]
+  pub chr2: String,
+
+  /// Variant end, or mate breakend position. 1-based, inclusive.
+  #[new(default)]
+  pub end: Position,
+
+  /// Structural variant type.
+  pub svtype: SVType,
+
+  /// Discordant pairs supporting variant.
+  #[new(default)]
+  pub support: usize,
+
+  /// Split reads supporting variant.
+  #[new(default)]
+  pub split_reads: usize,
+
+  /// Junction side at breakend & mate breakend, i.e., breakend orientation.
+  /// `Right` joins bases after breakend, `Left` joins bases before it.
+  #[new(default)]
+  pub orientation: (ClipSideEnum, ClipSideEnum),
+
+  /// Junction resolved by split reads. `None` on imprecise calls.
+  #[new(default)]
+  pub junction: Option<SVJunction>,
+
+  /// Read depth over flanking read depth. `None` when not profiled. Measured
+  /// on duplicated or deleted segment on complex variants.
+  #[new(default)]
+  pub depth_ratio: Option<f64>,
+
+  /// Breakend clusters resolved onto complex variant. Empty on simple
+  /// variants.
+  #[new(default)]
+  pub components: Vec<SVCall>,
+
+  /// Gene feature hit by variant, or by first breakend on translocations.
+  /// `None` when not annotated.
+  #[new(default)]
+  pub gene_annotation: Option<GeneAnnotation>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl SVCall {
+  /// Retrieve variant length. `None` on translocations.
+  pub fn length(&self) -> Option<u64> {
+    if self.chr != self.chr2 || self.svtype.is_breakend() {
+      None
+    } else {
+      Some((self.end - self.position + 1).max(0) as u64)
+    }
+  }
+
+  ///
+  /// Retrieve copy number estimate on diploid genome.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   SVCall,
+  ///   SVType,
+  /// };
+  ///
+  /// let mut sv_call = SVCall::new(SVType::Duplication);
+  /// assert_eq!(sv_call.copy_number(), None);
+  ///
+  /// sv_call.depth_ratio = Some(1.42);
+  /// assert_eq!(sv_call.copy_number(), Some(3));
+  /// ```
+  pub fn copy_number(&self) -> Option<i32> {
+    self.depth_ratio.map(|ratio| (ratio * 2.).round() as i32)
+  }
+
+  ///
+  /// Check whether read depth supports deleted or duplicated segment.
+  ///
+  /// # Returns
+  ///
+  /// Return false when depth ratio contradicts copy number change. True on
+  /// other variant types or when depth is not profiled.
+  pub fn depth_supported(&self) -> bool {
+    match (self.svtype, self.depth_ratio) {
+      (SVType::Deletion, Some(ratio)) |
+      (SVType::DeletionFlankedInversion, Some(ratio)) => {
+        ratio <= MAX_DELETION_DEPTH
+      }
+      (SVType::Duplication, Some(ratio)) |
+      (SVType::DispersedDuplication, Some(ratio)) |
+      (SVType::InvertedDuplication, Some(ratio)) => {
+        ratio >= MIN_DUPLICATION_DEPTH
+      }
+      _ => true,
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// merge
+impl Mergeable for SVCall {
+  fn variant_type(&self) -> SVType {
+    self.svtype
+  }
+
+  fn breakends(&self) -> ((&str, Position), (&str, Position)) {
+    ((&self.chr, self.position), (&self.chr2, self.end))
+  }
+
+  fn read_support(&self) -> usize {
+    self.support + self.split_reads
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for SVCall {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t{}\t{}\t{}\t",
+      self.chr,
+      self.position,
+      self.chr2,
+      self.end,
+      self.svtype,
+      self.support,
+      self.split_reads
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
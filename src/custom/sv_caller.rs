@@ -0,0 +1,483 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use colored::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  function::{
+    complex_resolver::resolve_complex,
+    flag_interpretor::SAMFlag,
+    junction_analyzer::characterize_junction,
+  },
+  custom::{
+    chr_anchor::ChrAnchor,
+    clip_side_enum::ClipSideEnum,
+    depth_profile::DepthProfile,
+    detection_config::DetectionConfig,
+    indexed_fasta::IndexedFasta,
+    position::Position,
+    raw_values::RawValues,
+    sv_call::SVCall,
+    sv_chimeric_pair::SVChimericPair,
+    sv_junction::SVJunction,
+    sv_type::SVType,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// call, supporting read IDs & split read breakends
+type Cluster = (SVCall, Vec<String>, Vec<(Position, Position)>);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Genome-wide structural variant calling pipeline on discordant pairs.
+#[derive(Debug, new, Clone, PartialEq)]
+pub struct SVCaller {
+  /// Detection parameters.
+  #[new(default)]
+  pub config: DetectionConfig,
+
+  /// Expected template length. Longer pairs are considered discordant.
+  #[new(value = "500")]
+  pub expected_tlen: i32,
+
+  /// Maximum breakend distance between pairs clustered onto a call.
+  #[new(value = "500")]
+  pub cluster_distance: u64,
+
+  /// Minimum discordant pairs & split reads supporting a call.
+  #[new(value = "2")]
+  pub min_support: usize,
+
+  /// Minimum clipped bases to register a split read break point.
+  #[new(value = "5")]
+  pub min_clip: i32,
+
+  /// Maximum microhomology or inserted sequence length at junctions.
+  #[new(value = "50")]
+  pub max_junction: usize,
+
+  /// Bin size on read depth profile.
+  #[new(value = "100")]
+  pub depth_bin: u64,
+
+  /// Flanking region length compared against deletions & duplications.
+  #[new(value = "1000")]
+  pub depth_flank: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+impl SVCaller {
+  ///
+  /// Load chromosomal alignment onto chimeric pairs & read depth profile.
+  ///
+  /// Only primary alignments above minimum mapping quality are loaded, along
+  /// with their supplementary alignments on SA tag. Break points are
+  /// registered on the longest clip of split reads. Malformed records are
+  /// skipped & counted on a single warning.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - SAM records aligned to reference assembly.
+  ///
+  /// # Returns
+  ///
+  /// Return chimeric pairs with both mates anchored, keyed by read ID, and
+  /// read depth of primary alignments.
+  pub fn load<R: BufRead>(
+    &self,
+    reader: R,
+  ) -> anyResult<(HashMap<String, SVChimericPair>, DepthProfile)> {
+    let mut sv_chimeric_pairs: HashMap<String, SVChimericPair> = HashMap::new();
+    let mut depth_profile = DepthProfile::new(self.depth_bin);
+    let mut skipped = 0;
+    for line in reader.lines() {
+      let line = line.context(CommonError::Reading)?;
+      let values = match RawValues::from_line(&line) {
+        Ok(Some(values)) => values,
+        Ok(None) => continue,
+        Err(_) => {
+          skipped += 1;
+          continue;
+        }
+      };
+
+      // unmapped, secondary & supplementary records
+      if values.interpret(3) || values.interpret(9) || values.interpret(12) {
+        continue;
+      }
+      if values.quality < self.config.min_mapq {
+        continue;
+      }
+
+      let sv_chimeric_pair = sv_chimeric_pairs
+        .entry(values.read_id.current.clone())
+        .or_insert_with(|| SVChimericPair::new(SVType::None));
+      let sv_chimeric_read = if values.interpret(8) {
+        &mut sv_chimeric_pair.read2
+      } else {
+        &mut sv_chimeric_pair.read1
+      };
+      sv_chimeric_read.sequence = values.sequence.clone();
+      let chr_anchor = ChrAnchor::load(
+        values.cigar.clone(),
+        values.scaffold.clone(),
+        values.flag,
+        values.quality,
+        values.position,
+        values.tlen,
+      );
+      depth_profile.add(
+        &chr_anchor.chr,
+        chr_anchor.position,
+        chr_anchor.reference_end(),
+      );
+      sv_chimeric_read.chr_read.push(chr_anchor);
+      // supplementary alignments follow primary
+      for supplementary in values.supplementary.iter() {
+        if supplementary.mapq < self.config.min_mapq {
+          continue;
+        }
+        sv_chimeric_read.chr_read.push(ChrAnchor::load(
+          supplementary.cigar.clone(),
+          supplementary.chr.clone(),
+          supplementary.flag(),
+          supplementary.mapq,
+          supplementary.position,
+          0,
+        ));
+      }
+
+      let offset = if values.cigar.left_clip >= values.cigar.rigth_clip {
+        -values.cigar.left_clip
+      } else {
+        values.cigar.rigth_clip
+      };
+      if offset.abs() >= self.min_clip {
+        // unaltered on records without stored sequence
+        sv_chimeric_read
+          .breakpoint
+          .update(
+            &values.sequence,
+            offset as i64,
+            self.config.breakpoint_flank,
+          )
+          .ok();
+      }
+    }
+
+    // warn once on skipped records
+    if skipped > 0 {
+      eprintln!(
+        "{} {} malformed alignment records skipped",
+        "warning:".yellow().bold(),
+        skipped
+      );
+    }
+
+    sv_chimeric_pairs.retain(|_, sv_chimeric_pair| {
+      !sv_chimeric_pair.read1.chr_read.is_empty() &&
+        !sv_chimeric_pair.read2.chr_read.is_empty()
+    });
+    Ok((sv_chimeric_pairs, depth_profile))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// call
+impl SVCaller {
+  ///
+  /// Call structural variants.
+  ///
+  /// Pairs are identified by discordant geometry & split reads by their
+  /// supplementary alignments, then clustered by variant type, breakend
+  /// orientation & proximity. Split reads place calls onto precise
+  /// breakends. Deletion & translocation junctions are characterized on
+  /// split reads when reference is passed. Deletions & duplications are
+  /// scored by read depth against flanking regions, corrected for GC content
+  /// when reference is passed. Clusters close to each other are finally
+  /// resolved onto complex variants.
+  ///
+  /// # Parameters
+  ///
+  /// * `reader` - SAM records aligned to reference assembly.
+  ///
+  /// * `reference` - Reference assembly to characterize junctions & correct
+  ///   read depth.
+  ///
+  /// # Returns
+  ///
+  /// Return calls above minimum support sorted by chromosome & position.
+  pub fn call<R: BufRead>(
+    &self,
+    reader: R,
+    reference: Option<&IndexedFasta>,
+  ) -> anyResult<Vec<SVCall>> {
+    let (mut sv_chimeric_pairs, mut depth_profile) = self.load(reader)?;
+    if let Some(reference) = reference {
+      depth_profile.gc_correct(reference)?;
+    }
+
+    // breakends & read IDs per variant type
+    let mut breakends: HashMap<SVType, Vec<(SVCall, String)>> = HashMap::new();
+    for (read_id, sv_chimeric_pair) in sv_chimeric_pairs.iter_mut() {
+      for sv_chimeric_read in
+        [&sv_chimeric_pair.read1, &sv_chimeric_pair.read2].iter()
+      {
+        for breakend in sv_chimeric_read.identify_split(&self.config) {
+          breakends
+            .entry(breakend.svtype)
+            .or_default()
+            .push((breakend, read_id.clone()));
+        }
+      }
+
+      if !sv_chimeric_pair.identify(self.expected_tlen, &self.config) {
+        continue;
+      }
+      breakends
+        .entry(sv_chimeric_pair.svtag)
+        .or_default()
+        .push((pair_breakends(sv_chimeric_pair), read_id.clone()));
+    }
+
+    let mut sv_calls = Vec::new();
+    for (_, mut evidence) in breakends {
+      evidence.sort_by(|(a, _), (b, _)| {
+        (&a.chr, &a.chr2, a.position).cmp(&(&b.chr, &b.chr2, b.position))
+      });
+      for (mut sv_call, read_ids) in self.cluster(evidence) {
+        if sv_call.support + sv_call.split_reads < self.min_support {
+          continue;
+        }
+        if let Some(reference) = reference {
+          self.junction(
+            &mut sv_call,
+            &read_ids,
+            &sv_chimeric_pairs,
+            reference,
+          )?;
+        }
+        if sv_call.svtype == SVType::Deletion ||
+          sv_call.svtype == SVType::Duplication
+        {
+          sv_call.depth_ratio = depth_profile.depth_ratio(
+            &sv_call.chr,
+            sv_call.position,
+            sv_call.end,
+            self.depth_flank,
+          );
+        }
+        sv_calls.push(sv_call);
+      }
+    }
+
+    // sorted before resolution for deterministic pairing
+    let order = |a: &SVCall, b: &SVCall| {
+      (&a.chr, a.position, a.end).cmp(&(&b.chr, b.position, b.end))
+    };
+    sv_calls.sort_by(order);
+    let mut sv_calls = resolve_complex(
+      sv_calls,
+      &depth_profile,
+      self.cluster_distance,
+      self.depth_flank,
+    );
+    sv_calls.sort_by(order);
+    Ok(sv_calls)
+  }
+
+  // cluster sorted breakends onto calls
+  fn cluster(
+    &self,
+    evidence: Vec<(SVCall, String)>,
+  ) -> Vec<(SVCall, Vec<String>)> {
+    let mut clusters: Vec<Cluster> = Vec::new();
+    for (breakend, read_id) in evidence {
+      let cluster = clusters
+        .iter_mut()
+        .rev()
+        .take_while(|(sv_call, ..)| {
+          sv_call.chr == breakend.chr &&
+            sv_call.chr2 == breakend.chr2 &&
+            (breakend.position - sv_call.position).unsigned_abs() <=
+              self.cluster_distance
+        })
+        .find(|(sv_call, ..)| {
+          sv_call.orientation == breakend.orientation &&
+            (breakend.end - sv_call.end).unsigned_abs() <=
+              self.cluster_distance
+        });
+      let split_breakends = if breakend.split_reads > 0 {
+        vec![(breakend.position, breakend.end)]
+      } else {
+        Vec::new()
+      };
+
+      match cluster {
+        Some((sv_call, read_ids, precise)) => {
+          if !split_breakends.is_empty() {
+            precise.extend(split_breakends);
+          // deletions narrow onto inner breakends, others span outer
+          } else if sv_call.svtype == SVType::Deletion {
+            sv_call.position = sv_call.position.max(breakend.position);
+            sv_call.end = sv_call.end.min(breakend.end);
+          } else {
+            sv_call.position = sv_call.position.min(breakend.position);
+            sv_call.end = sv_call.end.max(breakend.end);
+          }
+          sv_call.support += breakend.support;
+          sv_call.split_reads += breakend.split_reads;
+          read_ids.push(read_id);
+        }
+        None => clusters.push((breakend, vec![read_id], split_breakends)),
+      }
+    }
+
+    // split reads place calls onto most frequent breakends
+    clusters
+      .into_iter()
+      .map(|(mut sv_call, mut read_ids, precise)| {
+        if let Some((position, end)) = most_frequent(&precise) {
+          sv_call.position = position;
+          sv_call.end = end;
+        }
+        read_ids.sort();
+        read_ids.dedup();
+        (sv_call, read_ids)
+      })
+      .collect()
+  }
+
+  // characterize junction on split reads & place call onto it
+  fn junction(
+    &self,
+    sv_call: &mut SVCall,
+    read_ids: &[String],
+    sv_chimeric_pairs: &HashMap<String, SVChimericPair>,
+    reference: &IndexedFasta,
+  ) -> anyResult<()> {
+    if sv_call.svtype != SVType::Deletion &&
+      sv_call.svtype != SVType::Translocation
+    {
+      return Ok(());
+    }
+
+    let mut sv_junctions: Vec<SVJunction> = Vec::new();
+    for read_id in read_ids.iter() {
+      let sv_chimeric_pair = &sv_chimeric_pairs[read_id];
+      for sv_chimeric_read in
+        [&sv_chimeric_pair.read1, &sv_chimeric_pair.read2].iter()
+      {
+        let anchor = &sv_chimeric_read.chr_read[0];
+        // split reads open variant on clip downstream from anchor, or
+        // close it on clip upstream from anchor
+        let (chr, breakend) = match sv_chimeric_read.breakpoint.side {
+          ClipSideEnum::Right
+            if anchor.chr == sv_call.chr &&
+              (anchor.reference_end() - sv_call.position).unsigned_abs() <=
+                self.cluster_distance =>
+          {
+            (&sv_call.chr2, sv_call.end)
+          }
+          ClipSideEnum::Left
+            if anchor.chr == sv_call.chr2 &&
+              (anchor.position - sv_call.end).unsigned_abs() <=
+                self.cluster_distance =>
+          {
+            (&sv_call.chr, sv_call.position)
+          }
+          _ => continue,
+        };
+        if let Some(sv_junction) = characterize_junction(
+          reference,
+          anchor,
+          &sv_chimeric_read.breakpoint,
+          chr,
+          (
+            breakend - self.cluster_distance,
+            breakend + self.cluster_distance,
+          ),
+          self.max_junction,
+        )? {
+          sv_junctions.push(sv_junction);
+        }
+      }
+    }
+
+    let sv_junction = most_frequent(&sv_junctions);
+    if let Some(sv_junction) = &sv_junction {
+      if sv_call.svtype == SVType::Deletion {
+        sv_call.position = sv_junction.left + 1;
+        sv_call.end = sv_junction.right - 1;
+      } else {
+        sv_call.position = sv_junction.left;
+        sv_call.end = sv_junction.right;
+      }
+    }
+    sv_call.junction = sv_junction;
+    Ok(())
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// breakends on identified pair. leftmost anchor opens variant
+fn pair_breakends(sv_chimeric_pair: &SVChimericPair) -> SVCall {
+  let anchor1 = &sv_chimeric_pair.read1.chr_read[0];
+  let anchor2 = &sv_chimeric_pair.read2.chr_read[0];
+  let (left, right) =
+    if (&anchor1.chr, anchor1.position) <= (&anchor2.chr, anchor2.position) {
+      (anchor1, anchor2)
+    } else {
+      (anchor2, anchor1)
+    };
+
+  // forward anchors join bases after alignment, reverse anchors before it
+  let side = |anchor: &ChrAnchor| {
+    if anchor.interpret(5) {
+      ClipSideEnum::Left
+    } else {
+      ClipSideEnum::Right
+    }
+  };
+
+  let mut sv_call = SVCall::new(sv_chimeric_pair.svtag);
+  sv_call.chr = left.chr.clone();
+  sv_call.chr2 = right.chr.clone();
+  sv_call.support = 1;
+  sv_call.orientation = (side(left), side(right));
+  if sv_chimeric_pair.svtag == SVType::Deletion {
+    sv_call.position = left.reference_end() + 1;
+    sv_call.end = right.position - 1;
+  } else {
+    sv_call.position = left.position;
+    sv_call.end = right.reference_end();
+  }
+  sv_call
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// most frequent item, first on ties
+fn most_frequent<T: Clone + PartialEq>(items: &[T]) -> Option<T> {
+  items
+    .iter()
+    .rev()
+    .max_by_key(|item| items.iter().filter(|other| other == item).count())
+    .cloned()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -4,6 +4,7 @@
 use crate::{
   function::identificator::identify,
   custom::{
+    detection_config::DetectionConfig,
     sv_chimeric_read::SVChimericRead,
     sv_type::SVType,
   },
@@ -34,8 +35,9 @@ impl SVChimericPair {
   pub fn identify(
     &mut self,
     expected_tlen: i32,
+    config: &DetectionConfig,
   ) -> bool {
-    identify(self, expected_tlen)
+    identify(self, expected_tlen, config)
   }
 }
 
@@ -1,9 +1,14 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::custom::{
-  break_point::BreakPoint,
-  chr_anchor::ChrAnchor,
+use crate::{
+  function::identificator::identify_split,
+  custom::{
+    break_point::BreakPoint,
+    chr_anchor::ChrAnchor,
+    detection_config::DetectionConfig,
+    sv_call::SVCall,
+  },
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -25,3 +30,15 @@ pub struct SVChimericRead {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl SVChimericRead {
+  /// Identify structural variants on split read, see `identify_split`.
+  pub fn identify_split(
+    &self,
+    config: &DetectionConfig,
+  ) -> Vec<SVCall> {
+    identify_split(self, config)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
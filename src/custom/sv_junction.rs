@@ -0,0 +1,95 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  position::Position,
+  repair_enum::RepairEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// shortest microhomology pointing to microhomology mediated end joining
+const MIN_MICROHOMOLOGY: usize = 2;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Structural variant junction resolved by split reads.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct SVJunction {
+  /// Last reference base before junction. 1-based, leftmost when junction
+  /// is ambiguous over microhomology.
+  pub left: Position,
+
+  /// First reference base after junction, on mate breakend chromosome.
+  /// 1-based, leftmost when junction is ambiguous over microhomology.
+  pub right: Position,
+
+  /// Microhomology, i.e., bases shared by both sides of junction.
+  #[new(default)]
+  pub homology: String,
+
+  /// Non-templated inserted sequence.
+  #[new(default)]
+  pub insertion: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl SVJunction {
+  /// Retrieve microhomology length.
+  pub fn homology_length(&self) -> usize {
+    self.homology.len()
+  }
+
+  ///
+  /// Retrieve repair mechanism suggested by junction.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::{
+  ///   Position,
+  ///   RepairEnum,
+  ///   SVJunction,
+  /// };
+  ///
+  /// let mut sv_junction =
+  ///   SVJunction::new(Position::OneBased(1000), Position::OneBased(2001));
+  /// assert_eq!(sv_junction.repair(), RepairEnum::EndJoining);
+  ///
+  /// sv_junction.homology = "TAG".to_string();
+  /// assert_eq!(sv_junction.repair(), RepairEnum::MicrohomologyMediated);
+  /// ```
+  pub fn repair(&self) -> RepairEnum {
+    if self.insertion.is_empty() && self.homology_length() >= MIN_MICROHOMOLOGY
+    {
+      RepairEnum::MicrohomologyMediated
+    } else {
+      RepairEnum::EndJoining
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for SVJunction {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t",
+      self.left, self.right, self.homology, self.insertion
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
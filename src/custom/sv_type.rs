@@ -1,14 +1,87 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Identify type of structural variant.
-#[derive(Debug)]
+///
+/// `Duplication` labels tandem duplications. Complex types are resolved from
+/// breakend clusters after calling. `Translocation` labels lone breakend
+/// clusters before resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SVType {
   Deletion,
   Duplication,
   Inversion,
   Insertion,
   Translocation,
+  DispersedDuplication,
+  InvertedDuplication,
+  DeletionFlankedInversion,
+  BalancedTranslocation,
+  UnbalancedTranslocation,
   None,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// get
+impl SVType {
+  /// Check whether variant is reported as breakends, i.e., translocations.
+  pub fn is_breakend(&self) -> bool {
+    matches!(
+      self,
+      SVType::Translocation |
+        SVType::BalancedTranslocation |
+        SVType::UnbalancedTranslocation
+    )
+  }
+
+  ///
+  /// Retrieve VCF symbolic allele, sub-typed on complex variants.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use genomic_structures::SVType;
+  ///
+  /// assert_eq!(SVType::Deletion.alt(), "<DEL>");
+  /// assert_eq!(SVType::InvertedDuplication.alt(), "<DUP:INV>");
+  /// ```
+  pub fn alt(&self) -> String {
+    match self {
+      SVType::DispersedDuplication => "<DUP:DISPERSED>".to_string(),
+      SVType::InvertedDuplication => "<DUP:INV>".to_string(),
+      SVType::DeletionFlankedInversion => "<INV:DEL>".to_string(),
+      svtype => format!("<{}>", svtype),
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation as VCF SVTYPE
+impl fmt::Display for SVType {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    let svtype = match self {
+      SVType::Deletion => "DEL",
+      SVType::Duplication |
+      SVType::DispersedDuplication |
+      SVType::InvertedDuplication => "DUP",
+      SVType::Inversion | SVType::DeletionFlankedInversion => "INV",
+      SVType::Insertion => "INS",
+      SVType::Translocation |
+      SVType::BalancedTranslocation |
+      SVType::UnbalancedTranslocation => "BND",
+      SVType::None => ".",
+    };
+    write!(f, "{}", svtype)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
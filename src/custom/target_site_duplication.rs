@@ -0,0 +1,68 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  position::Position,
+  refined_break_point::RefinedBreakPoint,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Target site duplication (TSD) at mobile element insertion.
+#[derive(Debug, new, Clone, Default, PartialEq)]
+pub struct TargetSiteDuplication {
+  /// Chromosomal allocation.
+  #[new(default)]
+  pub chr: String,
+
+  /// Duplication start. 1-based.
+  #[new(default)]
+  pub start: Position,
+
+  /// Duplication end. 1-based, inclusive.
+  #[new(default)]
+  pub end: Position,
+
+  /// Duplication length.
+  #[new(default)]
+  pub length: u64,
+
+  /// Duplicated sequence.
+  #[new(default)]
+  pub sequence: String,
+
+  /// Split reads supporting both junctions.
+  #[new(default)]
+  pub support: usize,
+
+  /// Left junction, i.e., reads clipped on the right.
+  #[new(default)]
+  pub left: RefinedBreakPoint,
+
+  /// Right junction, i.e., reads clipped on the left.
+  #[new(default)]
+  pub right: RefinedBreakPoint,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display trait implementation
+impl fmt::Display for TargetSiteDuplication {
+  fn fmt(
+    &self,
+    f: &mut fmt::Formatter,
+  ) -> fmt::Result {
+    writeln!(
+      f,
+      "{}\t{}\t{}\t{}\t{}\t{}",
+      self.chr, self.start, self.end, self.length, self.sequence, self.support
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,6 +11,21 @@ use thiserror::Error;
 pub enum CommonError {
   #[error("\n{}\n", "Fail to parse".red())]
   Parsing,
+
+  #[error("\n{}\n", "Fail to read".red())]
+  Reading,
+
+  #[error("\n{}\n", "Fail to write".red())]
+  Writing,
+
+  #[error("\n{}\n", "Empty sequence".red())]
+  EmptySequence,
+
+  #[error("\n{}\n", "Sequence not found on index".red())]
+  MissingSequence,
+
+  #[error("\n{}\n", "Coordinate out of sequence bounds".red())]
+  OutOfBounds,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
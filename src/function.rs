@@ -1,10 +1,25 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // modules
+pub mod alignment_decoder;
+pub mod break_point_refiner;
+pub mod complex_resolver;
+pub mod fasta_indexer;
 pub mod flag_interpretor;
+pub mod gene_annotator;
+pub mod genotype_likelihood;
 pub mod identificator;
+pub mod junction_analyzer;
+pub mod library_loader;
+pub mod me_classifier;
+pub mod me_deletion_detector;
+pub mod me_summarizer;
 pub mod position_binner;
 pub mod sequences;
+pub mod tail_detector;
 pub mod thresholder;
+pub mod tsd_detector;
+pub mod vcf_loader;
+pub mod vcf_writer;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,75 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use flate2::read::MultiGzDecoder;
+use std::io::{
+  BufRead,
+  BufReader,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::bam_reader::{
+    BamReader,
+    BAM_MAGIC,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// gzip magic, shared by BGZF blocks
+const GZIP_MAGIC: [u8; 2] = [31, 139];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Decode alignment stream onto SAM text.
+///
+/// Compression is detected from leading bytes. BGZF (or gzip) streams are
+/// decompressed & BAM records converted to SAM lines. Plain text is passed
+/// through.
+///
+/// # Parameters
+///
+/// * `reader` - SAM, compressed SAM or BAM stream.
+///
+/// # Returns
+///
+/// Return SAM text reader. Error on unreadable stream or malformed BAM
+/// header.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::decode_alignment;
+/// use std::io::BufRead;
+///
+/// let sam = "read\t0\tchr1\t101\t60\t100M\t*\t0\t0\t*\t*\n";
+/// let decoded =
+///   decode_alignment(sam.as_bytes()).expect("Alignment decoding failed!");
+///
+/// assert_eq!(decoded.lines().count(), 1);
+/// ```
+pub fn decode_alignment<'a, R: BufRead + 'a>(
+  mut reader: R
+) -> anyResult<Box<dyn BufRead + 'a>> {
+  let leading = reader.fill_buf().context(CommonError::Reading)?;
+  if !leading.starts_with(&GZIP_MAGIC) {
+    return Ok(Box::new(reader));
+  }
+
+  let mut decoder = BufReader::new(MultiGzDecoder::new(reader));
+  let leading = decoder.fill_buf().context(CommonError::Reading)?;
+  if leading.starts_with(&BAM_MAGIC) {
+    Ok(Box::new(BufReader::new(BamReader::load(decoder)?)))
+  } else {
+    Ok(Box::new(decoder))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,192 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::BTreeMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  clip_side_enum::ClipSideEnum,
+  me_chimeric_read::MEChimericRead,
+  position::Position,
+  refined_break_point::RefinedBreakPoint,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// confidence interval tail probability
+const CI_TAIL: f64 = 0.025;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Build clip-position histogram from chosen chromosomal anchors.
+///
+/// Ambiguous anchors are excluded.
+///
+/// # Parameters
+///
+/// * `me_chimeric_reads` - Reads at candidate site.
+///
+/// * `side` - Read clip side. `ClipSideEnum::None` collects both sides.
+///
+/// * `min_clip` - Minimum clipped bases to consider read as split read.
+///
+/// # Returns
+///
+/// Return split read count keyed by chromosome & junction coordinate.
+pub fn clip_histogram<'a, I>(
+  me_chimeric_reads: I,
+  side: ClipSideEnum,
+  min_clip: i32,
+) -> BTreeMap<(String, Position), usize>
+where
+  I: IntoIterator<Item = &'a MEChimericRead>,
+{
+  let sides = match side {
+    ClipSideEnum::None => vec![ClipSideEnum::Left, ClipSideEnum::Right],
+    _ => vec![side],
+  };
+
+  let mut histogram = BTreeMap::new();
+  for me_chimeric_read in me_chimeric_reads {
+    if let Some(chr_anchor) = me_chimeric_read
+      .chr_anchor()
+      .filter(|chr_anchor| !chr_anchor.ambiguous)
+    {
+      for clip_side in sides.iter() {
+        if chr_anchor.cigar.clip_length(*clip_side) < min_clip {
+          continue;
+        }
+        if let Some(junction) = chr_anchor.cigar.clip_junction(*clip_side) {
+          *histogram
+            .entry((chr_anchor.chr.clone(), junction))
+            .or_insert(0) += 1;
+        }
+      }
+    }
+  }
+  histogram
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Refine break point to base-pair resolution from split reads.
+///
+/// Consensus is the most frequent junction coordinate, ties resolved to the
+/// leftmost coordinate. Split reads within window of consensus support break
+/// point & delimit a 95% confidence interval.
+///
+/// # Parameters
+///
+/// * `me_chimeric_reads` - Reads at candidate site.
+///
+/// * `side` - Read clip side. `ClipSideEnum::None` collects both sides.
+///
+/// * `window` - Distance from consensus to collect supporting split reads.
+///
+/// * `min_clip` - Minimum clipped bases to consider read as split read.
+///
+/// # Returns
+///
+/// Return refined break point. `None` when no split reads are found.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   refine_break_point,
+///   ChrAnchor,
+///   ClipSideEnum,
+///   MEChimericRead,
+///   Position,
+///   CIGAR,
+/// };
+///
+/// let me_chimeric_reads = [
+///   ("30S70M", Position::OneBased(1001)),
+///   ("40S60M", Position::OneBased(1001)),
+///   ("20S80M", Position::OneBased(1003)),
+/// ]
+/// .iter()
+/// .map(|(cigar, position)| {
+///   let mut me_chimeric_read = MEChimericRead::new();
+///   me_chimeric_read.chr_read.push(ChrAnchor::load(
+///     CIGAR::load(cigar, *position).expect("CIGAR loading failed!"),
+///     "chr1".to_string(),
+///     0,
+///     60,
+///     *position,
+///     0,
+///   ));
+///   me_chimeric_read
+/// })
+/// .collect::<Vec<MEChimericRead>>();
+///
+/// let refined =
+///   refine_break_point(&me_chimeric_reads, ClipSideEnum::Left, 10, 5)
+///     .expect("No split reads found!");
+///
+/// assert_eq!(refined.position, Position::OneBased(1001));
+/// assert_eq!(
+///   (refined.ci_start, refined.ci_end),
+///   (Position::OneBased(1001), Position::OneBased(1003))
+/// );
+/// assert_eq!(refined.split_reads, 3);
+/// assert_eq!(refined.exact_reads, 2);
+/// ```
+pub fn refine_break_point<'a, I>(
+  me_chimeric_reads: I,
+  side: ClipSideEnum,
+  window: u64,
+  min_clip: i32,
+) -> Option<RefinedBreakPoint>
+where
+  I: IntoIterator<Item = &'a MEChimericRead>,
+{
+  let histogram = clip_histogram(me_chimeric_reads, side, min_clip);
+
+  // consensus
+  let mut consensus: Option<(&(String, Position), &usize)> = None;
+  for (key, count) in histogram.iter() {
+    match consensus {
+      Some((_, max)) if count <= max => (),
+      _ => consensus = Some((key, count)),
+    }
+  }
+  let ((chr, position), exact_reads) = consensus?;
+
+  // supporting split reads
+  let mut supporting = Vec::new();
+  for ((hist_chr, hist_position), count) in histogram.iter() {
+    if hist_chr == chr && (*hist_position - *position).unsigned_abs() <= window
+    {
+      supporting.extend(std::iter::repeat(*hist_position).take(*count));
+    }
+  }
+
+  let mut refined = RefinedBreakPoint::new();
+  refined.chr = chr.clone();
+  refined.position = *position;
+  refined.ci_start = percentile(&supporting, CI_TAIL);
+  refined.ci_end = percentile(&supporting, 1. - CI_TAIL);
+  refined.side = side;
+  refined.split_reads = supporting.len();
+  refined.exact_reads = *exact_reads;
+  Some(refined)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// nearest rank percentile on sorted values
+fn percentile(
+  sorted: &[Position],
+  probability: f64,
+) -> Position {
+  let ix = ((sorted.len() - 1) as f64 * probability).round() as usize;
+  sorted[ix]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,330 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    depth_profile::DepthProfile,
+    position::Position,
+    sv_call::SVCall,
+    sv_type::SVType,
+  },
+  MAX_DELETION_DEPTH,
+  MIN_DUPLICATION_DEPTH,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Resolve complex structural variants from breakend clusters.
+///
+/// Clusters on the same chromosomes with complementary orientation, i.e.,
+/// both junction sides flipped, are combined when at least one breakend lies
+/// within distance:
+///
+/// * Reciprocal translocations, both breakends within distance, onto balanced
+///   translocations. Remaining translocations are labeled unbalanced only
+///   when read depth drops past the breakend, i.e., the segment joined away
+///   is lost.
+///
+/// * Inversion junctions onto inversions. Segments between junctions are
+///   scored by read depth, resolving deletion-flanked inversions on deleted
+///   segments & inverted duplications on duplicated segments.
+///
+/// * Deletion & duplication junctions, or translocations, sharing a single
+///   breakend onto dispersed duplications.
+///
+/// # Parameters
+///
+/// * `sv_calls` - Clustered calls sorted by chromosome & position.
+///
+/// * `depth_profile` - Read depth to score segments between junctions.
+///
+/// * `distance` - Maximum distance between shared breakends.
+///
+/// * `flank` - Flanking region length compared against segments.
+///
+/// # Returns
+///
+/// Return complex calls, carrying combined clusters as components, along
+/// with unresolved calls in input order.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   resolve_complex,
+///   ClipSideEnum,
+///   DepthProfile,
+///   Position,
+///   SVCall,
+///   SVType,
+/// };
+///
+/// // reciprocal junctions between chr1 & chr2
+/// let breakend = |position, end, orientation| {
+///   let mut sv_call = SVCall::new(SVType::Translocation);
+///   sv_call.chr = "chr1".to_string();
+///   sv_call.chr2 = "chr2".to_string();
+///   sv_call.position = Position::OneBased(position);
+///   sv_call.end = Position::OneBased(end);
+///   sv_call.orientation = orientation;
+///   sv_call
+/// };
+/// let sv_calls = vec![
+///   breakend(5000, 8001, (ClipSideEnum::Right, ClipSideEnum::Left)),
+///   breakend(5001, 8000, (ClipSideEnum::Left, ClipSideEnum::Right)),
+/// ];
+///
+/// let sv_calls =
+///   resolve_complex(sv_calls, &DepthProfile::new(100), 500, 1000);
+///
+/// assert_eq!(sv_calls.len(), 1);
+/// assert_eq!(sv_calls[0].svtype, SVType::BalancedTranslocation);
+/// assert_eq!(sv_calls[0].components.len(), 2);
+/// ```
+pub fn resolve_complex(
+  sv_calls: Vec<SVCall>,
+  depth_profile: &DepthProfile,
+  distance: u64,
+  flank: u64,
+) -> Vec<SVCall> {
+  // call order by chromosome & end, to reach clusters sharing end breakends
+  let mut by_end = (0..sv_calls.len()).collect::<Vec<usize>>();
+  by_end.sort_by(|a, b| {
+    let (a, b) = (&sv_calls[*a], &sv_calls[*b]);
+    (&a.chr, a.end).cmp(&(&b.chr, b.end))
+  });
+  let mut end_rank = vec![0; sv_calls.len()];
+  for (rank, ix) in by_end.iter().enumerate() {
+    end_rank[*ix] = rank;
+  }
+
+  let mut resolved = Vec::new();
+  let mut combined = vec![false; sv_calls.len()];
+  for (ix, sv_call) in sv_calls.iter().enumerate() {
+    if combined[ix] {
+      continue;
+    }
+
+    // scans stop past distance on either breakend
+    let mut candidates = sv_calls
+      .iter()
+      .enumerate()
+      .skip(ix + 1)
+      .take_while(|(_, other)| {
+        other.chr == sv_call.chr &&
+          other.position - sv_call.position <= distance as i64
+      })
+      .map(|(jx, _)| jx)
+      .collect::<Vec<usize>>();
+    let within = |jx: &&usize| {
+      let other = &sv_calls[**jx];
+      other.chr == sv_call.chr &&
+        (other.end - sv_call.end).unsigned_abs() <= distance
+    };
+    candidates.extend(by_end[..end_rank[ix]].iter().rev().take_while(within));
+    candidates.extend(by_end[end_rank[ix] + 1..].iter().take_while(within));
+    candidates.retain(|jx| *jx > ix);
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let complex = candidates
+      .into_iter()
+      .filter(|jx| {
+        !combined[*jx] && complementary(sv_call, &sv_calls[*jx], distance)
+      })
+      .find_map(|jx| {
+        combine(sv_call, &sv_calls[jx], depth_profile, distance, flank)
+          .map(|complex| (jx, complex))
+      });
+
+    match complex {
+      Some((jx, complex)) => {
+        combined[jx] = true;
+        resolved.push(complex);
+      }
+      None => {
+        let mut sv_call = sv_call.clone();
+        if sv_call.svtype == SVType::Translocation {
+          if let Some(ratio) = imbalance(&sv_call, depth_profile, flank) {
+            sv_call.svtype = SVType::UnbalancedTranslocation;
+            sv_call.depth_ratio = Some(ratio);
+          }
+        }
+        resolved.push(sv_call);
+      }
+    }
+  }
+  resolved
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// same chromosomes, flipped junction sides & at least one breakend shared
+fn complementary(
+  sv_call: &SVCall,
+  other: &SVCall,
+  distance: u64,
+) -> bool {
+  let flip = |side: ClipSideEnum| match side {
+    ClipSideEnum::Left => ClipSideEnum::Right,
+    ClipSideEnum::Right => ClipSideEnum::Left,
+    ClipSideEnum::None => ClipSideEnum::None,
+  };
+  sv_call.chr == other.chr &&
+    sv_call.chr2 == other.chr2 &&
+    sv_call.orientation.0 != ClipSideEnum::None &&
+    sv_call.orientation.1 != ClipSideEnum::None &&
+    sv_call.orientation.0 == flip(other.orientation.0) &&
+    sv_call.orientation.1 == flip(other.orientation.1) &&
+    ((sv_call.position - other.position).unsigned_abs() <= distance ||
+      (sv_call.end - other.end).unsigned_abs() <= distance)
+}
+
+// depth past breakend over retained side, when low enough to support loss
+fn imbalance(
+  sv_call: &SVCall,
+  depth_profile: &DepthProfile,
+  flank: u64,
+) -> Option<f64> {
+  let position = sv_call.position;
+  let (lost, retained) = match sv_call.orientation.0 {
+    ClipSideEnum::Right => {
+      ((position + 1, position + flank), (position - flank + 1, position))
+    }
+    ClipSideEnum::Left => {
+      ((position - flank, position - 1), (position, position + flank - 1))
+    }
+    ClipSideEnum::None => return None,
+  };
+  let lost = depth_profile.depth(&sv_call.chr, lost.0, lost.1)?;
+  let retained = depth_profile.depth(&sv_call.chr, retained.0, retained.1)?;
+  if retained <= 0. {
+    return None;
+  }
+  Some(lost / retained).filter(|ratio| *ratio <= MAX_DELETION_DEPTH)
+}
+
+// complex variant on complementary clusters
+fn combine(
+  sv_call: &SVCall,
+  other: &SVCall,
+  depth_profile: &DepthProfile,
+  distance: u64,
+  flank: u64,
+) -> Option<SVCall> {
+  let shared = (
+    (sv_call.position - other.position).unsigned_abs() <= distance,
+    (sv_call.end - other.end).unsigned_abs() <= distance,
+  );
+
+  // segment between unshared breakends, on mate chromosome when starts match
+  let segment = match shared {
+    (true, false) => Some((
+      &sv_call.chr2,
+      sv_call.end.min(other.end),
+      sv_call.end.max(other.end),
+    )),
+    (false, true) => Some((
+      &sv_call.chr,
+      sv_call.position.min(other.position),
+      sv_call.position.max(other.position),
+    )),
+    _ => None,
+  };
+  let segment_depth = segment.and_then(|(chr, start, end)| {
+    depth_profile.depth_ratio(chr, start + 1, end - 1, flank)
+  });
+
+  let complex = match (sv_call.svtype, other.svtype) {
+    (SVType::Translocation, SVType::Translocation)
+      if shared == (true, true) =>
+    {
+      merge(SVType::BalancedTranslocation, sv_call, other)
+    }
+    (SVType::Inversion, SVType::Inversion) => {
+      // segments between inversion junctions on each side
+      let gaps = [
+        (
+          sv_call.position.min(other.position),
+          sv_call.position.max(other.position),
+        ),
+        (sv_call.end.min(other.end), sv_call.end.max(other.end)),
+      ];
+      let depths = gaps
+        .iter()
+        .filter(|(start, end)| *end - *start > depth_profile.bin_size as i64)
+        .filter_map(|(start, end)| {
+          depth_profile.depth_ratio(&sv_call.chr, *start + 1, *end - 1, flank)
+        })
+        .collect::<Vec<f64>>();
+
+      let deleted = depths.iter().find(|ratio| **ratio <= MAX_DELETION_DEPTH);
+      let duplicated = segment_depth.filter(|ratio| {
+        *ratio >= MIN_DUPLICATION_DEPTH
+      });
+      if let Some(ratio) = deleted {
+        let mut complex =
+          merge(SVType::DeletionFlankedInversion, sv_call, other);
+        complex.depth_ratio = Some(*ratio);
+        complex
+      } else if duplicated.is_some() {
+        let mut complex = merge(SVType::InvertedDuplication, sv_call, other);
+        complex.depth_ratio = duplicated;
+        place(&mut complex, segment?);
+        complex
+      } else if shared == (true, true) {
+        merge(SVType::Inversion, sv_call, other)
+      } else {
+        return None;
+      }
+    }
+    (SVType::Deletion, SVType::Duplication) |
+    (SVType::Duplication, SVType::Deletion) |
+    (SVType::Translocation, SVType::Translocation) => {
+      // segment lost on source
+      if matches!(segment_depth, Some(ratio) if ratio <= MAX_DELETION_DEPTH) {
+        return None;
+      }
+      let mut complex = merge(SVType::DispersedDuplication, sv_call, other);
+      complex.depth_ratio = segment_depth;
+      place(&mut complex, segment?);
+      complex
+    }
+    _ => return None,
+  };
+
+  Some(complex)
+}
+
+// complex call spanning both clusters, with summed support
+fn merge(
+  svtype: SVType,
+  sv_call: &SVCall,
+  other: &SVCall,
+) -> SVCall {
+  let mut complex = SVCall::new(svtype);
+  complex.chr = sv_call.chr.clone();
+  complex.chr2 = sv_call.chr2.clone();
+  complex.position = sv_call.position.min(other.position);
+  complex.end = sv_call.end.max(other.end);
+  complex.support = sv_call.support + other.support;
+  complex.split_reads = sv_call.split_reads + other.split_reads;
+  complex.orientation = sv_call.orientation;
+  complex.components = vec![sv_call.clone(), other.clone()];
+  complex
+}
+
+// place complex call onto duplicated segment
+fn place(
+  complex: &mut SVCall,
+  (chr, start, end): (&String, Position, Position),
+) {
+  complex.chr = chr.clone();
+  complex.chr2 = chr.clone();
+  complex.position = start;
+  complex.end = end;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,283 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::io::{
+  BufRead,
+  ErrorKind,
+  Read,
+  Write,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::fasta_index_record::FastaIndexRecord,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// BGZF block header, i.e., gzip magic, deflate, extra field flag
+const BGZF_MAGIC: [u8; 4] = [31, 139, 8, 4];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Index uncompressed FASTA.
+///
+/// Lines within a sequence must hold the same number of bases, except the
+/// last, as required for random access.
+///
+/// # Parameters
+///
+/// * `reader` - Uncompressed FASTA.
+///
+/// # Returns
+///
+/// Return .fai records in file order. Error on sequence lines before any
+/// header or on uneven line lengths.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::index_fasta;
+///
+/// let fasta = ">chr1 assembled\nACGTA\nCGTAC\nGT\n>chr2\nTTTT\n";
+/// let records = index_fasta(fasta.as_bytes()).expect("Indexing failed!");
+///
+/// assert_eq!(records[0].to_string(), "chr1\t12\t16\t5\t6");
+/// assert_eq!(records[1].to_string(), "chr2\t4\t37\t4\t5");
+/// ```
+pub fn index_fasta<R: BufRead>(
+  mut reader: R
+) -> anyResult<Vec<FastaIndexRecord>> {
+  let mut records: Vec<FastaIndexRecord> = Vec::new();
+  let mut offset = 0;
+  let mut line = String::new();
+  // whether a line shorter than line width closed the sequence
+  let mut closed = false;
+  loop {
+    line.clear();
+    let width =
+      reader.read_line(&mut line).context(CommonError::Reading)? as u64;
+    if width == 0 {
+      break;
+    }
+    offset += width;
+
+    if let Some(header) = line.strip_prefix('>') {
+      let name = header
+        .split_whitespace()
+        .next()
+        .context(CommonError::Parsing)?;
+      records.push(FastaIndexRecord::new(name.to_string(), offset));
+      closed = false;
+      continue;
+    }
+
+    let record = records.last_mut().context(CommonError::Parsing)?;
+    let terminated = line.ends_with('\n');
+    let bases = line.trim_end_matches(&['\n', '\r'][..]).len() as u64;
+    if bases == 0 {
+      closed = true;
+      continue;
+    }
+    if record.line_bases == 0 {
+      record.line_bases = bases;
+      record.line_width = width;
+    } else if closed ||
+      bases > record.line_bases ||
+      (terminated && bases == record.line_bases && width != record.line_width)
+    {
+      return Err(CommonError::Parsing.into());
+    }
+    closed = bases < record.line_bases;
+    record.length += bases;
+  }
+  Ok(records)
+}
+
+///
+/// Index BGZF block offsets, i.e., .gzi.
+///
+/// # Parameters
+///
+/// * `reader` - BGZF compressed file.
+///
+/// # Returns
+///
+/// Return compressed & uncompressed offsets at every block start but the
+/// first. Error on non BGZF input.
+pub fn index_bgzf<R: Read>(mut reader: R) -> anyResult<Vec<(u64, u64)>> {
+  let mut blocks = Vec::new();
+  let mut compressed = 0;
+  let mut uncompressed = 0;
+  loop {
+    let mut header = [0; 12];
+    match reader.read_exact(&mut header) {
+      Ok(()) => (),
+      Err(error) if error.kind() == ErrorKind::UnexpectedEof => break,
+      Err(error) => return Err(error).context(CommonError::Reading),
+    }
+    if header[..4] != BGZF_MAGIC {
+      return Err(CommonError::Parsing.into());
+    }
+
+    // block size on BC extra subfield
+    let extra_length = u16::from_le_bytes([header[10], header[11]]) as usize;
+    let mut extra = vec![0; extra_length];
+    reader.read_exact(&mut extra).context(CommonError::Reading)?;
+    let block_size = bgzf_block_size(&extra).context(CommonError::Parsing)?;
+
+    // skip compressed data & CRC32, then read uncompressed size
+    let remainder = block_size
+      .checked_sub(12 + extra_length as u64)
+      .filter(|remainder| *remainder >= 8)
+      .context(CommonError::Parsing)?;
+    let mut trailer = vec![0; remainder as usize];
+    reader.read_exact(&mut trailer).context(CommonError::Reading)?;
+    let isize_bytes = &trailer[trailer.len() - 4..];
+    let isize = u32::from_le_bytes([
+      isize_bytes[0],
+      isize_bytes[1],
+      isize_bytes[2],
+      isize_bytes[3],
+    ]);
+
+    if compressed > 0 && isize > 0 {
+      blocks.push((compressed, uncompressed));
+    }
+    compressed += block_size;
+    uncompressed += isize as u64;
+  }
+  Ok(blocks)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Load FASTA index (.fai).
+///
+/// # Parameters
+///
+/// * `reader` - .fai formatted index.
+///
+/// # Returns
+///
+/// Return index records in file order.
+pub fn load_fai<R: BufRead>(reader: R) -> anyResult<Vec<FastaIndexRecord>> {
+  let mut records = Vec::new();
+  for line in reader.lines() {
+    let line = line.context(CommonError::Reading)?;
+    if line.trim().is_empty() {
+      continue;
+    }
+    records.push(FastaIndexRecord::load(&line)?);
+  }
+  Ok(records)
+}
+
+///
+/// Load BGZF block index (.gzi).
+///
+/// # Parameters
+///
+/// * `reader` - .gzi formatted index, i.e., little endian block count
+///   followed by compressed & uncompressed offset pairs.
+///
+/// # Returns
+///
+/// Return compressed & uncompressed block offsets.
+pub fn load_gzi<R: Read>(mut reader: R) -> anyResult<Vec<(u64, u64)>> {
+  let count = read_u64(&mut reader)?;
+  let mut blocks = Vec::new();
+  for _ in 0..count {
+    blocks.push((read_u64(&mut reader)?, read_u64(&mut reader)?));
+  }
+  Ok(blocks)
+}
+
+///
+/// Write FASTA index (.fai).
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `records` - Index records.
+pub fn write_fai<W: Write>(
+  writer: &mut W,
+  records: &[FastaIndexRecord],
+) -> anyResult<()> {
+  for record in records.iter() {
+    writeln!(writer, "{}", record).context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+///
+/// Write BGZF block index (.gzi).
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `blocks` - Compressed & uncompressed block offsets.
+pub fn write_gzi<W: Write>(
+  writer: &mut W,
+  blocks: &[(u64, u64)],
+) -> anyResult<()> {
+  writer
+    .write_all(&(blocks.len() as u64).to_le_bytes())
+    .context(CommonError::Writing)?;
+  for (compressed, uncompressed) in blocks.iter() {
+    writer
+      .write_all(&compressed.to_le_bytes())
+      .context(CommonError::Writing)?;
+    writer
+      .write_all(&uncompressed.to_le_bytes())
+      .context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Check whether leading bytes open a BGZF block.
+///
+/// # Parameters
+///
+/// * `bytes` - Leading file bytes.
+pub fn is_bgzf(bytes: &[u8]) -> bool {
+  bytes.len() >= 4 && bytes[..4] == BGZF_MAGIC
+}
+
+// total block size from BC extra subfield
+fn bgzf_block_size(extra: &[u8]) -> Option<u64> {
+  let mut ix = 0;
+  while ix + 4 <= extra.len() {
+    let length = u16::from_le_bytes([extra[ix + 2], extra[ix + 3]]) as usize;
+    if ix + 4 + length > extra.len() {
+      return None;
+    }
+    if extra[ix] == b'B' && extra[ix + 1] == b'C' && length == 2 {
+      let bsize = u16::from_le_bytes([extra[ix + 4], extra[ix + 5]]);
+      return Some(bsize as u64 + 1);
+    }
+    ix += 4 + length;
+  }
+  None
+}
+
+// little endian unsigned integer
+fn read_u64<R: Read>(reader: &mut R) -> anyResult<u64> {
+  let mut bytes = [0; 8];
+  reader.read_exact(&mut bytes).context(CommonError::Reading)?;
+  Ok(u64::from_le_bytes(bytes))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -16,6 +16,8 @@
 /// 11) read is PCR or optical duplicate.
 /// 12) supplementary alignment.
 ///
+/// Flags outside of the 12 bit range set no property.
+///
 /// # Examples
 ///
 /// ```
@@ -26,11 +28,15 @@
 /// assert_eq!(interpret(2165, 3), true);
 /// assert_eq!(interpret(133, 7), false);
 /// assert_eq!(interpret(157, 5), true);
+/// assert_eq!(interpret(4097, 1), false);
 /// ```
 pub fn interpret(
   n: i32,
   p: usize,
 ) -> bool {
+  if !(0..4096).contains(&n) {
+    return false;
+  }
   let bin_n: String = format!("{:b}", n).chars().rev().collect();
   let mut stat_array = ['0'; 12];
   for i in bin_n.char_indices() {
@@ -0,0 +1,89 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  feature_index::FeatureIndex,
+  me_insertion_call::MEInsertionCall,
+  sv_call::SVCall,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Annotate mobile element insertion calls with gene features.
+///
+/// # Parameters
+///
+/// * `me_insertion_calls` - Insertion calls.
+///
+/// * `feature_index` - Gene features.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   annotate_me_insertions,
+///   FeatureEnum,
+///   FeatureIndex,
+///   MEInsertionCall,
+///   Position,
+/// };
+///
+/// let gff3 = "chr1\tsrc\tgene\t5001\t9000\t.\t-\t.\tID=g1;Name=BRCA1\n";
+/// let feature_index =
+///   FeatureIndex::load(gff3.as_bytes()).expect("Annotation loading failed!");
+///
+/// let mut me_insertion_call = MEInsertionCall::new();
+/// me_insertion_call.chr = "chr1".to_string();
+/// me_insertion_call.position = Position::OneBased(9500);
+///
+/// let mut me_insertion_calls = vec![me_insertion_call];
+/// annotate_me_insertions(&mut me_insertion_calls, &feature_index);
+///
+/// let gene_annotation = me_insertion_calls[0]
+///   .gene_annotation
+///   .as_ref()
+///   .expect("Annotation missing!");
+/// assert_eq!(gene_annotation.feature, FeatureEnum::Promoter);
+/// assert_eq!(gene_annotation.distance, 500);
+/// ```
+pub fn annotate_me_insertions(
+  me_insertion_calls: &mut [MEInsertionCall],
+  feature_index: &FeatureIndex,
+) {
+  for me_insertion_call in me_insertion_calls.iter_mut() {
+    me_insertion_call.gene_annotation = feature_index.annotate(
+      &me_insertion_call.chr,
+      me_insertion_call.position,
+      me_insertion_call.position,
+    );
+  }
+}
+
+///
+/// Annotate structural variant calls with gene features.
+///
+/// Variants are annotated over their span, translocations on their first
+/// breakend.
+///
+/// # Parameters
+///
+/// * `sv_calls` - Structural variant calls.
+///
+/// * `feature_index` - Gene features.
+pub fn annotate_sv_calls(
+  sv_calls: &mut [SVCall],
+  feature_index: &FeatureIndex,
+) {
+  for sv_call in sv_calls.iter_mut() {
+    let end = if sv_call.svtype.is_breakend() {
+      sv_call.position
+    } else {
+      sv_call.end
+    };
+    sv_call.gene_annotation =
+      feature_index.annotate(&sv_call.chr, sv_call.position, end);
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,47 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Compute biallelic genotype likelihoods on allele support.
+///
+/// Likelihoods are binomial on alternate allele fraction, i.e., error rate
+/// on 0/0, half on 0/1 & complement of error rate on 1/1. Binomial
+/// coefficient is shared across genotypes & therefore omitted.
+///
+/// # Parameters
+///
+/// * `reference` - Fragments supporting reference allele.
+///
+/// * `alternate` - Fragments supporting alternate allele.
+///
+/// * `error_rate` - Sequencing & alignment error rate.
+///
+/// # Returns
+///
+/// Return log10 likelihoods on 0/0, 0/1 & 1/1.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::genotype_likelihoods;
+///
+/// let likelihoods = genotype_likelihoods(2, 0, 0.01);
+///
+/// assert!((likelihoods[0] - 2. * 0.99f64.log10()).abs() < 1e-9);
+/// assert!((likelihoods[1] - 2. * 0.5f64.log10()).abs() < 1e-9);
+/// assert!((likelihoods[2] + 4.).abs() < 1e-9);
+/// ```
+pub fn genotype_likelihoods(
+  reference: usize,
+  alternate: usize,
+  error_rate: f64,
+) -> [f64; 3] {
+  let fractions = [error_rate, 0.5, 1. - error_rate];
+  let mut likelihoods = [0.; 3];
+  for (likelihood, fraction) in likelihoods.iter_mut().zip(fractions.iter()) {
+    *likelihood = alternate as f64 * fraction.log10() +
+      reference as f64 * (1. - fraction).log10();
+  }
+  likelihoods
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,38 +1,129 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::TRANSLOCATION_DISTANCE;
 use crate::{
   function::flag_interpretor::interpret,
   custom::{
+    chr_anchor::ChrAnchor,
+    clip_side_enum::ClipSideEnum,
+    detection_config::DetectionConfig,
+    sv_call::SVCall,
     sv_chimeric_pair::SVChimericPair,
+    sv_chimeric_read::SVChimericRead,
     sv_type::SVType,
   },
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Identify structural variant type.
 ///
-/// # Examples
+/// Identify structural variant type on discordant pair.
 ///
-/// ```
-/// ```
+/// Labels are exclusive & evaluated on priority: insertion on unmapped mate,
+/// deletion on forward-reverse mates on the same chromosome further apart
+/// than expected template length, then translocation across chromosomes or
+/// beyond translocation distance, inversion & duplication.
+///
+/// # Parameters
+///
+/// * `pair` - Chimeric pair with anchored mates. Labeled in place.
+///
+/// * `expected_tlen` - Expected template length.
+///
+/// * `config` - Detection parameters.
+///
+/// # Returns
+///
+/// Return whether pair is discordant.
 pub fn identify(
   pair: &mut SVChimericPair,
   expected_tlen: i32,
+  config: &DetectionConfig,
 ) -> bool {
-  let mut psw = vec![];
-
-  // evaluate read pairs
-  psw.push(sv_deletion(pair, expected_tlen));
-  psw.push(sv_duplication(pair));
-  psw.push(sv_inversion(pair));
-  psw.push(sv_insertion(pair));
-  psw.push(sv_translocation(pair));
-  // BUG: variant are called simultaneous
+  // evaluate read pairs. first match labels pair
+  sv_insertion(pair) ||
+    sv_deletion(pair, expected_tlen) ||
+    sv_translocation(pair, config) ||
+    sv_inversion(pair) ||
+    sv_duplication(pair)
+}
 
-  psw.contains(&true)
+///
+/// Identify structural variants on split read.
+///
+/// Primary alignment (index 0) is joined to every supplementary alignment
+/// in read order. Breakends on the same chromosome are labeled by their
+/// orientation as deletions, duplications or inversions. Inversion breakends
+/// further apart than translocation distance are labeled as translocations.
+///
+/// # Parameters
+///
+/// * `read` - Chimeric read with primary & supplementary alignments.
+///
+/// * `config` - Detection parameters.
+///
+/// # Returns
+///
+/// Return one call per supplementary alignment, with precise breakends,
+/// breakend orientation & a single split read as support.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   identify_split,
+///   ChrAnchor,
+///   ClipSideEnum,
+///   DetectionConfig,
+///   Position,
+///   SVChimericRead,
+///   SVType,
+///   CIGAR,
+/// };
+///
+/// // bases 1041 - 1100 joined to 1801 - 1840
+/// let mut sv_chimeric_read = SVChimericRead::new();
+/// let cigars = [("chr1", 0, 1041, "60M40S"), ("chr1", 2048, 1801, "60H40M")];
+/// for (chr, flag, position, cigar) in cigars.iter() {
+///   let position = Position::OneBased(*position);
+///   sv_chimeric_read.chr_read.push(ChrAnchor::load(
+///     CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
+///     chr.to_string(),
+///     *flag,
+///     60,
+///     position,
+///     0,
+///   ));
+/// }
+///
+/// let config = DetectionConfig::default();
+/// let sv_calls = identify_split(&sv_chimeric_read, &config);
+/// assert_eq!(sv_calls.len(), 1);
+/// assert_eq!(sv_calls[0].svtype, SVType::Deletion);
+/// assert_eq!(
+///   (sv_calls[0].position, sv_calls[0].end),
+///   (Position::OneBased(1101), Position::OneBased(1800))
+/// );
+/// assert_eq!(
+///   sv_calls[0].orientation,
+///   (ClipSideEnum::Right, ClipSideEnum::Left)
+/// );
+/// ```
+pub fn identify_split(
+  read: &SVChimericRead,
+  config: &DetectionConfig,
+) -> Vec<SVCall> {
+  let primary = match read.chr_read.first() {
+    Some(primary) => primary,
+    None => return Vec::new(),
+  };
+  read
+    .chr_read
+    .iter()
+    .skip(1)
+    .filter(|anchor| interpret(anchor.flag, 12))
+    .filter_map(|supplementary| split_breakends(primary, supplementary, config))
+    .collect()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -41,6 +132,7 @@ pub trait SVIdentify {
   fn identify(
     self,
     expected_tlen: i32,
+    config: &DetectionConfig,
   ) -> bool;
 }
 
@@ -51,8 +143,18 @@ fn sv_deletion(
   pair: &mut SVChimericPair,
   expected_tlen: i32,
 ) -> bool {
-  let tlen = pair.read1.chr_read[0].position - pair.read2.chr_read[0].position;
-  if tlen.abs() >= expected_tlen {
+  let (anchor1, anchor2) = (&pair.read1.chr_read[0], &pair.read2.chr_read[0]);
+  let (left, right) = if anchor1.position <= anchor2.position {
+    (anchor1, anchor2)
+  } else {
+    (anchor2, anchor1)
+  };
+  // leftmost mate forward & rightmost reverse
+  if left.chr == right.chr &&
+    !interpret(left.flag, 5) &&
+    interpret(right.flag, 5) &&
+    right.position - left.position >= expected_tlen as i64
+  {
     pair.svtag = SVType::Deletion;
     true
   } else {
@@ -99,9 +201,12 @@ fn sv_insertion(pair: &mut SVChimericPair) -> bool {
 }
 
 // translocation
-fn sv_translocation(pair: &mut SVChimericPair) -> bool {
+fn sv_translocation(
+  pair: &mut SVChimericPair,
+  config: &DetectionConfig,
+) -> bool {
   let tlen = pair.read1.chr_read[0].position - pair.read2.chr_read[0].position;
-  if tlen.abs() > TRANSLOCATION_DISTANCE ||
+  if tlen.abs() > config.translocation_distance as i64 ||
     pair.read1.chr_read[0].chr != pair.read2.chr_read[0].chr
   {
     pair.svtag = SVType::Translocation;
@@ -113,6 +218,85 @@ fn sv_translocation(pair: &mut SVChimericPair) -> bool {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// breakends joining alignments on read order
+fn split_breakends(
+  primary: &ChrAnchor,
+  supplementary: &ChrAnchor,
+  config: &DetectionConfig,
+) -> Option<SVCall> {
+  let (first, second) = if query_start(primary) <= query_start(supplementary)
+  {
+    (primary, supplementary)
+  } else {
+    (supplementary, primary)
+  };
+
+  // junction closes first alignment & opens second, on read direction
+  let first_breakend = if interpret(first.flag, 5) {
+    (&first.chr, first.position, ClipSideEnum::Left)
+  } else {
+    (&first.chr, first.reference_end(), ClipSideEnum::Right)
+  };
+  let second_breakend = if interpret(second.flag, 5) {
+    (&second.chr, second.reference_end(), ClipSideEnum::Right)
+  } else {
+    (&second.chr, second.position, ClipSideEnum::Left)
+  };
+
+  // leftmost breakend opens variant
+  let ((chr, left, left_side), (chr2, right, right_side)) =
+    if (first_breakend.0, first_breakend.1) <=
+      (second_breakend.0, second_breakend.1)
+    {
+      (first_breakend, second_breakend)
+    } else {
+      (second_breakend, first_breakend)
+    };
+
+  // orientation labels intrachromosomal breakends, distance breaks ties
+  let svtype = match (left_side, right_side) {
+    _ if chr != chr2 => SVType::Translocation,
+    (ClipSideEnum::Right, ClipSideEnum::Left) => SVType::Deletion,
+    (ClipSideEnum::Left, ClipSideEnum::Right) => SVType::Duplication,
+    _ if right - left > config.translocation_distance as i64 => {
+      SVType::Translocation
+    }
+    _ => SVType::Inversion,
+  };
+
+  let mut sv_call = SVCall::new(svtype);
+  sv_call.chr = chr.clone();
+  sv_call.chr2 = chr2.clone();
+  sv_call.split_reads = 1;
+  sv_call.orientation = (left_side, right_side);
+  match svtype {
+    // deleted & inverted bases lie between breakends
+    SVType::Deletion | SVType::Inversion => {
+      sv_call.position = left + (left_side == ClipSideEnum::Right) as u64;
+      sv_call.end = right - (right_side == ClipSideEnum::Left) as u64;
+    }
+    _ => {
+      sv_call.position = left;
+      sv_call.end = right;
+    }
+  }
+  if sv_call.end < sv_call.position {
+    return None;
+  }
+  Some(sv_call)
+}
+
+// first aligned base on read direction. 0-based
+fn query_start(anchor: &ChrAnchor) -> i32 {
+  if interpret(anchor.flag, 5) {
+    anchor.cigar.rigth_clip
+  } else {
+    anchor.cigar.left_clip
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // // test private functions
 // #[cfg(test)]
 // mod priv_tests {
@@ -0,0 +1,246 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    break_point::BreakPoint,
+    chr_anchor::ChrAnchor,
+    clip_side_enum::ClipSideEnum,
+    indexed_fasta::IndexedFasta,
+    position::Position,
+    sv_junction::SVJunction,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// minimum clipped bases located on reference to resolve junction
+const JUNCTION_ANCHOR: usize = 10;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Characterize structural variant junction on split read.
+///
+/// Clipped bases are located on mate breakend window, trimming bases next
+/// to break point as inserted sequence. Blunt junctions are then extended
+/// over bases shared by both sides of junction, i.e., microhomology.
+///
+/// # Parameters
+///
+/// * `reference` - Reference assembly.
+///
+/// * `anchor` - Split read chromosomal alignment.
+///
+/// * `breakpoint` - Split read break point.
+///
+/// * `chr` - Mate breakend chromosome.
+///
+/// * `window` - Mate breakend search window, inclusive.
+///
+/// * `max_length` - Maximum microhomology or inserted sequence length.
+///
+/// # Returns
+///
+/// Return junction, `None` when clipped bases are not located on window.
+/// Error on sequences missing from reference.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   characterize_junction,
+///   BreakPoint,
+///   ChrAnchor,
+///   CIGAR,
+///   IndexedFasta,
+///   Position,
+/// };
+///
+/// let path = std::env::temp_dir().join("characterize_junction.fa");
+/// let fasta = ">chr1\nAACCGGTTACGTAGGCATTCCGATCCAGTAGCTTGACCGTAA\n";
+/// std::fs::write(&path, fasta).expect("FASTA writing failed!");
+/// let reference = IndexedFasta::open(&path).expect("FASTA indexing failed!");
+///
+/// // bases 1 - 10 joined to 26 - 37. base 10 & 25 are shared
+/// let mut anchor = ChrAnchor::new();
+/// anchor.chr = "chr1".to_string();
+/// anchor.position = Position::OneBased(1);
+/// anchor.cigar = CIGAR::load("10M12S", anchor.position)
+///   .expect("CIGAR loading failed!");
+/// let breakpoint = BreakPoint::load("AACCGGTTACCAGTAGCTTGAC", 12, 5)
+///   .expect("BreakPoint failed!");
+///
+/// let sv_junction = characterize_junction(
+///   &reference,
+///   &anchor,
+///   &breakpoint,
+///   "chr1",
+///   (Position::OneBased(20), Position::OneBased(42)),
+///   5,
+/// )
+/// .expect("Junction characterization failed!")
+/// .expect("Junction not resolved!");
+///
+/// assert_eq!(
+///   (sv_junction.left, sv_junction.right),
+///   (Position::OneBased(9), Position::OneBased(25))
+/// );
+/// assert_eq!(sv_junction.homology, "C");
+/// assert_eq!(sv_junction.insertion, "");
+/// ```
+pub fn characterize_junction(
+  reference: &IndexedFasta,
+  anchor: &ChrAnchor,
+  breakpoint: &BreakPoint,
+  chr: &str,
+  window: (Position, Position),
+  max_length: usize,
+) -> anyResult<Option<SVJunction>> {
+  let clipped = breakpoint.clipped().to_uppercase();
+  let (start, sequence) = flank(reference, chr, window.0, window.1)?;
+  if sequence.is_empty() || clipped.len() < JUNCTION_ANCHOR {
+    return Ok(None);
+  }
+  let expected = window.0 + (window.1 - window.0).max(0) as u64 / 2;
+
+  for trim in 0..=max_length.min(clipped.len() - JUNCTION_ANCHOR) {
+    match breakpoint.side {
+      // clipped bases downstream from anchor. insertion opens clip
+      ClipSideEnum::Right => {
+        let pattern = &clipped[trim..];
+        if let Some(right) = locate(&sequence, pattern, start, expected) {
+          let left = anchor.reference_end();
+          return resolve(
+            reference,
+            (&anchor.chr, left),
+            (chr, right),
+            &clipped[..trim],
+            max_length,
+          )
+          .map(Some);
+        }
+      }
+      // clipped bases upstream from anchor. insertion closes clip
+      ClipSideEnum::Left => {
+        let pattern = &clipped[..clipped.len() - trim];
+        if let Some(found) = locate(&sequence, pattern, start, expected) {
+          let left = found + pattern.len() as u64 - 1;
+          return resolve(
+            reference,
+            (chr, left),
+            (&anchor.chr, anchor.position),
+            &clipped[clipped.len() - trim..],
+            max_length,
+          )
+          .map(Some);
+        }
+      }
+      ClipSideEnum::None => break,
+    }
+  }
+  Ok(None)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// occurrence closest to expected position
+fn locate(
+  sequence: &str,
+  pattern: &str,
+  offset: Position,
+  expected: Position,
+) -> Option<Position> {
+  sequence
+    .match_indices(pattern)
+    .map(|(ix, _)| offset + ix as u64)
+    .min_by_key(|position| (*position - expected).unsigned_abs())
+}
+
+// normalize junction leftmost & collect microhomology on blunt junctions
+fn resolve(
+  reference: &IndexedFasta,
+  (left_chr, left): (&str, Position),
+  (right_chr, right): (&str, Position),
+  insertion: &str,
+  max_length: usize,
+) -> anyResult<SVJunction> {
+  let mut sv_junction = SVJunction::new(left, right);
+  if !insertion.is_empty() {
+    sv_junction.insertion = insertion.to_string();
+    return Ok(sv_junction);
+  }
+
+  let reach = max_length as u64;
+  let (left_start, left_bases) =
+    flank(reference, left_chr, left - reach + 1, left + reach)?;
+  let (right_start, right_bases) =
+    flank(reference, right_chr, right - reach, right + reach - 1)?;
+  let left_base =
+    |position: Position| base(&left_bases, position - left_start);
+  let right_base =
+    |position: Position| base(&right_bases, position - right_start);
+
+  // bases shared upstream & downstream from junction
+  let mut upstream = 0;
+  while upstream < reach &&
+    left_base(left - upstream).is_some() &&
+    left_base(left - upstream) == right_base(right - 1 - upstream)
+  {
+    upstream += 1;
+  }
+  let mut downstream = 0;
+  while upstream + downstream < reach &&
+    left_base(left + 1 + downstream).is_some() &&
+    left_base(left + 1 + downstream) == right_base(right + downstream)
+  {
+    downstream += 1;
+  }
+
+  sv_junction.left = left - upstream;
+  sv_junction.right = right - upstream;
+  let homology_start = (sv_junction.left + 1 - left_start) as usize;
+  let homology_end = (left + downstream + 1 - left_start) as usize;
+  sv_junction.homology = left_bases[homology_start..homology_end].to_string();
+  Ok(sv_junction)
+}
+
+// reference bases clamped to sequence bounds, with first base
+fn flank(
+  reference: &IndexedFasta,
+  chr: &str,
+  start: Position,
+  end: Position,
+) -> anyResult<(Position, String)> {
+  let chr_length = reference
+    .get_record(chr)
+    .context(CommonError::MissingSequence)?
+    .length;
+  let start = start.max(Position::OneBased(1));
+  let end = end.min(Position::OneBased(chr_length));
+  if start > end {
+    return Ok((start, String::new()));
+  }
+  Ok((start, reference.fetch(chr, start, end)?.to_uppercase()))
+}
+
+// base at 0-based offset
+fn base(
+  bases: &str,
+  offset: i64,
+) -> Option<u8> {
+  if offset < 0 {
+    None
+  } else {
+    bases.as_bytes().get(offset as usize).copied()
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,99 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::me_library::MELibrary,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Load mobile element library from FASTA.
+///
+/// Entries are keyed by the first word on header lines.
+///
+/// # Parameters
+///
+/// * `reader` - FASTA formatted library.
+///
+/// # Returns
+///
+/// Return mobile element library with sequences & sizes. Error on sequence
+/// lines before any header or on empty entries.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::load_library;
+///
+/// let fasta = ">L1HS consensus\nGGGGGAGGAG\nCCAAGATGGC\n>AluY\nGGCCGGGCGC\n";
+/// let library =
+///   load_library(fasta.as_bytes()).expect("Library loading failed!");
+///
+/// assert_eq!(library["L1HS"].size, 20);
+/// assert_eq!(library["L1HS"].sequence, "GGGGGAGGAGCCAAGATGGC");
+/// assert_eq!(library["AluY"].size, 10);
+/// ```
+pub fn load_library<R: BufRead>(
+  reader: R
+) -> anyResult<HashMap<String, MELibrary>> {
+  let mut library = HashMap::new();
+  let mut mobel: Option<String> = None;
+  let mut me_library = MELibrary::new();
+  for line in reader.lines() {
+    let line = line.context(CommonError::Reading)?;
+    let line = line.trim();
+    if line.is_empty() {
+      continue;
+    }
+
+    if let Some(header) = line.strip_prefix('>') {
+      if let Some(mobel) = mobel.take() {
+        insert_entry(&mut library, mobel, me_library)?;
+      }
+      mobel = Some(
+        header
+          .split_whitespace()
+          .next()
+          .context(CommonError::Parsing)?
+          .to_string(),
+      );
+      me_library = MELibrary::new();
+    } else if mobel.is_some() {
+      me_library.sequence.push_str(line);
+    } else {
+      return Err(CommonError::Parsing.into());
+    }
+  }
+  if let Some(mobel) = mobel {
+    insert_entry(&mut library, mobel, me_library)?;
+  }
+  Ok(library)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// size & insert library entry
+fn insert_entry(
+  library: &mut HashMap<String, MELibrary>,
+  mobel: String,
+  mut me_library: MELibrary,
+) -> anyResult<()> {
+  if me_library.sequence.is_empty() {
+    return Err(CommonError::EmptySequence.into());
+  }
+  me_library.size = me_library.sequence.len() as i32;
+  library.insert(mobel, me_library);
+  Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
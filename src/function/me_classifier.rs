@@ -0,0 +1,306 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    me_anchor::MEAnchor,
+    me_classification::MEClassification,
+    me_level_enum::MELevelEnum,
+    me_library::MELibrary,
+    repeat_masker_record::RepeatMaskerRecord,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Load RepeatMasker .out annotations.
+///
+/// Header, blank & non-numeric lines are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   load_repeat_masker,
+///   MEClassification,
+/// };
+///
+/// let out = "   SW  perc perc perc  query   position  in query    matching repeat   position in repeat
+/// score  div. del. ins.  sequence  begin end  (left)   repeat   class/family  begin  end (left)  ID
+///
+///  1234   1.1  0.2  0.3  chr1      1001 7000 (100) + L1HS LINE/L1 1 6000 (155) 1
+///   301  12.0  1.0  0.0  chr1      9001 9300   (0) C AluY SINE/Alu (11) 300 1 2
+/// ";
+///
+/// let records =
+///   load_repeat_masker(out.as_bytes()).expect("RepeatMasker loading failed!");
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(
+///   records[1].classification,
+///   MEClassification::load("AluY", "SINE/Alu")
+/// );
+/// ```
+pub fn load_repeat_masker<R: BufRead>(
+  reader: R
+) -> anyResult<Vec<RepeatMaskerRecord>> {
+  let mut records = Vec::new();
+  for line in reader.lines() {
+    let line = line.context(CommonError::Parsing)?;
+    let flines = line.split_whitespace().collect::<Vec<&str>>();
+
+    // skip header & blank lines
+    if flines.is_empty() || flines[0].parse::<i32>().is_err() {
+      continue;
+    }
+
+    records.push(RepeatMaskerRecord::load(flines)?);
+  }
+  Ok(records)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Load Dfam-style classification table.
+///
+/// Tab separated table keyed by library entry name. Accepted layouts:
+///   - `name` `class/family`, where subfamily defaults to `name`.
+///   - `name` `class/family/subfamily`.
+///   - `name` `subfamily` `class/family`.
+///
+/// Classification may also be given as Dfam `;` separated hierarchy on any
+/// layout, e.g., `root;Interspersed_Repeat;Transposable_Element;LINE;L1`.
+///
+/// Commented (`#`) & blank lines are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   load_classification,
+///   MEClassification,
+/// };
+///
+/// let table = "#name\tclassification
+/// mobel77\tL1HS\tLINE/L1
+/// mobel78\tLTR/ERVK/HERVK11
+/// L1PA2\tLINE/L1
+/// mobel79\tL1PA3\troot;Interspersed_Repeat;Transposable_Element;LINE;L1
+/// ";
+///
+/// let classification =
+///   load_classification(table.as_bytes()).expect("Table loading failed!");
+///
+/// assert_eq!(
+///   classification["mobel77"],
+///   MEClassification::load("L1HS", "LINE/L1")
+/// );
+/// assert_eq!(
+///   classification["mobel78"],
+///   MEClassification::load("HERVK11", "LTR/ERVK")
+/// );
+/// assert_eq!(
+///   classification["L1PA2"],
+///   MEClassification::load("L1PA2", "LINE/L1")
+/// );
+/// assert_eq!(
+///   classification["mobel79"],
+///   MEClassification::load("L1PA3", "LINE/L1")
+/// );
+/// ```
+pub fn load_classification<R: BufRead>(
+  reader: R
+) -> anyResult<HashMap<String, MEClassification>> {
+  let mut classification = HashMap::new();
+  for line in reader.lines() {
+    let line = line.context(CommonError::Parsing)?;
+
+    // skip commented & blank lines
+    if line.trim().is_empty() || line.starts_with('#') {
+      continue;
+    }
+
+    let flines = line.split('\t').map(str::trim).collect::<Vec<&str>>();
+    let me_classification = match flines.len() {
+      2 if flines[1].contains(';') => {
+        MEClassification::load_dfam(flines[0], flines[1])
+      }
+      3 if flines[2].contains(';') => {
+        MEClassification::load_dfam(flines[1], flines[2])
+      }
+      2 => {
+        let levels = flines[1].split('/').collect::<Vec<&str>>();
+        if levels.len() > 2 {
+          MEClassification::load(levels[2], &levels[..2].join("/"))
+        } else {
+          MEClassification::load(flines[0], flines[1])
+        }
+      }
+      3 => MEClassification::load(flines[1], flines[2]),
+      _ => return Err(CommonError::Parsing.into()),
+    };
+
+    classification.insert(flines[0].to_string(), me_classification);
+  }
+  Ok(classification)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Collect classification hierarchy from RepeatMasker records keyed by
+/// repeat name.
+pub fn collect_classification(
+  records: &[RepeatMaskerRecord]
+) -> HashMap<String, MEClassification> {
+  records
+    .iter()
+    .map(|record| {
+      (
+        record.classification.subfamily.clone(),
+        record.classification.clone(),
+      )
+    })
+    .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Attach classification hierarchy onto mobile element library.
+///
+/// # Returns
+///
+/// Return number of library entries classified.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   classify,
+///   MEClassification,
+///   MELibrary,
+/// };
+/// use std::collections::HashMap;
+///
+/// let mut library = HashMap::new();
+/// library.insert("mobel77".to_string(), MELibrary::new());
+/// library.insert("mobel78".to_string(), MELibrary::new());
+///
+/// let mut classification = HashMap::new();
+/// classification.insert(
+///   "mobel77".to_string(),
+///   MEClassification::load("L1HS", "LINE/L1"),
+/// );
+///
+/// assert_eq!(classify(&mut library, &classification), 1);
+/// assert_eq!(library["mobel77"].classification.family, "L1");
+/// ```
+pub fn classify(
+  library: &mut HashMap<String, MELibrary>,
+  classification: &HashMap<String, MEClassification>,
+) -> usize {
+  let mut classified = 0;
+  for (mobel, me_library) in library.iter_mut() {
+    if let Some(me_classification) = classification.get(mobel) {
+      me_library.classification = me_classification.clone();
+      classified += 1;
+    }
+  }
+  classified
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Retrieve mobile element annotation at hierarchy level.
+///
+/// Unclassified mobile elements & `MELevelEnum::None` fall back to library
+/// entry name.
+pub fn classify_mobel<'a>(
+  mobel: &'a str,
+  library: &'a HashMap<String, MELibrary>,
+  level: MELevelEnum,
+) -> &'a str {
+  match library.get(mobel) {
+    Some(me_library)
+      if me_library.classification.is_classified() &&
+        level != MELevelEnum::None =>
+    {
+      me_library.classification.get_level(level)
+    }
+    _ => mobel,
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Aggregate mobile element anchors at hierarchy level.
+///
+/// # Returns
+///
+/// Return anchor count per annotation.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   aggregate_mobel,
+///   MEAnchor,
+///   MEClassification,
+///   MELevelEnum,
+///   MELibrary,
+/// };
+/// use std::collections::HashMap;
+///
+/// let mut library = HashMap::new();
+/// for (mobel, subfamily) in [("mobel77", "L1HS"), ("mobel78", "L1PA2")] {
+///   let mut me_library = MELibrary::new();
+///   me_library.classification = MEClassification::load(subfamily, "LINE/L1");
+///   library.insert(mobel.to_string(), me_library);
+/// }
+///
+/// let me_anchors = ["mobel77", "mobel78", "mobel99"]
+///   .iter()
+///   .map(|mobel| {
+///     let mut me_anchor = MEAnchor::new();
+///     me_anchor.mobel = mobel.to_string();
+///     me_anchor
+///   })
+///   .collect::<Vec<MEAnchor>>();
+///
+/// let family = aggregate_mobel(&me_anchors, &library, MELevelEnum::Family);
+///
+/// assert_eq!(family["L1"], 2);
+/// assert_eq!(family["mobel99"], 1);
+/// ```
+pub fn aggregate_mobel<'a, I>(
+  me_anchors: I,
+  library: &HashMap<String, MELibrary>,
+  level: MELevelEnum,
+) -> HashMap<String, usize>
+where
+  I: IntoIterator<Item = &'a MEAnchor>,
+{
+  let mut aggregated = HashMap::new();
+  for me_anchor in me_anchors {
+    *aggregated
+      .entry(classify_mobel(&me_anchor.mobel, library, level).to_string())
+      .or_insert(0) += 1;
+  }
+  aggregated
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
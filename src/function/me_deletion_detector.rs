@@ -0,0 +1,146 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  clip_side_enum::ClipSideEnum,
+  genomic_interval::GenomicInterval,
+  interval_index::IntervalIndex,
+  me_deletion_call::MEDeletionCall,
+  repeat_masker_record::RepeatMaskerRecord,
+  sv_call::SVCall,
+  sv_type::SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// RepeatMasker classes annotating mobile elements
+const ME_CLASSES: [&str; 5] = ["DNA", "LINE", "LTR", "Retroposon", "SINE"];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Detect reference mobile element deletions, i.e., polymorphic absences.
+///
+/// Deletion calls are crossed against reference mobile element annotations.
+/// Deletions with both breakends within tolerance from annotated element
+/// boundaries are reported as element deletions, onto the closest element.
+/// Breakends on the same chromosome joined as deletions, i.e., long
+/// deletions labeled as translocations, are evaluated as well.
+///
+/// # Parameters
+///
+/// * `sv_calls` - Structural variant calls.
+///
+/// * `repeat_masker` - Reference RepeatMasker annotations.
+///
+/// * `tolerance` - Maximum distance between breakends & element boundaries.
+///
+/// # Returns
+///
+/// Return element deletions in deletion call order.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   detect_me_deletions,
+///   load_repeat_masker,
+///   Position,
+///   SVCall,
+///   SVType,
+/// };
+///
+/// let out = "301 12.0 1.0 0.0 chr1 9001 9300 (0) C AluY SINE/Alu (11) 300 1 \
+///            2";
+/// let repeat_masker =
+///   load_repeat_masker(out.as_bytes()).expect("RepeatMasker loading failed!");
+///
+/// let mut sv_call = SVCall::new(SVType::Deletion);
+/// sv_call.chr = "chr1".to_string();
+/// sv_call.chr2 = "chr1".to_string();
+/// sv_call.position = Position::OneBased(8990);
+/// sv_call.end = Position::OneBased(9310);
+/// sv_call.support = 4;
+///
+/// let me_deletion_calls =
+///   detect_me_deletions(&[sv_call], &repeat_masker, 50);
+///
+/// assert_eq!(me_deletion_calls.len(), 1);
+/// assert_eq!(me_deletion_calls[0].classification.family, "Alu");
+/// assert_eq!(me_deletion_calls[0].length(), 300);
+/// assert_eq!(me_deletion_calls[0].deleted_length, 321);
+/// ```
+pub fn detect_me_deletions(
+  sv_calls: &[SVCall],
+  repeat_masker: &[RepeatMaskerRecord],
+  tolerance: u64,
+) -> Vec<MEDeletionCall> {
+  // mobile element annotations indexed per scaffold
+  let mut elements = IntervalIndex::new();
+  for record in repeat_masker
+    .iter()
+    .filter(|record| ME_CLASSES.contains(&record.classification.class.as_str()))
+  {
+    elements.insert(
+      GenomicInterval::new(record.scaffold.clone(), record.start, record.end),
+      record,
+    );
+  }
+  elements.index();
+
+  let mut me_deletion_calls = Vec::new();
+  for sv_call in sv_calls.iter().filter(|sv_call| deletion(sv_call)) {
+    let closest = elements
+      .window(&sv_call.chr, sv_call.position, sv_call.position, tolerance)
+      .into_iter()
+      .map(|(_, record)| *record)
+      .filter(|record| {
+        (record.start - sv_call.position).unsigned_abs() <= tolerance &&
+          (record.end - sv_call.end).unsigned_abs() <= tolerance
+      })
+      .min_by_key(|record| {
+        (record.start - sv_call.position).unsigned_abs() +
+          (record.end - sv_call.end).unsigned_abs()
+      });
+    if let Some(record) = closest {
+      me_deletion_calls.push(me_deletion(sv_call, record));
+    }
+  }
+  me_deletion_calls
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// deletion calls & deletion joins between breakends
+fn deletion(sv_call: &SVCall) -> bool {
+  sv_call.svtype == SVType::Deletion ||
+    (sv_call.svtype.is_breakend() &&
+      sv_call.chr == sv_call.chr2 &&
+      sv_call.orientation == (ClipSideEnum::Right, ClipSideEnum::Left))
+}
+
+// element deletion on annotated coordinates
+fn me_deletion(
+  sv_call: &SVCall,
+  record: &RepeatMaskerRecord,
+) -> MEDeletionCall {
+  let mut me_deletion_call = MEDeletionCall::new();
+  me_deletion_call.chr = sv_call.chr.clone();
+  me_deletion_call.position = record.start;
+  me_deletion_call.end = record.end;
+  me_deletion_call.deleted_length =
+    (sv_call.end - sv_call.position + 1).max(0) as u64;
+  me_deletion_call.precise =
+    sv_call.split_reads > 0 || sv_call.junction.is_some();
+  me_deletion_call.me_info.mobel = record.classification.subfamily.clone();
+  me_deletion_call.me_info.start = record.repeat_start;
+  me_deletion_call.me_info.end = record.repeat_end;
+  me_deletion_call.me_info.strand = record.strand;
+  me_deletion_call.me_info.length = record.length();
+  me_deletion_call.classification = record.classification.clone();
+  me_deletion_call.support = sv_call.support;
+  me_deletion_call.split_reads = sv_call.split_reads;
+  me_deletion_call
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,207 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  function::{
+    flag_interpretor::SAMFlag,
+    me_classifier::{
+      aggregate_mobel,
+      classify_mobel,
+    },
+  },
+  custom::{
+    me_anchor::MEAnchor,
+    me_chimeric_pair::MEChimericPair,
+    me_chimeric_read::MEChimericRead,
+    me_info::MEInfo,
+    me_level_enum::MELevelEnum,
+    me_library::MELibrary,
+    position::Position,
+    strand_enum::StrandEnum,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Summarize mobile element insertion at candidate site.
+///
+/// Mobile element anchors are aggregated at hierarchy level, & anchors on the
+/// most supported annotation are combined, e.g., sibling subfamilies at
+/// family level. Insertion is labeled by the most supported library entry
+/// within annotation. `MELevelEnum::None` aggregates by library entry.
+/// Insertion strand is assigned by majority, where mobile element &
+/// chromosomal alignments on the same strand of a read indicate a forward
+/// insertion. Reads without chromosomal alignment are polarized against the
+/// resolved chromosomal anchor of their pair, i.e., the mate, where mates
+/// face each other & opposing strands indicate a forward insertion.
+/// Covered consensus span estimates inserted length & 5' truncation.
+///
+/// # Parameters
+///
+/// * `me_chimeric_pairs` - Supporting pairs at candidate site.
+///
+/// * `library` - Mobile element library, classified or not.
+///
+/// * `level` - Hierarchy level aggregating anchor support.
+///
+/// # Returns
+///
+/// Return insertion summary. `None` when no mobile element anchor is found.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   summarize_me,
+///   ChrAnchor,
+///   ChrAnchorEnum,
+///   MEAnchor,
+///   MEChimericPair,
+///   MELevelEnum,
+///   Position,
+///   StrandEnum,
+///   CIGAR,
+/// };
+/// use std::collections::HashMap;
+///
+/// // discordant pairs anchored on reverse strand mates
+/// let me_chimeric_pairs = [("50S50M", 5001), ("70M30S", 5931)]
+///   .iter()
+///   .map(|(cigar, position)| {
+///     let position = Position::OneBased(*position);
+///     let mut me_chimeric_pair = MEChimericPair::load(MEAnchor::load(
+///       CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
+///       65,
+///       "mobel77".to_string(),
+///       Default::default(),
+///       position,
+///       6000.,
+///     ));
+///     let mut chr_anchor = ChrAnchor::new();
+///     chr_anchor.flag = 145;
+///     me_chimeric_pair.read2.chr_read.push(chr_anchor);
+///     me_chimeric_pair.chranch = ChrAnchorEnum::Read2;
+///     me_chimeric_pair
+///   })
+///   .collect::<Vec<MEChimericPair>>();
+///
+/// let me_info =
+///   summarize_me(&me_chimeric_pairs, &HashMap::new(), MELevelEnum::None)
+///     .expect("No anchors found!");
+///
+/// assert_eq!(me_info.strand, StrandEnum::Forward);
+/// assert_eq!(
+///   (me_info.start, me_info.end),
+///   (Position::OneBased(5001), Position::OneBased(6000))
+/// );
+/// assert_eq!((me_info.length, me_info.truncation), (1000, 5000));
+/// assert_eq!(me_info.to_string(), "mobel77,5001,6000,+");
+/// ```
+pub fn summarize_me<'a, I>(
+  me_chimeric_pairs: I,
+  library: &HashMap<String, MELibrary>,
+  level: MELevelEnum,
+) -> Option<MEInfo>
+where
+  I: IntoIterator<Item = &'a MEChimericPair>,
+{
+  let me_chimeric_pairs = me_chimeric_pairs.into_iter().collect::<Vec<_>>();
+  let me_chimeric_reads = me_chimeric_pairs
+    .iter()
+    .flat_map(|me_chimeric_pair| {
+      [&me_chimeric_pair.read1, &me_chimeric_pair.read2]
+    })
+    .collect::<Vec<&MEChimericRead>>();
+  let me_anchors = me_chimeric_reads
+    .iter()
+    .flat_map(|me_chimeric_read| me_chimeric_read.me_read.iter())
+    .collect::<Vec<&MEAnchor>>();
+
+  // most supported annotation & library entry within it
+  let annotation =
+    most_supported(aggregate_mobel(me_anchors.iter().copied(), library, level))?;
+  let in_annotation = |me_anchor: &MEAnchor| {
+    classify_mobel(&me_anchor.mobel, library, level) == annotation
+  };
+  let mobel = most_supported(aggregate_mobel(
+    me_anchors.iter().copied().filter(|me_anchor| in_annotation(me_anchor)),
+    library,
+    MELevelEnum::None,
+  ))?;
+
+  let mut me_info = MEInfo::new();
+  me_info.mobel = mobel;
+  me_info.start = Position::OneBased(u64::MAX);
+  me_info.end = Position::OneBased(0);
+
+  let mut forward = 0;
+  let mut reverse = 0;
+  for me_chimeric_pair in me_chimeric_pairs.iter() {
+    let reads = [&me_chimeric_pair.read1, &me_chimeric_pair.read2];
+    for me_chimeric_read in reads {
+      // chromosomal alignment on read, otherwise on resolved mate
+      let polarity = match me_chimeric_read.chr_anchor() {
+        Some(chr_anchor) => Some((chr_anchor, true)),
+        None => me_chimeric_pair
+          .get_chr_anchor()
+          .filter(|anchor_read| !std::ptr::eq(*anchor_read, me_chimeric_read))
+          .and_then(|anchor_read| anchor_read.chr_anchor())
+          .map(|chr_anchor| (chr_anchor, false)),
+      };
+
+      for me_anchor in me_chimeric_read.me_read.iter() {
+        if !in_annotation(me_anchor) {
+          continue;
+        }
+
+        // aligned consensus span clamped to consensus
+        let start = me_anchor.position.max(Position::OneBased(1));
+        let mut end =
+          me_anchor.cigar.right_boundry - me_anchor.cigar.rigth_clip as u64;
+        if me_anchor.size > 0. {
+          end = end.min(Position::OneBased(me_anchor.size as u64));
+        }
+        me_info.start = me_info.start.min(start);
+        me_info.end = me_info.end.max(end);
+        me_info.support += 1;
+
+        // strand
+        if let Some((chr_anchor, same_read)) = polarity {
+          let same_strand = me_anchor.interpret(5) == chr_anchor.interpret(5);
+          if same_strand == same_read {
+            forward += 1;
+          } else {
+            reverse += 1;
+          }
+        }
+      }
+    }
+  }
+
+  me_info.strand = match (forward, reverse) {
+    (f, r) if f > r => StrandEnum::Forward,
+    (f, r) if f < r => StrandEnum::Reverse,
+    (_, _) => StrandEnum::None,
+  };
+  me_info.length = (me_info.end - me_info.start + 1).max(0) as u64;
+  me_info.truncation = me_info.start.zero_based();
+  Some(me_info)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// most supported key, lexicographically first on ties
+fn most_supported(counts: HashMap<String, usize>) -> Option<String> {
+  counts
+    .into_iter()
+    .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+    .map(|(key, _)| key)
+}
+
+////
@@ -1,26 +1,45 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::BIN_SIZE;
+use crate::custom::{
+  detection_config::DetectionConfig,
+  position::Position,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 ///
 /// Bin alignment position.
 ///
+/// # Parameters
+///
+/// * `position` - Alignment position.
+///
+/// * `config` - Detection parameters. Sets bin size.
+///
 /// # Returns
 /// Return binned position.
 ///
 /// # Examples
 ///
 /// ```
-/// use genomic_structures::bin;
+/// use genomic_structures::{
+///   bin,
+///   DetectionConfig,
+///   Position,
+/// };
 ///
-/// assert_eq!(bin(2099), 2000);
+/// assert_eq!(
+///   bin(Position::OneBased(2099), &DetectionConfig::default()),
+///   Position::OneBased(2000)
+/// );
 /// ```
-pub fn bin(position: i32) -> i32 {
-  let binned = position % BIN_SIZE;
-  position - binned
+pub fn bin(
+  position: Position,
+  config: &DetectionConfig,
+) -> Position {
+  let binned = position.one_based() % config.bin_size as u64;
+  position.to_one_based() - binned
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -30,23 +49,40 @@ pub trait Anchor {
   ///
   /// Bin alignment position.
   ///
+  /// # Parameters
+  ///
+  /// * `config` - Detection parameters. Sets bin size.
+  ///
   /// # Returns
   /// Return binned position.
   ///
   /// # Examples
   ///
   /// ```
-  /// use genomic_structures::bin;
+  /// use genomic_structures::{
+  ///   Anchor,
+  ///   ChrAnchor,
+  ///   DetectionConfig,
+  ///   Position,
+  /// };
+  ///
+  /// let mut chr_anchor = ChrAnchor::new();
+  /// chr_anchor.position = Position::OneBased(2099);
   ///
-  /// assert_eq!(bin(2099), 2000);
+  /// assert_eq!(
+  ///   chr_anchor.bin(&DetectionConfig::default()),
+  ///   Position::OneBased(2000)
+  /// );
   /// ```
-  fn bin(&self) -> i32 {
-    let binned = self.get_position() % BIN_SIZE;
-    self.get_position() - binned
+  fn bin(
+    &self,
+    config: &DetectionConfig,
+  ) -> Position {
+    bin(self.get_position(), config)
   }
 
   /// Retrieve position from struct.
-  fn get_position(&self) -> i32;
+  fn get_position(&self) -> Position;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -30,6 +30,44 @@ pub fn reverse_sequence(sequence: &str) -> String {
     .collect()
 }
 
+///
+/// Calculate GC content.
+///
+/// Ambiguous bases, e.g., N, are left out of count.
+///
+/// # Returns
+///
+/// Return G & C fraction on unambiguous bases. `None` when less than half
+/// of bases are unambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::gc_content;
+///
+/// assert_eq!(gc_content("GATTACA"), Some(2. / 7.));
+/// assert_eq!(gc_content("gcNN"), Some(1.));
+/// assert_eq!(gc_content("ANNN"), None);
+/// ```
+pub fn gc_content(sequence: &str) -> Option<f64> {
+  let (mut gc, mut called) = (0, 0);
+  for nucleotide in sequence.chars() {
+    match nucleotide.to_ascii_uppercase() {
+      'G' | 'C' => {
+        gc += 1;
+        called += 1;
+      }
+      'A' | 'T' => called += 1,
+      _ => (),
+    }
+  }
+  if called == 0 || called * 2 < sequence.len() {
+    None
+  } else {
+    Some(gc as f64 / called as f64)
+  }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Methods to manipulate sequences.
@@ -0,0 +1,143 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::custom::{
+  me_chimeric_read::MEChimericRead,
+  poly_tail::PolyTail,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// consecutive mismatches allowed within tail
+const MAX_MISMATCH_RUN: i32 = 2;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Detect poly-A / poly-T tail adjacent to junction on clipped sequence.
+///
+/// Tail is extended from junction while mismatches remain within tolerance
+/// and no more than two consecutive mismatches occur. Tail always ends on a
+/// matching nucleotide.
+///
+/// # Parameters
+///
+/// * `sequence` - Clipped sequence.
+///
+/// * `from_start` - Whether junction lies at sequence start. Otherwise,
+///   junction lies at sequence end.
+///
+/// * `min_length` - Minimum tail length.
+///
+/// * `max_mismatch` - Maximum mismatch fraction within tail.
+///
+/// # Returns
+///
+/// Return longest poly-A or poly-T tail. `None` when shorter than minimum
+/// length.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::detect_poly_tail;
+///
+/// let tail = detect_poly_tail("AAAAAAGAAAAAAAACGTCGT", true, 10, 0.1)
+///   .expect("No tail found!");
+///
+/// assert_eq!((tail.base, tail.length, tail.mismatches), ('A', 15, 1));
+///
+/// let tail = detect_poly_tail("CGTCGTTTTTTTTTTTT", false, 10, 0.1)
+///   .expect("No tail found!");
+///
+/// assert_eq!((tail.base, tail.length, tail.mismatches), ('T', 12, 0));
+/// ```
+pub fn detect_poly_tail(
+  sequence: &str,
+  from_start: bool,
+  min_length: i32,
+  max_mismatch: f64,
+) -> Option<PolyTail> {
+  let nucleotides = if from_start {
+    sequence.chars().collect::<Vec<char>>()
+  } else {
+    sequence.chars().rev().collect::<Vec<char>>()
+  };
+
+  // longest tail
+  let tail = ['A', 'T']
+    .iter()
+    .map(|base| extend_tail(&nucleotides, *base, max_mismatch))
+    .fold(PolyTail::new(), |longest, tail| {
+      if tail.length > longest.length {
+        tail
+      } else {
+        longest
+      }
+    });
+
+  if tail.length >= min_length && tail.length > 0 {
+    Some(tail)
+  } else {
+    None
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Summarize tail length at candidate site.
+///
+/// # Returns
+///
+/// Return median tail length on reads carrying a tail. `None` when no read
+/// carries a tail.
+pub fn site_tail_length<'a, I>(me_chimeric_reads: I) -> Option<i32>
+where
+  I: IntoIterator<Item = &'a MEChimericRead>,
+{
+  let mut lengths = me_chimeric_reads
+    .into_iter()
+    .filter_map(|me_chimeric_read| {
+      me_chimeric_read.tail.as_ref().map(|tail| tail.length)
+    })
+    .collect::<Vec<i32>>();
+  if lengths.is_empty() {
+    return None;
+  }
+  lengths.sort_unstable();
+  Some(lengths[lengths.len() / 2])
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// extend tail from junction
+fn extend_tail(
+  nucleotides: &[char],
+  base: char,
+  max_mismatch: f64,
+) -> PolyTail {
+  let mut tail = PolyTail::new();
+  tail.base = base;
+
+  let mut mismatches = 0;
+  let mut mismatch_run = 0;
+  for (ix, nucleotide) in nucleotides.iter().enumerate() {
+    if nucleotide.to_ascii_uppercase() == base {
+      mismatch_run = 0;
+      let length = ix as i32 + 1;
+      if mismatches as f64 <= max_mismatch * length as f64 {
+        tail.length = length;
+        tail.mismatches = mismatches;
+      }
+    } else {
+      mismatches += 1;
+      mismatch_run += 1;
+      if mismatch_run > MAX_MISMATCH_RUN {
+        break;
+      }
+    }
+  }
+  tail
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,10 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::{
-  BIN_OVERLAP,
-  BIN_SIZE,
-};
+use crate::custom::detection_config::DetectionConfig;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -22,8 +19,8 @@ macro_rules! test_calculate_effective_len {
     fn $function() {
       let effective_length = calculate_effective_len(
         $glen,
-        BIN_SIZE as f64,
-        BIN_OVERLAP as f64,
+        DetectionConfig::DEFAULT.bin_size as f64,
+        DetectionConfig::DEFAULT.bin_overlap as f64,
       );
       assert_eq!(
         effective_length, $expect,
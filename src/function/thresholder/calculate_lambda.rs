@@ -1,7 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use crate::BIN_SIZE;
+use crate::custom::detection_config::DetectionConfig;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -18,7 +18,11 @@ macro_rules! test_calculate_lambda {
   ) => {
     #[test]
     fn $function() {
-      let lambda = calculate_lambda($noreads, $eflen, BIN_SIZE as f64);
+      let lambda = calculate_lambda(
+        $noreads,
+        $eflen,
+        DetectionConfig::DEFAULT.bin_size as f64,
+      );
       assert_eq!(
         lambda,
         $expect,
@@ -3,6 +3,9 @@
 // private function
 use super::table;
 
+// crate utilities
+use crate::custom::position::Position;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // private function
@@ -16,7 +19,7 @@ macro_rules! test_table {
     fn $function() {
       let mut bined_hm = std::collections::HashMap::new();
       for ix in 0..$keys.len() {
-        bined_hm.insert($keys[ix], $values[ix].clone());
+        bined_hm.insert(Position::OneBased($keys[ix]), $values[ix].clone());
       }
       let table = table(&bined_hm, $psize);
 
@@ -54,4 +57,18 @@ test_table!(test01;
   expect |> vec![1., 3., 2., 0., 0., ];
 );
 
+test_table!(test02; // empty bin
+  params |> 5;
+  values |>
+    k >>> [
+      100,
+      200,
+    ];
+    v >>> [
+      Vec::<String>::new(),
+      vec!["200.1".to_string(), "200.2".to_string(), "200.3".to_string(), ],
+    ];
+  expect |> vec![0., 0., 1., 0., 0., ];
+);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,203 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  function::break_point_refiner::refine_break_point,
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    me_chimeric_read::MEChimericRead,
+    orientation_enum::OrientationEnum,
+    position::Position,
+    target_site_duplication::TargetSiteDuplication,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Detect target site duplication (TSD) at mobile element insertion site.
+///
+/// Reads tagged as upstream or downstream mobile element junctions are split
+/// by chromosomal clip side. Right-clipped reads delimit the left junction &
+/// left-clipped reads delimit the right junction. Overlap between junctions
+/// corresponds to the duplicated target site.
+///
+/// # Parameters
+///
+/// * `me_chimeric_reads` - Tagged reads at candidate site.
+///
+/// * `window` - Distance from consensus to collect supporting split reads.
+///
+/// * `min_clip` - Minimum clipped bases to consider read as split read.
+///
+/// * `max_length` - Maximum duplication length.
+///
+/// # Returns
+///
+/// Return target site duplication. `None` when junctions are missing or do
+/// not overlap within maximum length.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   detect_tsd,
+///   ChrAnchor,
+///   MEChimericRead,
+///   OrientationEnum,
+///   Position,
+///   CIGAR,
+/// };
+///
+/// // GGGGGGGGGG[AAGAATTC]|ME|[AAGAATTC]CCCCCCCCCC
+/// // TSD located at 1011-1018
+/// let reads = [
+///   ("GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", "18M12S", Position::OneBased(1001)),
+///   ("MMMMMMMMMMMMAAGAATTCCCCCCCCCCC", "12S18M", Position::OneBased(1011)),
+/// ];
+///
+/// let me_chimeric_reads = reads
+///   .iter()
+///   .map(|(sequence, cigar, position)| {
+///     let mut me_chimeric_read = MEChimericRead::new();
+///     me_chimeric_read.sequence = sequence.to_string();
+///     me_chimeric_read.orientation = OrientationEnum::Upstream;
+///     me_chimeric_read.chr_read.push(ChrAnchor::load(
+///       CIGAR::load(cigar, *position).expect("CIGAR loading failed!"),
+///       "chr1".to_string(),
+///       0,
+///       60,
+///       *position,
+///       0,
+///     ));
+///     me_chimeric_read
+///   })
+///   .collect::<Vec<MEChimericRead>>();
+///
+/// let tsd =
+///   detect_tsd(&me_chimeric_reads, 10, 5, 50).expect("No TSD found!");
+///
+/// assert_eq!(
+///   (tsd.start, tsd.end, tsd.length),
+///   (Position::OneBased(1011), Position::OneBased(1018), 8)
+/// );
+/// assert_eq!(tsd.sequence, "AAGAATTC");
+/// assert_eq!(tsd.support, 2);
+/// ```
+pub fn detect_tsd<'a, I>(
+  me_chimeric_reads: I,
+  window: u64,
+  min_clip: i32,
+  max_length: u64,
+) -> Option<TargetSiteDuplication>
+where
+  I: IntoIterator<Item = &'a MEChimericRead>,
+{
+  // mobile element junction reads
+  let junction_reads = me_chimeric_reads
+    .into_iter()
+    .filter(|me_chimeric_read| {
+      me_chimeric_read.orientation == OrientationEnum::Upstream ||
+        me_chimeric_read.orientation == OrientationEnum::Downstream
+    })
+    .collect::<Vec<&MEChimericRead>>();
+
+  // junctions
+  let left = refine_break_point(
+    junction_reads.iter().copied(),
+    ClipSideEnum::Right,
+    window,
+    min_clip,
+  )?;
+  let right = refine_break_point(
+    junction_reads.iter().copied(),
+    ClipSideEnum::Left,
+    window,
+    min_clip,
+  )?;
+
+  // overlap
+  let length = left.position - right.position;
+  if left.chr != right.chr || length <= 0 || length as u64 > max_length {
+    return None;
+  }
+  let length = length as u64;
+
+  let mut tsd = TargetSiteDuplication::new();
+  tsd.chr = left.chr.clone();
+  tsd.start = right.position;
+  tsd.end = left.position - 1;
+  tsd.length = length;
+  tsd.sequence = tsd_sequence(
+    &junction_reads,
+    &left.chr,
+    left.position,
+    right.position,
+    length,
+  );
+  tsd.support = left.split_reads + right.split_reads;
+  tsd.left = left;
+  tsd.right = right;
+  Some(tsd)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// most frequent duplicated sequence on reads clipped at consensus junctions
+fn tsd_sequence(
+  junction_reads: &[&MEChimericRead],
+  chr: &str,
+  left_position: Position,
+  right_position: Position,
+  length: u64,
+) -> String {
+  let mut sequences: HashMap<String, usize> = HashMap::new();
+  for me_chimeric_read in junction_reads.iter() {
+    let chr_anchor = match me_chimeric_read.chr_anchor() {
+      Some(chr_anchor) if chr_anchor.chr == chr => chr_anchor,
+      _ => continue,
+    };
+    let sequence = me_chimeric_read.chr_sequence(chr_anchor);
+    let left_clip = chr_anchor.cigar.left_clip as i64;
+    let length = length as i64;
+
+    // first aligned bases on left-clipped reads
+    let start = if chr_anchor.cigar.clip_junction(ClipSideEnum::Left) ==
+      Some(right_position)
+    {
+      left_clip
+    // last aligned bases on right-clipped reads
+    } else if chr_anchor.cigar.clip_junction(ClipSideEnum::Right) ==
+      Some(left_position)
+    {
+      left_clip + (left_position - chr_anchor.position) - length
+    } else {
+      continue;
+    };
+
+    if start < 0 {
+      continue;
+    }
+    if let Some(duplicated) =
+      sequence.get(start as usize..(start + length) as usize)
+    {
+      *sequences.entry(duplicated.to_string()).or_insert(0) += 1;
+    }
+  }
+
+  let mut consensus = (String::new(), 0);
+  for (sequence, count) in sequences {
+    if count > consensus.1 || (count == consensus.1 && sequence < consensus.0)
+    {
+      consensus = (sequence, count);
+    }
+  }
+  consensus.0
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,349 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::collections::{
+  HashMap,
+  HashSet,
+};
+use std::io::BufRead;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    me_classification::MEClassification,
+    me_insertion_call::MEInsertionCall,
+    position::Position,
+    site_filter_enum::SiteFilterEnum,
+    strand_enum::StrandEnum,
+    sv_call::SVCall,
+    sv_junction::SVJunction,
+    sv_type::SVType,
+    target_site_duplication::TargetSiteDuplication,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// mandatory VCF columns, i.e., up to INFO
+const VCF_FIELDS: usize = 8;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Load structural variant & mobile element insertion calls from VCF.
+///
+/// Records with `<INS:ME>` symbolic alleles, e.g., `<INS:ME:L1>`, load as
+/// insertion calls, keeping MEINFO, CIPOS, SUPPORT, SR, TSD & POLYA. Other
+/// records typed by SVTYPE load as structural variant calls, keeping END,
+/// CHR2, PE, SR, DR & junction sequences. Breakend pairs load as a single
+/// call, from the first mate found, with orientation taken from the ALT
+/// bracket notation. Records without structural variant type, e.g., SNVs,
+/// are skipped.
+///
+/// # Parameters
+///
+/// * `reader` - VCF, e.g., written by this crate or other callers.
+///
+/// # Returns
+///
+/// Return structural variant & insertion calls, in record order. Error on
+/// records missing mandatory columns or with malformed positions.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   load_vcf,
+///   Position,
+///   SVType,
+/// };
+///
+/// let vcf = "##fileformat=VCFv4.2\n\
+///   #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n\
+///   chr1\t1001\tSV1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=1800;PE=5;SR=0\n\
+///   chr1\t9000\tME1\tN\t<INS:ME:L1>\t.\tPASS\tSVTYPE=INS;SVLEN=6000;\
+///   MEINFO=L1HS,1,6000,+;CIPOS=-10,20;IMPRECISE;SUPPORT=4,3;SR=0\n";
+/// let (sv_calls, me_insertion_calls) =
+///   load_vcf(vcf.as_bytes()).expect("VCF loading failed!");
+///
+/// assert_eq!(sv_calls[0].svtype, SVType::Deletion);
+/// assert_eq!(sv_calls[0].end, Position::OneBased(1800));
+/// assert_eq!(me_insertion_calls[0].family(), "L1");
+/// assert_eq!(me_insertion_calls[0].ci_start, Position::OneBased(8990));
+/// assert_eq!(me_insertion_calls[0].support(), 7);
+/// ```
+pub fn load_vcf<R: BufRead>(
+  reader: R
+) -> anyResult<(Vec<SVCall>, Vec<MEInsertionCall>)> {
+  let mut sv_calls = Vec::new();
+  let mut me_insertion_calls = Vec::new();
+  let mut breakends = HashSet::new();
+  for line in reader.lines() {
+    let line = line.context(CommonError::Reading)?;
+    if line.trim().is_empty() || line.starts_with('#') {
+      continue;
+    }
+
+    let flines = line.split('\t').collect::<Vec<&str>>();
+    if flines.len() < VCF_FIELDS {
+      return Err(CommonError::Parsing.into());
+    }
+    let position = Position::OneBased(
+      flines[1].parse::<u64>().context(CommonError::Parsing)?,
+    );
+    let alt = flines[4];
+    let info = info_fields(flines[7]);
+
+    if alt.starts_with("<INS:ME") {
+      me_insertion_calls.push(me_insertion_call(
+        flines[0],
+        position,
+        alt,
+        flines[6],
+        &info,
+      )?);
+      continue;
+    }
+
+    let svtype = match svtype(alt, &info) {
+      Some(svtype) => svtype,
+      None => continue,
+    };
+    // mate record of breakend pair already loaded
+    if svtype.is_breakend() {
+      if info.get("MATEID").map_or(false, |mate| breakends.contains(*mate)) {
+        continue;
+      }
+      breakends.insert(flines[2].to_string());
+    }
+    sv_calls.push(sv_call(flines[0], position, alt, svtype, &info)?);
+  }
+  Ok((sv_calls, me_insertion_calls))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// INFO keys & values, empty values on flags
+fn info_fields(info: &str) -> HashMap<&str, &str> {
+  info
+    .split(';')
+    .filter(|field| !field.is_empty() && *field != ".")
+    .map(|field| match field.split_once('=') {
+      Some((key, value)) => (key, value),
+      None => (field, ""),
+    })
+    .collect()
+}
+
+// parse INFO value, `None` when absent
+fn info_value<T: std::str::FromStr>(
+  info: &HashMap<&str, &str>,
+  key: &str,
+) -> anyResult<Option<T>> {
+  match info.get(key) {
+    Some(value) => {
+      Ok(Some(value.parse::<T>().ok().context(CommonError::Parsing)?))
+    }
+    None => Ok(None),
+  }
+}
+
+// structural variant type on SVTYPE, sub-typed on symbolic allele
+fn svtype(
+  alt: &str,
+  info: &HashMap<&str, &str>,
+) -> Option<SVType> {
+  match (info.get("SVTYPE").copied(), alt) {
+    (_, "<DUP:DISPERSED>") => Some(SVType::DispersedDuplication),
+    (_, "<DUP:INV>") => Some(SVType::InvertedDuplication),
+    (_, "<INV:DEL>") => Some(SVType::DeletionFlankedInversion),
+    (Some("DEL"), _) => Some(SVType::Deletion),
+    (Some("DUP"), _) => Some(SVType::Duplication),
+    (Some("INV"), _) => Some(SVType::Inversion),
+    (Some("INS"), _) => Some(SVType::Insertion),
+    (Some("BND"), _) | (Some("TRA"), _) => Some(SVType::Translocation),
+    _ => None,
+  }
+}
+
+// shift position by signed offset
+fn shift(
+  position: Position,
+  offset: i64,
+) -> Position {
+  if offset < 0 {
+    position - offset.unsigned_abs()
+  } else {
+    position + offset as u64
+  }
+}
+
+// mobile element insertion call
+fn me_insertion_call(
+  chr: &str,
+  position: Position,
+  alt: &str,
+  filter: &str,
+  info: &HashMap<&str, &str>,
+) -> anyResult<MEInsertionCall> {
+  let mut me_insertion_call = MEInsertionCall::new();
+  me_insertion_call.chr = chr.to_string();
+  me_insertion_call.position = position;
+  me_insertion_call.precise = !info.contains_key("IMPRECISE");
+
+  // family on symbolic allele, e.g., <INS:ME:L1>
+  let family = alt
+    .trim_start_matches('<')
+    .trim_end_matches('>')
+    .splitn(3, ':')
+    .nth(2)
+    .unwrap_or_default();
+  me_insertion_call.classification = MEClassification::new();
+  me_insertion_call.classification.family = family.to_string();
+
+  // element name, covered consensus & polarity
+  if let Some(me_info) = info.get("MEINFO") {
+    let fields = me_info.split(',').collect::<Vec<&str>>();
+    if let [mobel, start, end, polarity] = fields[..] {
+      me_insertion_call.me_info.mobel = mobel.to_string();
+      me_insertion_call.classification.subfamily = mobel.to_string();
+      me_insertion_call.me_info.start =
+        Position::OneBased(start.parse::<u64>().unwrap_or_default());
+      me_insertion_call.me_info.end =
+        Position::OneBased(end.parse::<u64>().unwrap_or_default());
+      me_insertion_call.me_info.strand = match polarity {
+        "+" => StrandEnum::Forward,
+        "-" => StrandEnum::Reverse,
+        _ => StrandEnum::None,
+      };
+    } else {
+      return Err(CommonError::Parsing.into());
+    }
+  }
+  if let Some(length) = info_value::<i64>(info, "SVLEN")? {
+    me_insertion_call.me_info.length = length.unsigned_abs();
+  }
+
+  me_insertion_call.ci_start = position;
+  me_insertion_call.ci_end = position;
+  if let Some(cipos) = info.get("CIPOS") {
+    let offsets = cipos
+      .split(',')
+      .map(|offset| offset.parse::<i64>().context(CommonError::Parsing))
+      .collect::<anyResult<Vec<i64>>>()?;
+    if let [left, right] = offsets[..] {
+      me_insertion_call.ci_start = shift(position, left);
+      me_insertion_call.ci_end = shift(position, right);
+    } else {
+      return Err(CommonError::Parsing.into());
+    }
+  }
+
+  if let Some(support) = info.get("SUPPORT") {
+    let (five_prime, three_prime) =
+      support.split_once(',').context(CommonError::Parsing)?;
+    me_insertion_call.five_prime =
+      five_prime.parse().context(CommonError::Parsing)?;
+    me_insertion_call.three_prime =
+      three_prime.parse().context(CommonError::Parsing)?;
+  }
+  me_insertion_call.split_reads =
+    info_value(info, "SR")?.unwrap_or_default();
+  me_insertion_call.tail_length = info_value(info, "POLYA")?;
+  if let Some(sequence) = info.get("TSD") {
+    let mut tsd = TargetSiteDuplication::new();
+    tsd.chr = chr.to_string();
+    tsd.sequence = sequence.to_string();
+    tsd.length = sequence.len() as u64;
+    me_insertion_call.tsd = Some(tsd);
+  }
+
+  me_insertion_call.filters = filter
+    .split(';')
+    .filter_map(|filter| match filter {
+      "BLACKLIST" => Some(SiteFilterEnum::Blacklist),
+      "REF_ME" => Some(SiteFilterEnum::ReferenceElement),
+      _ => None,
+    })
+    .collect();
+  Ok(me_insertion_call)
+}
+
+// structural variant call
+fn sv_call(
+  chr: &str,
+  position: Position,
+  alt: &str,
+  svtype: SVType,
+  info: &HashMap<&str, &str>,
+) -> anyResult<SVCall> {
+  let mut sv_call = SVCall::new(svtype);
+  sv_call.chr = chr.to_string();
+  sv_call.position = position;
+  sv_call.chr2 = info.get("CHR2").unwrap_or(&chr).to_string();
+  sv_call.end = info_value::<u64>(info, "END")?
+    .map_or(position, Position::OneBased);
+  sv_call.support = info_value(info, "PE")?.unwrap_or_default();
+  sv_call.split_reads = info_value(info, "SR")?.unwrap_or_default();
+  sv_call.depth_ratio = info_value(info, "DR")?;
+
+  if svtype.is_breakend() {
+    let (mate_chr, mate_position, orientation) =
+      breakend_mate(alt).context(CommonError::Parsing)?;
+    sv_call.chr2 = mate_chr;
+    sv_call.end = mate_position;
+    sv_call.orientation = orientation;
+    // first breakend upstream, as written
+    if (&sv_call.chr2, sv_call.end) < (&sv_call.chr, sv_call.position) {
+      std::mem::swap(&mut sv_call.chr, &mut sv_call.chr2);
+      std::mem::swap(&mut sv_call.position, &mut sv_call.end);
+      sv_call.orientation = (orientation.1, orientation.0);
+    }
+  }
+
+  if ["HOMLEN", "HOMSEQ", "SVINSSEQ"]
+    .iter()
+    .any(|key| info.contains_key(key))
+  {
+    let mut sv_junction = SVJunction::new(sv_call.position, sv_call.end);
+    sv_junction.homology = info.get("HOMSEQ").unwrap_or(&"").to_string();
+    sv_junction.insertion = info.get("SVINSSEQ").unwrap_or(&"").to_string();
+    sv_call.junction = Some(sv_junction);
+  }
+  Ok(sv_call)
+}
+
+// mate breakend & junction sides on bracket notation, e.g., N[chr2:5001[
+fn breakend_mate(
+  alt: &str
+) -> Option<(String, Position, (ClipSideEnum, ClipSideEnum))> {
+  let bracket = alt.find(['[', ']'])?;
+  let mate_bracket = alt[bracket..].chars().next()?;
+  let mate = alt[bracket + 1..].split(mate_bracket).next()?;
+  let (mate_chr, mate_position) = mate.rsplit_once(':')?;
+  let mate_position = mate_position.parse::<u64>().ok()?;
+
+  let side = if bracket == 0 {
+    ClipSideEnum::Left
+  } else {
+    ClipSideEnum::Right
+  };
+  let mate_side = if mate_bracket == '[' {
+    ClipSideEnum::Left
+  } else {
+    ClipSideEnum::Right
+  };
+  Some((
+    mate_chr.to_string(),
+    Position::OneBased(mate_position),
+    (side, mate_side),
+  ))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,685 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use std::io::Write;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use crate::{
+  error::common_error::CommonError,
+  custom::{
+    clip_side_enum::ClipSideEnum,
+    me_deletion_call::MEDeletionCall,
+    me_insertion_call::MEInsertionCall,
+    merged_call::MergedCall,
+    position::Position,
+    sv_call::SVCall,
+    sv_type::SVType,
+  },
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// VCF header
+const VCF_VERSION: &str = "##fileformat=VCFv4.2";
+const VCF_SOURCE: &str = "##source=genomic_structures";
+const VCF_COLUMNS: &str = "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO";
+
+const ME_HEADER: [&str; 15] = [
+  "##ALT=<ID=INS:ME,Description=\"Insertion of mobile element\">",
+  "##FILTER=<ID=BLACKLIST,Description=\"Site overlaps blacklist region\">",
+  "##FILTER=<ID=REF_ME,Description=\"Site near reference mobile element of the same family\">",
+  "##INFO=<ID=SVTYPE,Number=1,Type=String,Description=\"Type of structural variant\">",
+  "##INFO=<ID=SVLEN,Number=1,Type=Integer,Description=\"Inserted mobile element length\">",
+  "##INFO=<ID=MEINFO,Number=4,Type=String,Description=\"Mobile element info of the form NAME,START,END,POLARITY\">",
+  "##INFO=<ID=CIPOS,Number=2,Type=Integer,Description=\"Confidence interval around POS\">",
+  "##INFO=<ID=IMPRECISE,Number=0,Type=Flag,Description=\"Imprecise insertion position\">",
+  "##INFO=<ID=SUPPORT,Number=2,Type=Integer,Description=\"Discordant pairs supporting 5' and 3' junctions\">",
+  "##INFO=<ID=SR,Number=1,Type=Integer,Description=\"Split reads supporting insertion position\">",
+  "##INFO=<ID=TSD,Number=1,Type=String,Description=\"Target site duplication sequence\">",
+  "##INFO=<ID=POLYA,Number=1,Type=Integer,Description=\"Poly-A tail length\">",
+  "##INFO=<ID=GENE,Number=1,Type=String,Description=\"Gene hit by insertion, or nearest gene\">",
+  "##INFO=<ID=FEATURE,Number=1,Type=String,Description=\"Gene feature hit by insertion: UTR, exon, intron, promoter or intergenic\">",
+  "##INFO=<ID=GENEDIST,Number=1,Type=Integer,Description=\"Distance to gene\">",
+];
+
+const GENOTYPE_HEADER: [&str; 4] = [
+  "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">",
+  "##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Genotype quality\">",
+  "##FORMAT=<ID=GL,Number=G,Type=Float,Description=\"Genotype likelihoods, log10 scaled\">",
+  "##FORMAT=<ID=AD,Number=R,Type=Integer,Description=\"Fragments supporting reference and alternate alleles\">",
+];
+const GENOTYPE_FORMAT: &str = "GT:GQ:GL:AD";
+
+const ME_DELETION_HEADER: [&str; 9] = [
+  "##ALT=<ID=DEL:ME,Description=\"Deletion of reference mobile element\">",
+  "##INFO=<ID=SVTYPE,Number=1,Type=String,Description=\"Type of structural variant\">",
+  "##INFO=<ID=SVLEN,Number=1,Type=Integer,Description=\"Deleted mobile element length\">",
+  "##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position of the variant\">",
+  "##INFO=<ID=MEINFO,Number=4,Type=String,Description=\"Mobile element info of the form NAME,START,END,POLARITY\">",
+  "##INFO=<ID=IMPRECISE,Number=0,Type=Flag,Description=\"Imprecise deletion breakends\">",
+  "##INFO=<ID=PE,Number=1,Type=Integer,Description=\"Discordant pairs supporting deletion\">",
+  "##INFO=<ID=SR,Number=1,Type=Integer,Description=\"Split reads supporting deletion\">",
+  "##INFO=<ID=DELLEN,Number=1,Type=Integer,Description=\"Deleted length between observed breakends\">",
+];
+
+const SV_HEADER: [&str; 25] = [
+  "##ALT=<ID=DEL,Description=\"Deletion\">",
+  "##ALT=<ID=DUP,Description=\"Duplication\">",
+  "##ALT=<ID=INV,Description=\"Inversion\">",
+  "##ALT=<ID=INS,Description=\"Insertion\">",
+  "##ALT=<ID=DUP:DISPERSED,Description=\"Duplication inserted away from source segment\">",
+  "##ALT=<ID=DUP:INV,Description=\"Inverted duplication\">",
+  "##ALT=<ID=INV:DEL,Description=\"Inversion flanked by deletion\">",
+  "##FILTER=<ID=DEPTH,Description=\"Read depth contradicts copy number change\">",
+  "##INFO=<ID=SVTYPE,Number=1,Type=String,Description=\"Type of structural variant\">",
+  "##INFO=<ID=SVLEN,Number=1,Type=Integer,Description=\"Difference in length between REF and ALT alleles\">",
+  "##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position of the variant\">",
+  "##INFO=<ID=CHR2,Number=1,Type=String,Description=\"Chromosome of mate breakend\">",
+  "##INFO=<ID=IMPRECISE,Number=0,Type=Flag,Description=\"Imprecise structural variation\">",
+  "##INFO=<ID=PE,Number=1,Type=Integer,Description=\"Discordant pairs supporting variant\">",
+  "##INFO=<ID=SR,Number=1,Type=Integer,Description=\"Split reads supporting variant\">",
+  "##INFO=<ID=MATEID,Number=1,Type=String,Description=\"ID of mate breakend\">",
+  "##INFO=<ID=EVENT,Number=1,Type=String,Description=\"ID of event associated to breakend\">",
+  "##INFO=<ID=HOMLEN,Number=1,Type=Integer,Description=\"Length of base pair identical micro-homology at event breakpoints\">",
+  "##INFO=<ID=HOMSEQ,Number=1,Type=String,Description=\"Sequence of base pair identical micro-homology at event breakpoints\">",
+  "##INFO=<ID=SVINSSEQ,Number=1,Type=String,Description=\"Non-templated sequence inserted at event breakpoints\">",
+  "##INFO=<ID=DR,Number=1,Type=Float,Description=\"Read depth over flanking read depth\">",
+  "##INFO=<ID=CN,Number=1,Type=Integer,Description=\"Copy number estimate on read depth\">",
+  "##INFO=<ID=GENE,Number=1,Type=String,Description=\"Gene hit by variant, or nearest gene\">",
+  "##INFO=<ID=FEATURE,Number=1,Type=String,Description=\"Gene feature hit by variant: UTR, exon, intron, promoter or intergenic\">",
+  "##INFO=<ID=GENEDIST,Number=1,Type=Integer,Description=\"Distance to gene\">",
+];
+
+const MERGE_HEADER: [&str; 2] = [
+  "##INFO=<ID=SUPP,Number=1,Type=Integer,Description=\"Number of inputs supporting merged call\">",
+  "##INFO=<ID=SUPP_VEC,Number=1,Type=String,Description=\"Inputs supporting merged call, one digit per input in input order\">",
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+///
+/// Write mobile element insertion calls as sites-only VCF.
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `me_insertion_calls` - Insertion calls.
+///
+/// # Returns
+///
+/// Return error on failed writing.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   write_me_vcf,
+///   MEInsertionCall,
+///   Position,
+/// };
+///
+/// let mut me_insertion_call = MEInsertionCall::new();
+/// me_insertion_call.chr = "chr1".to_string();
+/// me_insertion_call.position = Position::OneBased(10000);
+/// me_insertion_call.ci_start = Position::OneBased(9990);
+/// me_insertion_call.ci_end = Position::OneBased(10020);
+/// me_insertion_call.me_info.mobel = "L1HS".to_string();
+///
+/// let mut vcf = Vec::new();
+/// write_me_vcf(&mut vcf, &[me_insertion_call]).expect("Writing failed!");
+/// let vcf = String::from_utf8(vcf).expect("Invalid UTF-8!");
+///
+/// assert!(vcf.starts_with("##fileformat=VCFv4.2"));
+/// assert!(vcf.contains("chr1\t10000\tME1\tN\t<INS:ME:L1HS>\t.\tPASS\t"));
+/// assert!(vcf.contains("CIPOS=-10,20;IMPRECISE"));
+/// ```
+pub fn write_me_vcf<W: Write>(
+  writer: &mut W,
+  me_insertion_calls: &[MEInsertionCall],
+) -> anyResult<()> {
+  let mut header = vec![VCF_VERSION, VCF_SOURCE];
+  header.extend(ME_HEADER.iter());
+  header.push(VCF_COLUMNS);
+  writeln!(writer, "{}", header.join("\n")).context(CommonError::Writing)?;
+
+  for (ix, me_insertion_call) in me_insertion_calls.iter().enumerate() {
+    writeln!(writer, "{}", me_record(me_insertion_call, ix + 1))
+      .context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+///
+/// Write jointly genotyped mobile element insertion calls as multi-sample VCF.
+///
+/// Sample columns follow sample order. Calls missing a sample genotype are
+/// written as no-calls.
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `samples` - Sample names.
+///
+/// * `me_insertion_calls` - Catalog sites carrying genotypes in sample order.
+///
+/// # Returns
+///
+/// Return error on failed writing.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   write_genotyped_me_vcf,
+///   MEInsertionCall,
+///   Position,
+///   SampleGenotype,
+/// };
+///
+/// let mut me_insertion_call = MEInsertionCall::new();
+/// me_insertion_call.chr = "chr1".to_string();
+/// me_insertion_call.position = Position::OneBased(10000);
+/// me_insertion_call.me_info.mobel = "L1HS".to_string();
+/// me_insertion_call.genotypes = vec![SampleGenotype::load(0, 12, 0.01)];
+///
+/// let samples = vec!["NA12878".to_string(), "NA12891".to_string()];
+/// let mut vcf = Vec::new();
+/// write_genotyped_me_vcf(&mut vcf, &samples, &[me_insertion_call])
+///   .expect("Writing failed!");
+/// let vcf = String::from_utf8(vcf).expect("Invalid UTF-8!");
+///
+/// assert!(vcf.contains("\tFORMAT\tNA12878\tNA12891\n"));
+/// assert!(vcf.contains("\tGT:GQ:GL:AD\t1/1:"));
+/// assert!(vcf.ends_with("\t./.\n"));
+/// ```
+pub fn write_genotyped_me_vcf<W: Write>(
+  writer: &mut W,
+  samples: &[String],
+  me_insertion_calls: &[MEInsertionCall],
+) -> anyResult<()> {
+  let mut header = vec![VCF_VERSION, VCF_SOURCE];
+  header.extend(ME_HEADER.iter());
+  header.extend(GENOTYPE_HEADER.iter());
+  writeln!(writer, "{}", header.join("\n")).context(CommonError::Writing)?;
+  writeln!(writer, "{}\tFORMAT\t{}", VCF_COLUMNS, samples.join("\t"))
+    .context(CommonError::Writing)?;
+
+  for (ix, me_insertion_call) in me_insertion_calls.iter().enumerate() {
+    let genotypes = (0..samples.len())
+      .map(|sample| match me_insertion_call.genotypes.get(sample) {
+        Some(sample_genotype) => sample_genotype.to_string(),
+        None => "./.".to_string(),
+      })
+      .collect::<Vec<String>>();
+    writeln!(
+      writer,
+      "{}\t{}\t{}",
+      me_record(me_insertion_call, ix + 1),
+      GENOTYPE_FORMAT,
+      genotypes.join("\t")
+    )
+    .context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+///
+/// Write structural variant calls as sites-only VCF.
+///
+/// Translocations are written as paired breakends.
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `sv_calls` - Structural variant calls.
+///
+/// # Returns
+///
+/// Return error on failed writing.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   write_sv_vcf,
+///   Position,
+///   SVCall,
+///   SVType,
+/// };
+///
+/// let mut sv_call = SVCall::new(SVType::Deletion);
+/// sv_call.chr = "chr1".to_string();
+/// sv_call.chr2 = "chr1".to_string();
+/// sv_call.position = Position::OneBased(1001);
+/// sv_call.end = Position::OneBased(1800);
+/// sv_call.support = 5;
+///
+/// let mut vcf = Vec::new();
+/// write_sv_vcf(&mut vcf, &[sv_call]).expect("Writing failed!");
+/// let vcf = String::from_utf8(vcf).expect("Invalid UTF-8!");
+///
+/// assert!(vcf.contains(
+///   "chr1\t1001\tSV1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;SVLEN=-800;END=1800;IMPRECISE;PE=5"
+/// ));
+/// ```
+pub fn write_sv_vcf<W: Write>(
+  writer: &mut W,
+  sv_calls: &[SVCall],
+) -> anyResult<()> {
+  let mut header = vec![VCF_VERSION, VCF_SOURCE];
+  header.extend(SV_HEADER.iter());
+  header.push(VCF_COLUMNS);
+  writeln!(writer, "{}", header.join("\n")).context(CommonError::Writing)?;
+
+  for (ix, sv_call) in sv_calls.iter().enumerate() {
+    for record in sv_records(sv_call, ix + 1) {
+      writeln!(writer, "{}", record).context(CommonError::Writing)?;
+    }
+  }
+  Ok(())
+}
+
+///
+/// Write reference mobile element deletion calls as sites-only VCF.
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `me_deletion_calls` - Element deletion calls.
+///
+/// # Returns
+///
+/// Return error on failed writing.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   write_me_deletion_vcf,
+///   MEClassification,
+///   MEDeletionCall,
+///   Position,
+/// };
+///
+/// let mut me_deletion_call = MEDeletionCall::new();
+/// me_deletion_call.chr = "chr1".to_string();
+/// me_deletion_call.position = Position::OneBased(9001);
+/// me_deletion_call.end = Position::OneBased(9300);
+/// me_deletion_call.deleted_length = 310;
+/// me_deletion_call.classification =
+///   MEClassification::load("AluY", "SINE/Alu");
+/// me_deletion_call.support = 4;
+///
+/// let mut vcf = Vec::new();
+/// write_me_deletion_vcf(&mut vcf, &[me_deletion_call])
+///   .expect("Writing failed!");
+/// let vcf = String::from_utf8(vcf).expect("Invalid UTF-8!");
+///
+/// assert!(vcf.contains("chr1\t9001\tMED1\tN\t<DEL:ME:Alu>\t.\tPASS\t"));
+/// assert!(vcf.contains("SVTYPE=DEL;SVLEN=-300;END=9300;"));
+/// ```
+pub fn write_me_deletion_vcf<W: Write>(
+  writer: &mut W,
+  me_deletion_calls: &[MEDeletionCall],
+) -> anyResult<()> {
+  let mut header = vec![VCF_VERSION, VCF_SOURCE];
+  header.extend(ME_DELETION_HEADER.iter());
+  header.push(VCF_COLUMNS);
+  writeln!(writer, "{}", header.join("\n")).context(CommonError::Writing)?;
+
+  for (ix, me_deletion_call) in me_deletion_calls.iter().enumerate() {
+    writeln!(writer, "{}", me_deletion_record(me_deletion_call, ix + 1))
+      .context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+///
+/// Write merged call sets as sites-only VCF, after SURVIVOR.
+///
+/// Merged calls are written through their representative call, tagged with
+/// supporting inputs (SUPP) & input provenance (SUPP_VEC). Records are
+/// sorted by chromosome & position.
+///
+/// # Parameters
+///
+/// * `writer` - Output destination.
+///
+/// * `inputs` - Number of merged inputs.
+///
+/// * `merged_sv_calls` - Merged structural variant calls.
+///
+/// * `merged_me_insertion_calls` - Merged insertion calls.
+///
+/// # Returns
+///
+/// Return error on failed writing.
+///
+/// # Examples
+///
+/// ```
+/// use genomic_structures::{
+///   write_merged_vcf,
+///   MEInsertionCall,
+///   MergedCall,
+///   Position,
+///   SVCall,
+///   SVType,
+/// };
+///
+/// let mut sv_call = SVCall::new(SVType::Deletion);
+/// sv_call.chr = "chr1".to_string();
+/// sv_call.chr2 = "chr1".to_string();
+/// sv_call.position = Position::OneBased(5001);
+/// sv_call.end = Position::OneBased(5800);
+/// let mut merged_sv_call = MergedCall::new(sv_call);
+/// merged_sv_call.members = vec![(0, 0), (2, 3)];
+///
+/// let mut me_insertion_call = MEInsertionCall::new();
+/// me_insertion_call.chr = "chr1".to_string();
+/// me_insertion_call.position = Position::OneBased(1000);
+/// me_insertion_call.me_info.mobel = "L1HS".to_string();
+/// let mut merged_me_insertion_call = MergedCall::new(me_insertion_call);
+/// merged_me_insertion_call.members = vec![(1, 0)];
+///
+/// let mut vcf = Vec::new();
+/// write_merged_vcf(&mut vcf, 3, &[merged_sv_call], &[
+///   merged_me_insertion_call,
+/// ])
+/// .expect("Writing failed!");
+/// let vcf = String::from_utf8(vcf).expect("Invalid UTF-8!");
+/// let records = vcf
+///   .lines()
+///   .filter(|line| !line.starts_with('#'))
+///   .collect::<Vec<&str>>();
+///
+/// assert!(records[0].starts_with("chr1\t1000\tME1\t"));
+/// assert!(records[0].ends_with(";SUPP=1;SUPP_VEC=010"));
+/// assert!(records[1].starts_with("chr1\t5001\tSV1\t"));
+/// assert!(records[1].ends_with(";SUPP=2;SUPP_VEC=101"));
+/// ```
+pub fn write_merged_vcf<W: Write>(
+  writer: &mut W,
+  inputs: usize,
+  merged_sv_calls: &[MergedCall<SVCall>],
+  merged_me_insertion_calls: &[MergedCall<MEInsertionCall>],
+) -> anyResult<()> {
+  // insertion header lines not declared by structural variant header
+  let mut header = vec![VCF_VERSION, VCF_SOURCE];
+  header.extend(SV_HEADER.iter());
+  header.extend(ME_HEADER.iter().filter(|line| {
+    let id = line.split(',').next().unwrap_or_default();
+    !SV_HEADER.iter().any(|sv_line| sv_line.split(',').next() == Some(id))
+  }));
+  header.extend(MERGE_HEADER.iter());
+  header.push(VCF_COLUMNS);
+  writeln!(writer, "{}", header.join("\n")).context(CommonError::Writing)?;
+
+  let mut records = Vec::new();
+  for (ix, merged_call) in merged_sv_calls.iter().enumerate() {
+    let provenance = provenance(merged_call, inputs);
+    for record in sv_records(&merged_call.call, ix + 1) {
+      records.push(format!("{};{}", record, provenance));
+    }
+  }
+  for (ix, merged_call) in merged_me_insertion_calls.iter().enumerate() {
+    records.push(format!(
+      "{};{}",
+      me_record(&merged_call.call, ix + 1),
+      provenance(merged_call, inputs)
+    ));
+  }
+  records.sort_by_cached_key(|record| {
+    let mut fields = record.split('\t');
+    let chr = fields.next().unwrap_or_default().to_string();
+    let position = fields
+      .next()
+      .and_then(|position| position.parse::<u64>().ok())
+      .unwrap_or_default();
+    (chr, position)
+  });
+
+  for record in records {
+    writeln!(writer, "{}", record).context(CommonError::Writing)?;
+  }
+  Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// supporting inputs & input provenance INFO
+fn provenance<T>(
+  merged_call: &MergedCall<T>,
+  inputs: usize,
+) -> String {
+  format!(
+    "SUPP={};SUPP_VEC={}",
+    merged_call.inputs().len(),
+    merged_call.support_vector(inputs)
+  )
+}
+
+// mobile element insertion VCF record
+fn me_record(
+  me_insertion_call: &MEInsertionCall,
+  id: usize,
+) -> String {
+  let mut info = vec![
+    "SVTYPE=INS".to_string(),
+    format!("SVLEN={}", me_insertion_call.me_info.length),
+    format!("MEINFO={}", me_insertion_call.me_info),
+    format!(
+      "CIPOS={},{}",
+      me_insertion_call.ci_start - me_insertion_call.position,
+      me_insertion_call.ci_end - me_insertion_call.position
+    ),
+  ];
+  if !me_insertion_call.precise {
+    info.push("IMPRECISE".to_string());
+  }
+  info.push(format!(
+    "SUPPORT={},{}",
+    me_insertion_call.five_prime, me_insertion_call.three_prime
+  ));
+  info.push(format!("SR={}", me_insertion_call.split_reads));
+  if let Some(tsd) = &me_insertion_call.tsd {
+    info.push(format!("TSD={}", tsd.sequence));
+  }
+  if let Some(tail_length) = me_insertion_call.tail_length {
+    info.push(format!("POLYA={}", tail_length));
+  }
+  if let Some(gene_annotation) = &me_insertion_call.gene_annotation {
+    info.push(gene_annotation.to_string());
+  }
+
+  let filter = if me_insertion_call.filters.is_empty() {
+    "PASS".to_string()
+  } else {
+    me_insertion_call
+      .filters
+      .iter()
+      .map(|filter| filter.to_string())
+      .collect::<Vec<String>>()
+      .join(";")
+  };
+
+  format!(
+    "{}\t{}\tME{}\tN\t<INS:ME:{}>\t.\t{}\t{}",
+    me_insertion_call.chr,
+    me_insertion_call.position,
+    id,
+    me_insertion_call.family(),
+    filter,
+    info.join(";")
+  )
+}
+
+// reference mobile element deletion VCF record
+fn me_deletion_record(
+  me_deletion_call: &MEDeletionCall,
+  id: usize,
+) -> String {
+  let mut info = vec![
+    "SVTYPE=DEL".to_string(),
+    format!("SVLEN=-{}", me_deletion_call.length()),
+    format!("END={}", me_deletion_call.end),
+    format!("MEINFO={}", me_deletion_call.me_info),
+  ];
+  if !me_deletion_call.precise {
+    info.push("IMPRECISE".to_string());
+  }
+  info.push(format!("PE={}", me_deletion_call.support));
+  info.push(format!("SR={}", me_deletion_call.split_reads));
+  info.push(format!("DELLEN={}", me_deletion_call.deleted_length));
+
+  format!(
+    "{}\t{}\tMED{}\tN\t<DEL:ME:{}>\t.\tPASS\t{}",
+    me_deletion_call.chr,
+    me_deletion_call.position,
+    id,
+    me_deletion_call.classification.family,
+    info.join(";")
+  )
+}
+
+// structural variant VCF records
+fn sv_records(
+  sv_call: &SVCall,
+  id: usize,
+) -> Vec<String> {
+  // reciprocal junctions linked as single event
+  if sv_call.svtype == SVType::BalancedTranslocation {
+    return sv_call
+      .components
+      .iter()
+      .enumerate()
+      .flat_map(|(ix, component)| {
+        let event = format!("EVENT=SV{}", id);
+        breakend_records(component, id, 2 * ix + 1, Some(&event))
+      })
+      .collect();
+  }
+  if sv_call.svtype.is_breakend() {
+    return breakend_records(sv_call, id, 1, None);
+  }
+
+  let svlen = sv_call.length().unwrap_or_default() as i64;
+  let svlen = if sv_call.svtype == SVType::Deletion { -svlen } else { svlen };
+  let filter = if sv_call.depth_supported() { "PASS" } else { "DEPTH" };
+  vec![format!(
+    "{}\t{}\tSV{}\tN\t{}\t.\t{}\tSVTYPE={};SVLEN={};END={};{}",
+    sv_call.chr,
+    sv_call.position,
+    id,
+    sv_call.svtype.alt(),
+    filter,
+    sv_call.svtype,
+    svlen,
+    sv_call.end,
+    evidence(sv_call)
+  )]
+}
+
+// breakend & mate breakend VCF records
+fn breakend_records(
+  sv_call: &SVCall,
+  id: usize,
+  first: usize,
+  event: Option<&str>,
+) -> Vec<String> {
+  let mut evidence = evidence(sv_call);
+  if let Some(event) = event {
+    evidence = format!("{};{}", evidence, event);
+  }
+  let info = |mate_chr: &str, mate_id: usize| {
+    format!(
+      "SVTYPE=BND;CHR2={};{};MATEID=SV{}_{}",
+      mate_chr, evidence, id, mate_id
+    )
+  };
+  // forward join on unknown orientation
+  let (side, mate_side) = match sv_call.orientation {
+    (ClipSideEnum::None, _) | (_, ClipSideEnum::None) => {
+      (ClipSideEnum::Right, ClipSideEnum::Left)
+    }
+    orientation => orientation,
+  };
+  vec![
+    format!(
+      "{}\t{}\tSV{}_{}\tN\t{}\t.\tPASS\t{}",
+      sv_call.chr,
+      sv_call.position,
+      id,
+      first,
+      breakend_alt(side, mate_side, &sv_call.chr2, sv_call.end),
+      info(&sv_call.chr2, first + 1)
+    ),
+    format!(
+      "{}\t{}\tSV{}_{}\tN\t{}\t.\tPASS\t{}",
+      sv_call.chr2,
+      sv_call.end,
+      id,
+      first + 1,
+      breakend_alt(mate_side, side, &sv_call.chr, sv_call.position),
+      info(&sv_call.chr, first)
+    ),
+  ]
+}
+
+// precision, junction, support, read depth & gene annotations
+fn evidence(sv_call: &SVCall) -> String {
+  let mut evidence = Vec::new();
+  match &sv_call.junction {
+    Some(sv_junction) => {
+      evidence.push(format!("HOMLEN={}", sv_junction.homology_length()));
+      if !sv_junction.homology.is_empty() {
+        evidence.push(format!("HOMSEQ={}", sv_junction.homology));
+      }
+      if !sv_junction.insertion.is_empty() {
+        evidence.push(format!("SVINSSEQ={}", sv_junction.insertion));
+      }
+    }
+    None if sv_call.split_reads == 0 => {
+      evidence.push("IMPRECISE".to_string())
+    }
+    None => (),
+  }
+  evidence.push(format!("PE={}", sv_call.support));
+  evidence.push(format!("SR={}", sv_call.split_reads));
+  if let (Some(depth_ratio), Some(copy_number)) =
+    (sv_call.depth_ratio, sv_call.copy_number())
+  {
+    evidence.push(format!("DR={:.2};CN={}", depth_ratio, copy_number));
+  }
+  if let Some(gene_annotation) = &sv_call.gene_annotation {
+    evidence.push(gene_annotation.to_string());
+  }
+  evidence.join(";")
+}
+
+// breakend ALT on junction sides
+fn breakend_alt(
+  side: ClipSideEnum,
+  mate_side: ClipSideEnum,
+  mate_chr: &str,
+  mate_position: Position,
+) -> String {
+  match (side, mate_side) {
+    (ClipSideEnum::Left, ClipSideEnum::Left) => {
+      format!("[{}:{}[N", mate_chr, mate_position)
+    }
+    (ClipSideEnum::Left, _) => format!("]{}:{}]N", mate_chr, mate_position),
+    (_, ClipSideEnum::Right) => format!("N]{}:{}]", mate_chr, mate_position),
+    _ => format!("N[{}:{}[", mate_chr, mate_position),
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
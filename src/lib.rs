@@ -30,15 +30,68 @@ mod function;
 
 // function
 pub use crate::function::{
+  alignment_decoder::decode_alignment,
+  break_point_refiner::{
+    clip_histogram,
+    refine_break_point,
+  },
+  complex_resolver::resolve_complex,
+  fasta_indexer::{
+    index_bgzf,
+    index_fasta,
+    is_bgzf,
+    load_fai,
+    load_gzi,
+    write_fai,
+    write_gzi,
+  },
   flag_interpretor::interpret,
-  identificator::identify,
+  gene_annotator::{
+    annotate_me_insertions,
+    annotate_sv_calls,
+  },
+  genotype_likelihood::genotype_likelihoods,
+  identificator::{
+    identify,
+    identify_split,
+  },
+  junction_analyzer::characterize_junction,
+  library_loader::load_library,
+  me_classifier::{
+    aggregate_mobel,
+    classify,
+    classify_mobel,
+    collect_classification,
+    load_classification,
+    load_repeat_masker,
+  },
+  me_deletion_detector::detect_me_deletions,
+  me_summarizer::summarize_me,
   position_binner::bin,
-  sequences::reverse_sequence,
+  sequences::{
+    gc_content,
+    reverse_sequence,
+  },
+  tail_detector::{
+    detect_poly_tail,
+    site_tail_length,
+  },
+  thresholder::threshold,
+  tsd_detector::detect_tsd,
+  vcf_loader::load_vcf,
+  vcf_writer::{
+    write_genotyped_me_vcf,
+    write_me_deletion_vcf,
+    write_me_vcf,
+    write_merged_vcf,
+    write_sv_vcf,
+  },
 };
 
 // traits
 pub use crate::function::{
   flag_interpretor::SAMFlag,
+  identificator::SVIdentify,
   position_binner::Anchor,
   sequences::Sequence,
 };
@@ -50,45 +103,104 @@ mod custom;
 
 // enums
 pub use crate::custom::{
+  ambiguity_enum::AmbiguityEnum,
   anchor_enum::AnchorEnum,
   chr_anchor_enum::ChrAnchorEnum,
+  clip_side_enum::ClipSideEnum,
   extra_values_enum::ExtraValuesEnum,
+  feature_enum::FeatureEnum,
+  genotype_enum::GenotypeEnum,
+  me_level_enum::MELevelEnum,
+  merge_mode_enum::MergeModeEnum,
   orientation_enum::OrientationEnum,
+  origin_enum::OriginEnum,
+  repair_enum::RepairEnum,
+  resolution_strategy_enum::ResolutionStrategyEnum,
+  site_filter_enum::SiteFilterEnum,
+  strand_enum::StrandEnum,
 };
 
 // structs
 pub use crate::custom::{
+  allele_count::AlleleCount,
+  allele_counter::AlleleCounter,
+  anchor_resolution::AnchorResolution,
+  annotation_index::AnnotationIndex,
+  bam_reader::BamReader,
   bin_position::BinPosition,
   break_point::BreakPoint,
+  call_merger::CallMerger,
   chr_anchor::ChrAnchor,
   cigar::CIGAR,
+  depth_profile::DepthProfile,
+  detection_config::DetectionConfig,
   erv_annotations::ERVAnnotations,
+  fasta_index_record::FastaIndexRecord,
+  feature_index::FeatureIndex,
+  gene_annotation::GeneAnnotation,
+  genomic_interval::GenomicInterval,
+  indexed_fasta::IndexedFasta,
+  interval_index::IntervalIndex,
   me_anchor::MEAnchor,
   me_chimeric_pair::MEChimericPair,
   me_chimeric_read::MEChimericRead,
+  me_classification::MEClassification,
+  me_deletion_call::MEDeletionCall,
+  me_genotyper::MEGenotyper,
+  me_info::MEInfo,
+  me_insertion_call::MEInsertionCall,
+  me_insertion_caller::MEInsertionCaller,
   me_library::MELibrary,
+  merged_call::MergedCall,
   orientation_enum::OrientationPair,
+  poly_tail::PolyTail,
+  position::Position,
   raw_values::RawValues,
   read_control::ReadControl,
+  refined_break_point::RefinedBreakPoint,
+  region::Region,
+  repeat_masker_record::RepeatMaskerRecord,
+  sample_genotype::SampleGenotype,
+  site_filter::SiteFilter,
   strand_direction::StrandDirection,
+  supplementary_alignment::SupplementaryAlignment,
+  sv_call::SVCall,
+  sv_caller::SVCaller,
   sv_chimeric_pair::SVChimericPair,
   sv_chimeric_read::SVChimericRead,
+  sv_junction::SVJunction,
   sv_type::SVType,
+  target_site_duplication::TargetSiteDuplication,
 };
 
 // traits
 pub use crate::custom::{
   activate::ActivateExt,
+  call_merger::Mergeable,
   me_anchor::TagME,
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-// TODO: handle constants
-pub const BIN_OVERLAP: i32 = 50;
-pub const BIN_SIZE: i32 = 100;
-pub const TRANSLOCATION_DISTANCE: i32 = 1000;
-pub const ME_LIMIT: i32 = 200;
-pub const ANCHOR_LIMIT: i32 = 50;
+// default detection parameters, derived from `DetectionConfig::DEFAULT`
+#[deprecated(note = "use `DetectionConfig::default().bin_overlap`")]
+pub const BIN_OVERLAP: i32 = DetectionConfig::DEFAULT.bin_overlap;
+#[deprecated(note = "use `DetectionConfig::default().bin_size`")]
+pub const BIN_SIZE: i32 = DetectionConfig::DEFAULT.bin_size;
+#[deprecated(note = "use `DetectionConfig::default().translocation_distance`")]
+pub const TRANSLOCATION_DISTANCE: i32 =
+  DetectionConfig::DEFAULT.translocation_distance;
+#[deprecated(note = "use `DetectionConfig::default().me_limit`")]
+pub const ME_LIMIT: i32 = DetectionConfig::DEFAULT.me_limit;
+#[deprecated(note = "use `DetectionConfig::default().anchor_limit`")]
+pub const ANCHOR_LIMIT: i32 = DetectionConfig::DEFAULT.anchor_limit;
+#[deprecated(note = "use `DetectionConfig::default().breakpoint_flank`")]
+pub const BREAKPOINT_FLANK: usize = DetectionConfig::DEFAULT.breakpoint_flank;
+#[deprecated(note = "use `DetectionConfig::default().min_mapq`")]
+pub const MIN_MAPQ: i32 = DetectionConfig::DEFAULT.min_mapq;
+
+// read depth ratios halfway between diploid & single copy change
+pub const MAX_DELETION_DEPTH: f64 = 0.75;
+pub const MIN_DUPLICATION_DEPTH: f64 = 1.25;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,54 +1,31 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Load features onto mobile element chimeric pair.
+/// Default detection parameters are used when none are provided.
 #[macro_export]
 macro_rules! load {
   // mobile element on hashmap
-  ( mobile element |> $record: expr; $values: expr; $read_no: tt ) => {
-    // record data on primary alignment
-    if $values.flag <= 255 {
-      $record.$read_no.sequence = $values.sequence.clone();
-    }
-
-    // record mobile element data
-    $record.$read_no.me_read.push(MEAnchor::load(
-      $values.cigar.clone(),
-      $values.flag,
-      $values.scaffold.clone(),
-      $values.orientation.clone(),
-      $values.position,
-      $values.get_extra(),
-    ));
+  ( mobile element |> $record: expr; $values: expr; $read_no: tt; $config: expr ) => {
+    $record.$read_no.load_me(&$values, &$config);
+  };
 
-    // calculate break point
-    $record
-      .$read_no
-      .me_read
-      .iter_mut()
-      .last()
-      .unwrap()
-      .calculate_break_point(&$record.$read_no.sequence.clone());
+  ( mobile element |> $record: expr; $values: expr; $read_no: tt ) => {
+    $crate::load!(
+      mobile element |> $record; $values; $read_no;
+      $crate::DetectionConfig::default()
+    );
   };
 
   // chromosomal loci
-  ( chromosomal |> $record: expr; $values: expr; $read_no: tt ) => {
-    // check whether read id & sequence coincide
-    if $record.$read_no.sequence == $values.sequence ||
-      $record.$read_no.reverse_sequence() == $values.sequence
-    {
-      // assign chromosomal anchor mapping quality
-      $record.$read_no.quality = $values.quality;
+  ( chromosomal |> $record: expr; $values: expr; $read_no: tt; $config: expr ) => {
+    $record.$read_no.load_chr(&$values, &$config);
+  };
 
-      // load chromosomal anchor
-      $record.$read_no.chr_read.push(ChrAnchor::load(
-        $values.cigar.clone(),
-        $values.scaffold.clone(),
-        $values.flag,
-        $values.quality,
-        $values.position,
-        $values.tlen,
-      ))
-    }
+  ( chromosomal |> $record: expr; $values: expr; $read_no: tt ) => {
+    $crate::load!(
+      chromosomal |> $record; $values; $read_no;
+      $crate::DetectionConfig::default()
+    );
   };
 }
 
@@ -63,7 +40,7 @@ macro_rules! load {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-///
+/// Count mobile element anchors by orientation.
 #[macro_export]
 macro_rules! anchor_count {
   ( $me_chimeric_read: expr, $anchor: tt ) => {
@@ -1,7 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Calculate effective genome / chromosome / scaffold length
-/// with default parameters: `BIN_SIZE` and `BIN_OVERLAP`.
+/// with default bin size & overlap, see `DetectionConfig`.
 #[macro_export]
 macro_rules! calculate_effective_len {
   // explicit values
@@ -11,14 +11,18 @@ macro_rules! calculate_effective_len {
 
   // default values
   ( $gl: expr ) => {
-    calculate_effective_len($gl, BIN_SIZE as f64, BIN_OVERLAP as f64)
+    calculate_effective_len(
+      $gl,
+      $crate::DetectionConfig::DEFAULT.bin_size as f64,
+      $crate::DetectionConfig::DEFAULT.bin_overlap as f64,
+    )
   };
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Calculate Poisson's lambda (λ)
-/// with default parameters: `BIN_SIZE`.
+/// with default bin size, see `DetectionConfig`.
 #[macro_export]
 macro_rules! calculate_lambda {
   // explicit values
@@ -28,7 +32,11 @@ macro_rules! calculate_lambda {
 
   // default values
   ( $pr: expr, $egl: expr ) => {
-    calculate_lambda($pr, $egl, BIN_SIZE as f64)
+    calculate_lambda(
+      $pr,
+      $egl,
+      $crate::DetectionConfig::DEFAULT.bin_size as f64,
+    )
   };
 }
 
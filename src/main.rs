@@ -0,0 +1,770 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use anyhow::Context;
+use anyhow::Result as anyResult;
+use clap::{
+  App,
+  AppSettings,
+  Arg,
+  ArgMatches,
+  ErrorKind,
+  SubCommand,
+};
+use colored::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{
+  self,
+  BufRead,
+  BufReader,
+  BufWriter,
+  Write,
+};
+use std::process;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  annotate_me_insertions,
+  annotate_sv_calls,
+  classify,
+  decode_alignment,
+  detect_me_deletions,
+  interpret,
+  load_classification,
+  load_library,
+  load_repeat_masker,
+  load_vcf,
+  threshold,
+  write_genotyped_me_vcf,
+  write_me_deletion_vcf,
+  write_me_vcf,
+  write_merged_vcf,
+  write_sv_vcf,
+  AnnotationIndex,
+  CallMerger,
+  CommonError,
+  DetectionConfig,
+  FeatureIndex,
+  IndexedFasta,
+  MEGenotyper,
+  MEInsertionCaller,
+  MELevelEnum,
+  MergeModeEnum,
+  Position,
+  SVCaller,
+  SiteFilter,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// exit codes
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+// SAM flag descriptions, see `interpret`
+const FLAG_DESCRIPTION: [&str; 12] = [
+  "read paired",
+  "read mapped in proper pair",
+  "read unmapped",
+  "mate unmapped",
+  "read reverse strand",
+  "mate reverse strand",
+  "first in pair",
+  "second in pair",
+  "not primary alignment",
+  "read fails platform/vendor quality checks",
+  "read is PCR or optical duplicate",
+  "supplementary alignment",
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn main() {
+  let matches = match cli().get_matches_safe() {
+    Ok(matches) => matches,
+    Err(error) => match error.kind {
+      ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => {
+        println!("{}", error.message);
+        process::exit(EXIT_SUCCESS);
+      }
+      _ => {
+        eprintln!("{}", error.message);
+        process::exit(EXIT_USAGE);
+      }
+    },
+  };
+
+  let quiet = matches.is_present("quiet");
+  let outcome = match matches.subcommand() {
+    ("me-call", Some(arguments)) => me_call(arguments, quiet),
+    ("me-genotype", Some(arguments)) => me_genotype(arguments, quiet),
+    ("sv-call", Some(arguments)) => sv_call(arguments, quiet),
+    ("me-deletion", Some(arguments)) => me_deletion(arguments, quiet),
+    ("merge", Some(arguments)) => merge(arguments, quiet),
+    ("threshold", Some(arguments)) => threshold_bins(arguments),
+    ("flag", Some(arguments)) => explain_flag(arguments),
+    ("cigar", Some(arguments)) => explain_cigar(arguments),
+    (_, _) => Ok(()),
+  };
+
+  if let Err(error) = outcome {
+    eprintln!("{} {:#}", "error:".red().bold(), error);
+    process::exit(EXIT_FAILURE);
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// command line interface
+fn cli() -> App<'static, 'static> {
+  let config = Arg::with_name("config")
+    .long("config")
+    .value_name("TOML")
+    .help("Detection parameters");
+  let output = Arg::with_name("output")
+    .short("o")
+    .long("output")
+    .value_name("VCF")
+    .help("Output file [default: stdout]");
+  let alignment = Arg::with_name("alignment")
+    .value_name("SAM/BAM")
+    .required(true)
+    .help("Reads aligned to reference assembly, '-' for stdin");
+  let insert_size = Arg::with_name("insert-size")
+    .long("insert-size")
+    .value_name("INT")
+    .default_value("500")
+    .help("Expected template length");
+  let min_support = Arg::with_name("min-support")
+    .long("min-support")
+    .value_name("INT")
+    .default_value("2")
+    .help("Minimum discordant pairs & split reads per call");
+  let annotation = Arg::with_name("annotation")
+    .long("annotation")
+    .value_name("GTF")
+    .help("Gene annotation, GTF or GFF3, to label calls by gene feature");
+  let library = Arg::with_name("library")
+    .long("library")
+    .value_name("FASTA")
+    .required(true)
+    .help("Mobile element library");
+  let classification = Arg::with_name("classification")
+    .long("classification")
+    .value_name("TSV")
+    .help("Mobile element classification hierarchy");
+  let level = Arg::with_name("level")
+    .long("level")
+    .value_name("LEVEL")
+    .possible_values(&["class", "family", "subfamily"])
+    .help("Classification level aggregating mobile element support");
+
+  App::new(env!("CARGO_PKG_NAME"))
+    .version(env!("CARGO_PKG_VERSION"))
+    .about(env!("CARGO_PKG_DESCRIPTION"))
+    .setting(AppSettings::SubcommandRequiredElseHelp)
+    .setting(AppSettings::VersionlessSubcommands)
+    .arg(
+      Arg::with_name("quiet")
+        .short("q")
+        .long("quiet")
+        .global(true)
+        .help("Silence progress logging"),
+    )
+    .subcommand(
+      SubCommand::with_name("me-call")
+        .about("Call mobile element insertions")
+        .arg(
+          Arg::with_name("me-alignment")
+            .long("me-alignment")
+            .value_name("SAM/BAM")
+            .required(true)
+            .help("Reads aligned to mobile element library"),
+        )
+        .arg(
+          Arg::with_name("chr-alignment")
+            .long("chr-alignment")
+            .value_name("SAM/BAM")
+            .required(true)
+            .help("Reads aligned to reference assembly"),
+        )
+        .arg(library.clone())
+        .arg(classification.clone())
+        .arg(level.clone())
+        .arg(
+          Arg::with_name("blacklist")
+            .long("blacklist")
+            .value_name("BED")
+            .help("Regions never called"),
+        )
+        .arg(
+          Arg::with_name("repeat-masker")
+            .long("repeat-masker")
+            .value_name("OUT")
+            .help("RepeatMasker annotation to filter reference elements"),
+        )
+        .arg(
+          Arg::with_name("filter-distance")
+            .long("filter-distance")
+            .value_name("INT")
+            .default_value("500")
+            .help("Maximum distance to reference element of the same family"),
+        )
+        .arg(
+          Arg::with_name("drop-filtered")
+            .long("drop-filtered")
+            .help("Drop filtered calls instead of tagging them"),
+        )
+        .arg(annotation.clone())
+        .arg(config.clone())
+        .arg(output.clone()),
+    )
+    .subcommand(
+      SubCommand::with_name("me-genotype")
+        .about("Genotype mobile element insertions jointly across samples")
+        .arg(
+          Arg::with_name("sample")
+            .long("sample")
+            .value_name("NAME,ME_SAM,CHR_SAM")
+            .required(true)
+            .multiple(true)
+            .number_of_values(1)
+            .validator(file_sample)
+            .help("Sample name & alignments, once per sample"),
+        )
+        .arg(library)
+        .arg(classification)
+        .arg(level)
+        .arg(
+          Arg::with_name("merge-distance")
+            .long("merge-distance")
+            .value_name("INT")
+            .default_value("100")
+            .help("Maximum distance between sample sites merged"),
+        )
+        .arg(config.clone())
+        .arg(output.clone()),
+    )
+    .subcommand(
+      SubCommand::with_name("sv-call")
+        .about("Call structural variants on discordant pairs & split reads")
+        .arg(alignment.clone())
+        .arg(insert_size.clone())
+        .arg(min_support.clone())
+        .arg(
+          Arg::with_name("reference")
+            .long("reference")
+            .value_name("FASTA")
+            .help("Reference assembly to characterize junctions"),
+        )
+        .arg(annotation)
+        .arg(config.clone())
+        .arg(output.clone()),
+    )
+    .subcommand(
+      SubCommand::with_name("me-deletion")
+        .about("Call deletions of reference mobile elements")
+        .arg(alignment)
+        .arg(
+          Arg::with_name("repeat-masker")
+            .long("repeat-masker")
+            .value_name("OUT")
+            .required(true)
+            .help("RepeatMasker annotation of reference assembly"),
+        )
+        .arg(insert_size)
+        .arg(min_support)
+        .arg(
+          Arg::with_name("tolerance")
+            .long("tolerance")
+            .value_name("INT")
+            .default_value("300")
+            .help("Maximum distance between breakends & element boundaries"),
+        )
+        .arg(config.clone())
+        .arg(output.clone()),
+    )
+    .subcommand(
+      SubCommand::with_name("merge")
+        .about("Merge call sets across samples or callers")
+        .arg(
+          Arg::with_name("vcf")
+            .value_name("VCF")
+            .required(true)
+            .multiple(true)
+            .help("Call sets, in support vector order"),
+        )
+        .arg(
+          Arg::with_name("max-distance")
+            .long("max-distance")
+            .value_name("INT")
+            .default_value("1000")
+            .help("Maximum distance between matching breakends"),
+        )
+        .arg(
+          Arg::with_name("min-overlap")
+            .long("min-overlap")
+            .value_name("FLOAT")
+            .default_value("0.5")
+            .help("Minimum reciprocal overlap between spanning variants"),
+        )
+        .arg(
+          Arg::with_name("consensus")
+            .long("consensus")
+            .value_name("INT")
+            .validator(positive)
+            .help("Minimum inputs supporting merged calls [default: union]"),
+        )
+        .arg(
+          Arg::with_name("ignore-type")
+            .long("ignore-type")
+            .help("Match calls regardless of variant type"),
+        )
+        .arg(
+          Arg::with_name("ignore-family")
+            .long("ignore-family")
+            .help("Match insertions regardless of mobile element family"),
+        )
+        .arg(output),
+    )
+    .subcommand(
+      SubCommand::with_name("threshold")
+        .about("Compute read support cutoff from bin counts")
+        .arg(
+          Arg::with_name("bins")
+            .value_name("TSV")
+            .required(true)
+            .help("Bin position & read count per line, '-' for stdin"),
+        )
+        .arg(
+          Arg::with_name("chromosome-size")
+            .long("chromosome-size")
+            .value_name("INT")
+            .required(true)
+            .validator(positive)
+            .help("Chromosome size"),
+        )
+        .arg(
+          Arg::with_name("fdr")
+            .long("fdr")
+            .value_name("FLOAT")
+            .default_value("0.001")
+            .help("False discovery tolerance"),
+        )
+        .arg(
+          Arg::with_name("psize")
+            .long("psize")
+            .value_name("INT")
+            .default_value("25")
+            .help("Maximum pile up evaluated"),
+        )
+        .arg(config),
+    )
+    .subcommand(
+      SubCommand::with_name("flag")
+        .about("Explain SAM flag")
+        .arg(Arg::with_name("flag").value_name("INT").required(true)),
+    )
+    .subcommand(
+      SubCommand::with_name("cigar")
+        .about("Explain CIGAR string")
+        .arg(Arg::with_name("cigar").value_name("CIGAR").required(true))
+        .arg(
+          Arg::with_name("position")
+            .long("position")
+            .value_name("INT")
+            .default_value("1")
+            .help("Alignment position. 1-based"),
+        ),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// subcommands
+fn me_call(
+  arguments: &ArgMatches,
+  quiet: bool,
+) -> anyResult<()> {
+  let mut me_insertion_caller = MEInsertionCaller::new();
+  me_insertion_caller.config = load_config(arguments)?;
+  me_insertion_caller.level = load_level(arguments);
+
+  log(quiet, "loading mobile element library");
+  let mut library = load_library(open(arguments.value_of("library"))?)?;
+  if let Some(path) = arguments.value_of("classification") {
+    let classification = load_classification(open(Some(path))?)?;
+    let classified = classify(&mut library, &classification);
+    log(quiet, &format!("classified {} library entries", classified));
+  }
+
+  log(quiet, "calling mobile element insertions");
+  let me_insertion_calls = me_insertion_caller.call(
+    open_alignment(arguments.value_of("me-alignment"))?,
+    open_alignment(arguments.value_of("chr-alignment"))?,
+    &library,
+  )?;
+  log(quiet, &format!("{} insertions called", me_insertion_calls.len()));
+
+  let mut site_filter = SiteFilter::new();
+  site_filter.distance = parse(arguments, "filter-distance")?;
+  if let Some(path) = arguments.value_of("blacklist") {
+    site_filter.blacklist = AnnotationIndex::load_bed(open(Some(path))?)?;
+  }
+  if let Some(path) = arguments.value_of("repeat-masker") {
+    let repeat_masker = load_repeat_masker(open(Some(path))?)?;
+    site_filter.reference = AnnotationIndex::load_repeat_masker(&repeat_masker);
+  }
+  let mut me_insertion_calls = site_filter
+    .apply(me_insertion_calls, arguments.is_present("drop-filtered"));
+  if let Some(path) = arguments.value_of("annotation") {
+    log(quiet, "annotating insertions with gene features");
+    let feature_index = FeatureIndex::load(open(Some(path))?)?;
+    annotate_me_insertions(&mut me_insertion_calls, &feature_index);
+  }
+
+  let mut writer = create(arguments.value_of("output"))?;
+  write_me_vcf(&mut writer, &me_insertion_calls)?;
+  writer.flush().context(CommonError::Writing)
+}
+
+fn me_genotype(
+  arguments: &ArgMatches,
+  quiet: bool,
+) -> anyResult<()> {
+  let mut me_genotyper = MEGenotyper::new();
+  me_genotyper.allele_counter.config = load_config(arguments)?;
+  me_genotyper.merge_distance = parse(arguments, "merge-distance")?;
+  let mut me_insertion_caller = MEInsertionCaller::new();
+  me_insertion_caller.config = me_genotyper.allele_counter.config;
+  me_insertion_caller.level = load_level(arguments);
+
+  // sample name, mobile element & reference alignments
+  let samples = arguments
+    .values_of("sample")
+    .context(CommonError::Parsing)?
+    .map(|sample| match sample.split(',').collect::<Vec<&str>>()[..] {
+      [name, me_alignment, chr_alignment] => {
+        Ok((name, me_alignment, chr_alignment))
+      }
+      _ => Err(CommonError::Parsing.into()),
+    })
+    .collect::<anyResult<Vec<(&str, &str, &str)>>>()?;
+
+  log(quiet, "loading mobile element library");
+  let mut library = load_library(open(arguments.value_of("library"))?)?;
+  if let Some(path) = arguments.value_of("classification") {
+    let classification = load_classification(open(Some(path))?)?;
+    let classified = classify(&mut library, &classification);
+    log(quiet, &format!("classified {} library entries", classified));
+  }
+
+  let mut sample_calls = Vec::new();
+  for (name, me_alignment, chr_alignment) in samples.iter() {
+    log(quiet, &format!("calling mobile element insertions on {}", name));
+    sample_calls.push(me_insertion_caller.call(
+      open_alignment(Some(me_alignment))?,
+      open_alignment(Some(chr_alignment))?,
+      &library,
+    )?);
+  }
+  let mut catalog = me_genotyper.catalog(&sample_calls);
+  log(quiet, &format!("{} catalog sites", catalog.len()));
+
+  for (name, me_alignment, chr_alignment) in samples.iter() {
+    log(quiet, &format!("genotyping {}", name));
+    let sample_genotypes = me_genotyper.genotype(
+      open_alignment(Some(me_alignment))?,
+      open_alignment(Some(chr_alignment))?,
+      &library,
+      &catalog,
+    )?;
+    for (site, sample_genotype) in catalog.iter_mut().zip(sample_genotypes) {
+      site.genotypes.push(sample_genotype);
+    }
+  }
+
+  let names = samples
+    .iter()
+    .map(|(name, _, _)| name.to_string())
+    .collect::<Vec<String>>();
+  let mut writer = create(arguments.value_of("output"))?;
+  write_genotyped_me_vcf(&mut writer, &names, &catalog)?;
+  writer.flush().context(CommonError::Writing)
+}
+
+fn sv_call(
+  arguments: &ArgMatches,
+  quiet: bool,
+) -> anyResult<()> {
+  let mut sv_caller = SVCaller::new();
+  sv_caller.config = load_config(arguments)?;
+  sv_caller.expected_tlen = parse(arguments, "insert-size")?;
+  sv_caller.min_support = parse(arguments, "min-support")?;
+
+  let reference = match arguments.value_of("reference") {
+    Some(path) => {
+      log(quiet, "indexing reference assembly");
+      Some(IndexedFasta::open(path)?)
+    }
+    None => None,
+  };
+
+  log(quiet, "calling structural variants");
+  let mut sv_calls = sv_caller.call(
+    open_alignment(arguments.value_of("alignment"))?,
+    reference.as_ref(),
+  )?;
+  log(quiet, &format!("{} structural variants called", sv_calls.len()));
+  if let Some(path) = arguments.value_of("annotation") {
+    log(quiet, "annotating structural variants with gene features");
+    let feature_index = FeatureIndex::load(open(Some(path))?)?;
+    annotate_sv_calls(&mut sv_calls, &feature_index);
+  }
+
+  let mut writer = create(arguments.value_of("output"))?;
+  write_sv_vcf(&mut writer, &sv_calls)?;
+  writer.flush().context(CommonError::Writing)
+}
+
+fn me_deletion(
+  arguments: &ArgMatches,
+  quiet: bool,
+) -> anyResult<()> {
+  let mut sv_caller = SVCaller::new();
+  sv_caller.config = load_config(arguments)?;
+  sv_caller.expected_tlen = parse(arguments, "insert-size")?;
+  sv_caller.min_support = parse(arguments, "min-support")?;
+
+  log(quiet, "loading RepeatMasker annotation");
+  let repeat_masker =
+    load_repeat_masker(open(arguments.value_of("repeat-masker"))?)?;
+
+  log(quiet, "calling structural variants");
+  let sv_calls = sv_caller
+    .call(open_alignment(arguments.value_of("alignment"))?, None)?;
+  let me_deletion_calls = detect_me_deletions(
+    &sv_calls,
+    &repeat_masker,
+    parse(arguments, "tolerance")?,
+  );
+  log(
+    quiet,
+    &format!("{} mobile element deletions called", me_deletion_calls.len()),
+  );
+
+  let mut writer = create(arguments.value_of("output"))?;
+  write_me_deletion_vcf(&mut writer, &me_deletion_calls)?;
+  writer.flush().context(CommonError::Writing)
+}
+
+fn merge(
+  arguments: &ArgMatches,
+  quiet: bool,
+) -> anyResult<()> {
+  let mut call_merger = CallMerger::new();
+  call_merger.max_distance = parse(arguments, "max-distance")?;
+  call_merger.min_overlap = parse(arguments, "min-overlap")?;
+  call_merger.match_type = !arguments.is_present("ignore-type");
+  call_merger.match_family = !arguments.is_present("ignore-family");
+  if arguments.is_present("consensus") {
+    call_merger.mode = MergeModeEnum::Consensus(parse(arguments, "consensus")?);
+  }
+
+  let paths = arguments
+    .values_of("vcf")
+    .context(CommonError::Parsing)?
+    .collect::<Vec<&str>>();
+  let mut sv_inputs = Vec::new();
+  let mut me_inputs = Vec::new();
+  for path in paths.iter() {
+    log(quiet, &format!("loading {}", path));
+    // bgzipped VCF decoded as compressed text
+    let (sv_calls, me_insertion_calls) =
+      load_vcf(decode_alignment(open(Some(path))?)?)?;
+    sv_inputs.push(sv_calls);
+    me_inputs.push(me_insertion_calls);
+  }
+
+  log(
+    quiet,
+    &format!("merging {} call sets, {}", paths.len(), call_merger.mode),
+  );
+  let merged_sv_calls = call_merger.merge(&sv_inputs);
+  let merged_me_insertion_calls = call_merger.merge(&me_inputs);
+  log(
+    quiet,
+    &format!(
+      "{} merged calls",
+      merged_sv_calls.len() + merged_me_insertion_calls.len()
+    ),
+  );
+
+  let mut writer = create(arguments.value_of("output"))?;
+  write_merged_vcf(
+    &mut writer,
+    paths.len(),
+    &merged_sv_calls,
+    &merged_me_insertion_calls,
+  )?;
+  writer.flush().context(CommonError::Writing)
+}
+
+fn threshold_bins(arguments: &ArgMatches) -> anyResult<()> {
+  let config = load_config(arguments)?;
+
+  // read IDs are irrelevant to thresholding, only pile up is
+  let mut bined_hm: HashMap<Position, Vec<String>> = HashMap::new();
+  let mut pop_reads = 0.;
+  for line in open(arguments.value_of("bins"))?.lines() {
+    let line = line.context(CommonError::Reading)?;
+    if line.trim().is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let flines = line.split_whitespace().collect::<Vec<&str>>();
+    if flines.len() != 2 {
+      return Err(CommonError::Parsing.into());
+    }
+    let bin = flines[0].parse::<u64>().context(CommonError::Parsing)?;
+    let count = flines[1].parse::<usize>().context(CommonError::Parsing)?;
+    bined_hm.insert(Position::OneBased(bin), vec![String::new(); count]);
+    pop_reads += count as f64;
+  }
+
+  let cutoff = threshold(
+    pop_reads,
+    parse(arguments, "chromosome-size")?,
+    parse(arguments, "fdr")?,
+    &bined_hm,
+    parse(arguments, "psize")?,
+    &config,
+  );
+  println!("{}", cutoff);
+  Ok(())
+}
+
+fn explain_flag(arguments: &ArgMatches) -> anyResult<()> {
+  let flag = parse::<i32>(arguments, "flag")?;
+  if !(0..4096).contains(&flag) {
+    return Err(CommonError::Parsing.into());
+  }
+  println!("{}\t{:#06x}", flag, flag);
+  for (ix, description) in FLAG_DESCRIPTION.iter().enumerate() {
+    if interpret(flag, ix + 1) {
+      println!("{:#06x}\t{}", 1 << ix, description);
+    }
+  }
+  Ok(())
+}
+
+fn explain_cigar(arguments: &ArgMatches) -> anyResult<()> {
+  let cigar = CIGAR::load(
+    arguments.value_of("cigar").unwrap_or_default(),
+    Position::OneBased(parse(arguments, "position")?),
+  )?;
+  println!("signature\t{}", cigar.signature);
+  println!("align\t{:?}", cigar.align);
+  println!("deletion\t{:?}", cigar.deletion);
+  println!("insertion\t{:?}", cigar.insertion);
+  println!("left_boundry\t{}", cigar.left_boundry);
+  println!("left_clip\t{}", cigar.left_clip);
+  println!("right_boundry\t{}", cigar.right_boundry);
+  println!("right_clip\t{}", cigar.rigth_clip);
+  Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// progress logging on stderr
+fn log(
+  quiet: bool,
+  message: &str,
+) {
+  if !quiet {
+    eprintln!("{} {}", "[genomic_structures]".green(), message);
+  }
+}
+
+// detection parameters, defaults when no configuration is passed
+fn load_config(arguments: &ArgMatches) -> anyResult<DetectionConfig> {
+  match arguments.value_of("config") {
+    Some(path) => DetectionConfig::load(path),
+    None => Ok(DetectionConfig::default()),
+  }
+}
+
+// classification level, library entries when none is passed
+fn load_level(arguments: &ArgMatches) -> MELevelEnum {
+  match arguments.value_of("level") {
+    Some("class") => MELevelEnum::Class,
+    Some("family") => MELevelEnum::Family,
+    Some("subfamily") => MELevelEnum::Subfamily,
+    _ => MELevelEnum::None,
+  }
+}
+
+// parse argument value
+fn parse<T: std::str::FromStr>(
+  arguments: &ArgMatches,
+  name: &str,
+) -> anyResult<T> {
+  arguments
+    .value_of(name)
+    .and_then(|value| value.parse::<T>().ok())
+    .context(CommonError::Parsing)
+}
+
+// validate positive argument value
+fn positive(value: String) -> Result<(), String> {
+  match value.parse::<f64>() {
+    Ok(parsed) if parsed > 0. => Ok(()),
+    _ => Err(format!("expected a positive number, found '{}'", value)),
+  }
+}
+
+// validate sample alignments on files, as they are read on calling & genotyping
+fn file_sample(value: String) -> Result<(), String> {
+  if value.split(',').skip(1).any(|path| path == "-") {
+    Err(format!(
+      "alignments are read twice, stdin is not supported, found '{}'",
+      value
+    ))
+  } else {
+    Ok(())
+  }
+}
+
+// open input, '-' reads from stdin
+fn open(path: Option<&str>) -> anyResult<Box<dyn BufRead>> {
+  match path {
+    Some("-") => Ok(Box::new(BufReader::new(io::stdin()))),
+    Some(path) => {
+      let file = File::open(path).context(CommonError::Reading)?;
+      Ok(Box::new(BufReader::new(file)))
+    }
+    None => Err(CommonError::Reading.into()),
+  }
+}
+
+// open alignment, decoding BAM & compressed SAM onto SAM text
+fn open_alignment(path: Option<&str>) -> anyResult<Box<dyn BufRead>> {
+  decode_alignment(open(path)?)
+}
+
+// create output, stdout when no path is passed
+fn create(path: Option<&str>) -> anyResult<Box<dyn Write>> {
+  match path {
+    Some(path) => {
+      let file = File::create(path).context(CommonError::Writing)?;
+      Ok(Box::new(BufWriter::new(file)))
+    }
+    None => Ok(Box::new(BufWriter::new(io::stdout()))),
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
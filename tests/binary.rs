@@ -0,0 +1,250 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::io::Write;
+use std::process::{
+  Command,
+  Stdio,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  write_merged_vcf,
+  write_sv_vcf,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// usage error footer
+const USAGE: &str = "\nFor more information try --help\n";
+
+// run binary
+fn run(
+  arguments: &[&str],
+  stdin: &str,
+) -> (i32, String, String) {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_genomic_structures"))
+    .args(arguments)
+    .env("NO_COLOR", "1")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("Binary execution failed!");
+  // binary may exit before reading stdin, i.e., broken pipe
+  let _ = child
+    .stdin
+    .take()
+    .expect("Binary stdin failed!")
+    .write_all(stdin.as_bytes());
+  let output = child.wait_with_output().expect("Binary execution failed!");
+  (
+    output.status.code().unwrap_or(-1),
+    String::from_utf8_lossy(&output.stdout).to_string(),
+    String::from_utf8_lossy(&output.stderr).to_string(),
+  )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// subcommands
+macro_rules! test_binary {
+  ( $function: ident;
+    params |> $arguments: expr, $stdin: expr;
+    expect |> $code: expr, $stdout: expr, $stderr: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let (code, stdout, stderr) = run(&$arguments, $stdin);
+      assert_eq!(
+        (code, stdout.as_str(), stderr.as_str()),
+        ($code, &$stdout[..], &$stderr[..]),
+        "\n\nProduced:\n{}\n{}\n{}\n\n",
+        code,
+        stdout,
+        stderr,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_binary!(flag01;
+  params |> ["flag", "99"], "";
+  expect |> 0, [
+    "99\t0x0063\n",
+    "0x0001\tread paired\n",
+    "0x0002\tread mapped in proper pair\n",
+    "0x0020\tmate reverse strand\n",
+    "0x0040\tfirst in pair\n",
+  ].concat(), "";
+);
+
+test_binary!(cigar01;
+  params |> ["cigar", "10S90M", "--position", "101"], "";
+  expect |> 0, [
+    "signature\t10S90M\n",
+    "align\t[90]\n",
+    "deletion\t[]\n",
+    "insertion\t[]\n",
+    "left_boundry\t91\n",
+    "left_clip\t10\n",
+    "right_boundry\t190\n",
+    "right_clip\t0\n",
+  ].concat(), "";
+);
+
+test_binary!(threshold01; // lone bin & pile up
+  params |> ["threshold", "-", "--chromosome-size", "20000"],
+    "100\t4\n200\t1\n";
+  expect |> 0, "3\n", "";
+);
+
+test_binary!(threshold02; // empty bin
+  params |> ["threshold", "-", "--chromosome-size", "20000"],
+    "100\t0\n200\t3\n";
+  expect |> 0, "3\n", "";
+);
+
+test_binary!(sv_call01; // no records
+  params |> ["sv-call", "-", "-q"], "@HD\tVN:1.6\n";
+  expect |> 0, {
+    let mut header = Vec::new();
+    write_sv_vcf(&mut header, &[]).expect("VCF writing failed!");
+    String::from_utf8(header).expect("VCF decoding failed!")
+  }, "";
+);
+
+test_binary!(merge01; // lone call set, provenance tagged
+  params |> ["merge", "-", "-q"], [
+    "##fileformat=VCFv4.2\n",
+    "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    "chr1\t1001\tdel1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=1800;PE=5\n",
+  ].concat().as_str();
+  expect |> 0, {
+    let mut header = Vec::new();
+    write_merged_vcf(&mut header, 1, &[], &[]).expect("VCF writing failed!");
+    let mut vcf = String::from_utf8(header).expect("VCF decoding failed!");
+    vcf.push_str(&[
+      "chr1\t1001\tSV1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;SVLEN=-800;",
+      "END=1800;IMPRECISE;PE=5;SR=0;SUPP=1;SUPP_VEC=1\n",
+    ].concat());
+    vcf
+  }, "";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fail
+test_binary!(fail01; // unknown subcommand
+  params |> ["bogus"], "";
+  expect |> 2, "", [
+    "error: Found argument 'bogus' which wasn't expected, ",
+    "or isn't valid in this context\n\n",
+    "USAGE:\n    genomic_structures [FLAGS] <SUBCOMMAND>\n",
+    USAGE,
+  ].concat();
+);
+
+test_binary!(fail02; // missing file
+  params |> ["sv-call", "/nonexistent.sam", "-q"], "";
+  expect |> 1, "",
+    "error: \nFail to read\n: No such file or directory (os error 2)\n";
+);
+
+test_binary!(fail03; // flag out of range
+  params |> ["flag", "4096"], "";
+  expect |> 1, "", "error: \nFail to parse\n\n";
+);
+
+test_binary!(fail04; // empty chromosome
+  params |> ["threshold", "-", "--chromosome-size", "0"], "100\t4\n";
+  expect |> 2, "", [
+    "error: Invalid value for '--chromosome-size <INT>': ",
+    "expected a positive number, found '0'\n",
+  ].concat();
+);
+
+test_binary!(fail05; // missing reference annotation
+  params |> ["me-deletion", "-", "-q"], "";
+  expect |> 2, "", [
+    "error: The following required arguments were not provided:\n",
+    "    --repeat-masker <OUT>\n\n",
+    "USAGE:\n    genomic_structures me-deletion <SAM/BAM> ",
+    "--insert-size <INT> --min-support <INT> --quiet ",
+    "--repeat-masker <OUT> --tolerance <INT>\n",
+    USAGE,
+  ].concat();
+);
+
+test_binary!(fail06; // annotation missing from disk
+  params |> [
+    "me-deletion",
+    "-",
+    "--repeat-masker",
+    "/nonexistent.out",
+    "-q",
+  ], "@HD\tVN:1.6\n";
+  expect |> 1, "",
+    "error: \nFail to read\n: No such file or directory (os error 2)\n";
+);
+
+test_binary!(fail07; // gene annotation missing from disk
+  params |> ["sv-call", "-", "--annotation", "/nonexistent.gtf", "-q"],
+    "@HD\tVN:1.6\n";
+  expect |> 1, "",
+    "error: \nFail to read\n: No such file or directory (os error 2)\n";
+);
+
+test_binary!(fail08; // missing samples
+  params |> ["me-genotype", "--library", "library.fa", "-q"], "";
+  expect |> 2, "", [
+    "error: The following required arguments were not provided:\n",
+    "    --sample <NAME,ME_SAM,CHR_SAM>...\n\n",
+    "USAGE:\n    genomic_structures me-genotype --library <FASTA> ",
+    "--merge-distance <INT> --quiet --sample <NAME,ME_SAM,CHR_SAM>...\n",
+    USAGE,
+  ].concat();
+);
+
+test_binary!(fail09; // malformed sample
+  params |> [
+    "me-genotype",
+    "--sample",
+    "NA12878,me.sam",
+    "--library",
+    "/nonexistent.fa",
+    "-q",
+  ], "";
+  expect |> 1, "", "error: \nFail to parse\n\n";
+);
+
+test_binary!(fail10; // sample alignments on stdin
+  params |> [
+    "me-genotype",
+    "--sample",
+    "NA12878,-,chr.sam",
+    "--library",
+    "library.fa",
+    "-q",
+  ], "";
+  expect |> 2, "", [
+    "error: Invalid value for '--sample <NAME,ME_SAM,CHR_SAM>...': ",
+    "alignments are read twice, stdin is not supported, ",
+    "found 'NA12878,-,chr.sam'\n",
+  ].concat();
+);
+
+test_binary!(fail11; // empty consensus
+  params |> ["merge", "-", "--consensus", "0"], "";
+  expect |> 2, "", [
+    "error: Invalid value for '--consensus <INT>': ",
+    "expected a positive number, found '0'\n",
+  ].concat();
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -2,9 +2,18 @@
 
 // modules
 // mod counter;
+mod break_point_refiner;
+mod complex_resolver;
 mod flag_interpretor;
-// mod identificator;
+mod identificator;
+mod junction_analyzer;
+mod me_classifier;
+mod me_deletion_detector;
+mod me_summarizer;
 mod sequences;
-// mod thresholder;
+mod tail_detector;
+mod thresholder;
+mod tsd_detector;
+mod vcf_loader;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
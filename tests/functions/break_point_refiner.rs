@@ -0,0 +1,117 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  refine_break_point,
+  ChrAnchor,
+  ClipSideEnum,
+  MEChimericRead,
+  Position,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// refine break point
+macro_rules! test_refine_break_point {
+  ( $function: ident;
+    params |> $side: expr, $window: expr, $min_clip: expr;
+    expect |> $position: expr, $ci: expr, $split_reads: expr;
+    vargs ... $($chr: expr, $cigar: expr, $pos: expr);+;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_chimeric_reads = vec![];
+      // variadic loading
+      $(
+        let mut me_chimeric_read = MEChimericRead::new();
+        let position = Position::OneBased($pos);
+        me_chimeric_read.chr_read.push(ChrAnchor::load(
+          CIGAR::load($cigar, position).expect("CIGAR loading failed!"),
+          $chr.to_string(),
+          0,
+          60,
+          position,
+          0,
+        ));
+        me_chimeric_reads.push(me_chimeric_read);
+      )+
+
+      let refined = refine_break_point(&me_chimeric_reads, $side, $window, $min_clip)
+        .expect("No split reads found!");
+      let ci = $ci;
+      assert_eq!(
+        (refined.position, (refined.ci_start, refined.ci_end), refined.split_reads),
+        (
+          Position::OneBased($position),
+          (Position::OneBased(ci.0), Position::OneBased(ci.1)),
+          $split_reads,
+        ),
+        "\n\nRefined break point:\n{:#?}.\n\n",
+        refined,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_refine_break_point!(left01;
+  params |> ClipSideEnum::Left, 10, 5;
+  expect |> 1001, (1001, 1001), 2;
+  vargs ... "chr1", "30S70M", 1001; "chr1", "40S60M", 1001;
+);
+
+test_refine_break_point!(left02; // short clips ignored
+  params |> ClipSideEnum::Left, 10, 5;
+  expect |> 1005, (1005, 1005), 2;
+  vargs ... "chr1", "30S70M", 1005; "chr1", "2S98M", 1001; "chr1", "40S60M", 1005; "chr1", "3S97M", 1001;
+);
+
+test_refine_break_point!(left03; // window
+  params |> ClipSideEnum::Left, 5, 5;
+  expect |> 1001, (999, 1004), 4;
+  vargs ... "chr1", "30S70M", 1001; "chr1", "30S70M", 1001; "chr1", "30S70M", 999; "chr1", "30S70M", 1004; "chr1", "30S70M", 1020;
+);
+
+test_refine_break_point!(right01; // junction after alignment
+  params |> ClipSideEnum::Right, 10, 5;
+  expect |> 1071, (1071, 1072), 3;
+  vargs ... "chr1", "70M30S", 1001; "chr1", "70M30S", 1001; "chr1", "71M29S", 1001;
+);
+
+test_refine_break_point!(right02; // other side ignored
+  params |> ClipSideEnum::Right, 10, 5;
+  expect |> 1071, (1071, 1071), 1;
+  vargs ... "chr1", "70M30S", 1001; "chr1", "30S70M", 1001; "chr1", "30S70M", 1001;
+);
+
+test_refine_break_point!(chr01; // chromosome
+  params |> ClipSideEnum::Left, 10, 5;
+  expect |> 1001, (1001, 1001), 2;
+  vargs ... "chr1", "30S70M", 1001; "chr1", "30S70M", 1001; "chr2", "30S70M", 1002;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// no split reads
+#[test]
+fn none01() {
+  let mut me_chimeric_read = MEChimericRead::new();
+  let position = Position::OneBased(1001);
+  me_chimeric_read.chr_read.push(ChrAnchor::load(
+    CIGAR::load("100M", position).expect("CIGAR loading failed!"),
+    "chr1".to_string(),
+    0,
+    60,
+    position,
+    0,
+  ));
+  assert_eq!(
+    refine_break_point(&[me_chimeric_read], ClipSideEnum::None, 10, 5),
+    None
+  );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,195 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  resolve_complex,
+  ClipSideEnum,
+  DepthProfile,
+  Position,
+  SVCall,
+  SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// breakend cluster, orientation as junction side initials
+fn cluster(
+  (svtype, chr2, position, end, orientation): (SVType, &str, u64, u64, &str),
+) -> SVCall {
+  let side = |initial: char| match initial {
+    'L' => ClipSideEnum::Left,
+    'R' => ClipSideEnum::Right,
+    _ => ClipSideEnum::None,
+  };
+  let initials = orientation.chars().collect::<Vec<char>>();
+  let mut sv_call = SVCall::new(svtype);
+  sv_call.chr = "chr1".to_string();
+  sv_call.chr2 = chr2.to_string();
+  sv_call.position = Position::OneBased(position);
+  sv_call.end = Position::OneBased(end);
+  sv_call.support = 3;
+  sv_call.orientation = (side(initials[0]), side(initials[1]));
+  sv_call
+}
+
+// diploid read depth, with altered copies over segment on chr1
+fn depth_profile((start, end, copies): (u64, u64, usize)) -> DepthProfile {
+  let mut depth_profile = DepthProfile::new(100);
+  for chr in ["chr1", "chr2"].iter() {
+    for position in (1..10000).step_by(50) {
+      let within = *chr == "chr1" && position >= start && position + 99 <= end;
+      for _ in 0..if within { copies } else { 2 } {
+        let position = Position::OneBased(position);
+        depth_profile.add(chr, position, position + 99);
+      }
+    }
+  }
+  depth_profile
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// resolve
+macro_rules! test_resolve {
+  ( $function: ident;
+    params |> $clusters: expr, $segment: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let sv_calls = $clusters.iter().cloned().map(cluster).collect();
+      let produced = resolve_complex(
+        sv_calls,
+        &depth_profile($segment),
+        500,
+        1000,
+      )
+      .iter()
+      .map(|sv_call| {
+        (
+          sv_call.svtype,
+          sv_call.position.one_based(),
+          sv_call.end.one_based(),
+          sv_call.components.len(),
+        )
+      })
+      .collect::<Vec<_>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_resolve!(resolve01; // reciprocal translocation
+  params |> [
+    (SVType::Translocation, "chr2", 5000, 8001, "RL"),
+    (SVType::Translocation, "chr2", 5001, 8000, "LR"),
+  ], (0, 0, 2);
+  expect |> vec![(SVType::BalancedTranslocation, 5000, 8001, 2)];
+);
+
+test_resolve!(resolve02; // translocation without reciprocal junction
+  params |> [
+    (SVType::Translocation, "chr2", 5000, 8001, "RL"),
+    (SVType::Translocation, "chr2", 5001, 8000, "RL"),
+  ], (0, 0, 2);
+  expect |> vec![
+    (SVType::Translocation, 5000, 8001, 0),
+    (SVType::Translocation, 5001, 8000, 0),
+  ];
+);
+
+test_resolve!(resolve03; // inversion junctions on both sides
+  params |> [
+    (SVType::Inversion, "chr1", 3000, 6000, "RR"),
+    (SVType::Inversion, "chr1", 3001, 6001, "LL"),
+  ], (0, 0, 2);
+  expect |> vec![(SVType::Inversion, 3000, 6001, 2)];
+);
+
+test_resolve!(resolve04; // inversion flanked by deletion
+  params |> [
+    (SVType::Inversion, "chr1", 3000, 6000, "RR"),
+    (SVType::Inversion, "chr1", 4001, 6001, "LL"),
+  ], (3001, 4000, 1);
+  expect |> vec![(SVType::DeletionFlankedInversion, 3000, 6001, 2)];
+);
+
+test_resolve!(resolve05; // inverted duplication
+  params |> [
+    (SVType::Inversion, "chr1", 3000, 6000, "RR"),
+    (SVType::Inversion, "chr1", 3001, 5001, "LL"),
+  ], (5001, 6000, 3);
+  expect |> vec![(SVType::InvertedDuplication, 5001, 6000, 2)];
+);
+
+test_resolve!(resolve06; // inversion junctions without copy number change
+  params |> [
+    (SVType::Inversion, "chr1", 3000, 6000, "RR"),
+    (SVType::Inversion, "chr1", 3001, 5001, "LL"),
+  ], (0, 0, 2);
+  expect |> vec![
+    (SVType::Inversion, 3000, 6000, 0),
+    (SVType::Inversion, 3001, 5001, 0),
+  ];
+);
+
+test_resolve!(resolve07; // dispersed duplication
+  params |> [
+    (SVType::Deletion, "chr1", 3000, 5001, "RL"),
+    (SVType::Duplication, "chr1", 3001, 6000, "LR"),
+  ], (5001, 6000, 3);
+  expect |> vec![(SVType::DispersedDuplication, 5001, 6000, 2)];
+);
+
+test_resolve!(resolve08; // source segment deleted
+  params |> [
+    (SVType::Deletion, "chr1", 3000, 5001, "RL"),
+    (SVType::Duplication, "chr1", 3001, 6000, "LR"),
+  ], (5001, 6000, 1);
+  expect |> vec![
+    (SVType::Deletion, 3000, 5001, 0),
+    (SVType::Duplication, 3001, 6000, 0),
+  ];
+);
+
+test_resolve!(resolve09; // clusters apart
+  params |> [
+    (SVType::Deletion, "chr1", 3000, 5001, "RL"),
+    (SVType::Duplication, "chr1", 4001, 7000, "LR"),
+  ], (0, 0, 2);
+  expect |> vec![
+    (SVType::Deletion, 3000, 5001, 0),
+    (SVType::Duplication, 4001, 7000, 0),
+  ];
+);
+
+////////////////////////////////////////////////////////////////////////////////////
+
+test_resolve!(resolve10; // lone translocation, segment past breakend lost
+  params |> [(SVType::Translocation, "chr2", 5000, 8001, "RL")],
+    (5001, 9999, 1);
+  expect |> vec![(SVType::UnbalancedTranslocation, 5000, 8001, 0)];
+);
+
+test_resolve!(resolve11; // lone translocation, retained segment lost
+  params |> [(SVType::Translocation, "chr2", 5000, 8001, "LR")],
+    (5001, 9999, 1);
+  expect |> vec![(SVType::Translocation, 5000, 8001, 0)];
+);
+
+test_resolve!(resolve12; // shared end beyond position scan
+  params |> [
+    (SVType::Inversion, "chr1", 3000, 6000, "RR"),
+    (SVType::Deletion, "chr1", 3600, 3900, "RL"),
+    (SVType::Inversion, "chr1", 4001, 6001, "LL"),
+  ], (3001, 4000, 1);
+  expect |> vec![
+    (SVType::DeletionFlankedInversion, 3000, 6001, 2),
+    (SVType::Deletion, 3600, 3900, 0),
+  ];
+);
+
+////////////////
@@ -210,4 +210,14 @@ interpret!(supplementary02;
   expect |> true;
 );
 
+interpret!(out_of_range00;
+  params |> 4097, 1;
+  expect |> false;
+);
+
+interpret!(out_of_range01;
+  params |> -5, 3;
+  expect |> false;
+);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,8 +1,111 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use genomic_structures::identificator;
+use genomic_structures::{
+  identify_split,
+  ChrAnchor,
+  ClipSideEnum,
+  DetectionConfig,
+  Position,
+  SVChimericRead,
+  SVType,
+  CIGAR,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// junction side at breakend & mate breakend
+type Orientation = (ClipSideEnum, ClipSideEnum);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// split read identification
+macro_rules! test_identify_split {
+  ( $function: ident;
+    params |> $alignments: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut sv_chimeric_read = SVChimericRead::new();
+      let alignments: Vec<(&str, i32, u64, &str)> = $alignments.to_vec();
+      for (chr, flag, position, cigar) in alignments.into_iter() {
+        let position = Position::OneBased(position);
+        sv_chimeric_read.chr_read.push(ChrAnchor::load(
+          CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
+          chr.to_string(),
+          flag,
+          60,
+          position,
+          0,
+        ));
+      }
+
+      let config = DetectionConfig::default();
+      let produced = identify_split(&sv_chimeric_read, &config)
+        .into_iter()
+        .map(|sv_call| {
+          (
+            sv_call.svtype,
+            sv_call.chr,
+            sv_call.position,
+            sv_call.chr2,
+            sv_call.end,
+            sv_call.orientation,
+          )
+        })
+        .collect::<Vec<_>>();
+      let expect: Vec<(SVType, &str, u64, &str, u64, Orientation)> = $expect;
+      let expect = expect
+        .into_iter()
+        .map(|(svtype, chr, position, chr2, end, orientation)| {
+          let (chr, chr2) = (chr.to_string(), chr2.to_string());
+          let (position, end) =
+            (Position::OneBased(position), Position::OneBased(end));
+          (svtype, chr, position, chr2, end, orientation)
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_identify_split!(split01; // deletion
+  params |> [("chr1", 0, 1041, "60M40S"), ("chr1", 2048, 1801, "60H40M")];
+  expect |> vec![(SVType::Deletion, "chr1", 1101, "chr1", 1800, (ClipSideEnum::Right, ClipSideEnum::Left))];
+);
+
+test_identify_split!(split02; // deletion, primary closes read
+  params |> [("chr1", 0, 1801, "60S40M"), ("chr1", 2048, 1041, "60M40H")];
+  expect |> vec![(SVType::Deletion, "chr1", 1101, "chr1", 1800, (ClipSideEnum::Right, ClipSideEnum::Left))];
+);
+
+test_identify_split!(split03; // deletion on reverse strand
+  params |> [("chr1", 16, 1041, "60M40S"), ("chr1", 2064, 1801, "60H40M")];
+  expect |> vec![(SVType::Deletion, "chr1", 1101, "chr1", 1800, (ClipSideEnum::Right, ClipSideEnum::Left))];
+);
+
+test_identify_split!(split04; // tandem duplication
+  params |> [("chr1", 0, 1501, "60M40S"), ("chr1", 2048, 1201, "60H40M")];
+  expect |> vec![(SVType::Duplication, "chr1", 1201, "chr1", 1560, (ClipSideEnum::Left, ClipSideEnum::Right))];
+);
+
+test_identify_split!(split05; // inversion
+  params |> [("chr1", 0, 1001, "60M40S"), ("chr1", 2064, 1500, "40M60H")];
+  expect |> vec![(SVType::Inversion, "chr1", 1061, "chr1", 1539, (ClipSideEnum::Right, ClipSideEnum::Right))];
+);
+
+test_identify_split!(split06; // translocation
+  params |> [("chr1", 0, 5001, "60M40S"), ("chr2", 2048, 8001, "60H40M")];
+  expect |> vec![(SVType::Translocation, "chr1", 5060, "chr2", 8001, (ClipSideEnum::Right, ClipSideEnum::Left))];
+);
+
+test_identify_split!(split07; // secondary alignments are ignored
+  params |> [("chr1", 0, 1041, "60M40S"), ("chr1", 256, 1801, "60S40M")];
+  expect |> vec![];
+);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
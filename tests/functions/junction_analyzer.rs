@@ -0,0 +1,182 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  characterize_junction,
+  BreakPoint,
+  ChrAnchor,
+  IndexedFasta,
+  Position,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// aligned bases on each side of junction
+const ALIGNED: i32 = 40;
+
+// pseudo random bases, with microhomology planted at junction
+fn bases(
+  left: i32,
+  right: i32,
+  homology: i32,
+) -> Vec<u8> {
+  let mut seed: u64 = 42;
+  let mut bases = (0..2000)
+    .map(|_| {
+      seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+      b"ACGT"[(seed >> 62) as usize]
+    })
+    .collect::<Vec<u8>>();
+
+  // 0-based offsets of 1-based positions
+  let ix = |position: i32| (position - 1) as usize;
+  for shift in 0..homology {
+    bases[ix(right - 1 - shift)] = bases[ix(left - shift)];
+  }
+  // homology boundaries must differ
+  let complement = |base: u8| if base == b'A' { b'C' } else { b'A' };
+  if bases[ix(left - homology)] == bases[ix(right - 1 - homology)] {
+    bases[ix(right - 1 - homology)] = complement(bases[ix(left - homology)]);
+  }
+  if bases[ix(left + 1)] == bases[ix(right)] {
+    bases[ix(right)] = complement(bases[ix(left + 1)]);
+  }
+  bases
+}
+
+// write reference on temporary directory
+fn reference(
+  name: &str,
+  bases: &[u8],
+) -> IndexedFasta {
+  let directory =
+    std::env::temp_dir().join("genomic_structures_junction_analyzer");
+  fs::create_dir_all(&directory).expect("Directory creation failed!");
+  let path = directory.join(name);
+  fs::remove_file(format!("{}.fai", path.display())).ok();
+  let mut fasta = b">chr1\n".to_vec();
+  fasta.extend(bases);
+  fasta.push(b'\n');
+  fs::write(&path, fasta).expect("FASTA writing failed!");
+  IndexedFasta::open(&path).expect("FASTA indexing failed!")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// characterize
+macro_rules! test_junction {
+  ( $function: ident;
+    params |> $clip: expr, $left: expr, $right: expr, $homology: expr,
+      $insertion: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let bases = bases($left, $right, $homology);
+      let reference = reference(stringify!($function), &bases);
+
+      // read joins bases upstream & downstream from planted junction
+      let segment = |start: i32, end: i32| {
+        String::from_utf8(bases[(start - 1) as usize..end as usize].to_vec())
+          .expect("Sequence decoding failed!")
+      };
+      let upstream = segment($left - ALIGNED + 1, $left);
+      let downstream = segment($right, $right + ALIGNED - 1);
+      let read = format!("{}{}{}", upstream, $insertion, downstream);
+      let clip = ALIGNED + $insertion.len() as i32;
+
+      let mut anchor = ChrAnchor::new();
+      anchor.chr = "chr1".to_string();
+      let position = |position: i32| Position::OneBased(position as u64);
+      let (signature, offset, window) = if $clip == "right" {
+        anchor.position = position($left - ALIGNED + 1);
+        (
+          format!("{}M{}S", ALIGNED, clip),
+          clip,
+          (position($right - 100), position($right + 100)),
+        )
+      } else {
+        anchor.position = position($right);
+        (
+          format!("{}S{}M", clip, ALIGNED),
+          -clip,
+          (position($left - 100), position($left + 100)),
+        )
+      };
+      anchor.cigar = CIGAR::load(&signature, anchor.position)
+        .expect("CIGAR loading failed!");
+      let breakpoint = BreakPoint::load(&read, offset as i64, 10)
+        .expect("BreakPoint failed!");
+
+      let sv_junction = characterize_junction(
+        &reference,
+        &anchor,
+        &breakpoint,
+        "chr1",
+        window,
+        20,
+      )
+      .expect("Junction characterization failed!")
+      .map(|sv_junction| {
+        (
+          sv_junction.left.one_based() as i32,
+          sv_junction.right.one_based() as i32,
+          sv_junction.homology,
+          sv_junction.insertion,
+        )
+      });
+
+      let expect: Option<(i32, i32, i32, &str)> = $expect;
+      let expect = expect.map(|(left, right, homology, insertion)| {
+        (
+          left,
+          right,
+          segment(left + 1, left + homology),
+          insertion.to_string(),
+        )
+      });
+      assert_eq!(sv_junction, expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_junction!(junction01; // blunt junction on downstream clip
+  params |> "right", 500, 1200, 0, "";
+  expect |> Some((500, 1200, 0, ""));
+);
+
+test_junction!(junction02; // microhomology on downstream clip
+  params |> "right", 500, 1200, 4, "";
+  expect |> Some((496, 1196, 4, ""));
+);
+
+test_junction!(junction03; // microhomology on upstream clip
+  params |> "left", 500, 1200, 3, "";
+  expect |> Some((497, 1197, 3, ""));
+);
+
+test_junction!(junction04; // non-templated insertion on downstream clip
+  params |> "right", 500, 1200, 0, "TTTTTT";
+  expect |> Some((500, 1200, 0, "TTTTTT"));
+);
+
+test_junction!(junction05; // non-templated insertion on upstream clip
+  params |> "left", 500, 1200, 0, "GGG";
+  expect |> Some((500, 1200, 0, "GGG"));
+);
+
+test_junction!(junction06; // insertion longer than allowed
+  params |> "right", 500, 1200, 0, "ACACACACACACACACACACACACA";
+  expect |> None;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
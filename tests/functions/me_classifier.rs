@@ -0,0 +1,95 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  aggregate_mobel,
+  load_classification,
+  MEAnchor,
+  MELevelEnum,
+  MELibrary,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// aggregate mobile element anchors
+macro_rules! test_aggregate_mobel {
+  ( $function: ident;
+    params |> $table: expr, $level: expr;
+    expect |> $($key: expr => $count: expr),+;
+    vargs ... $($mobel: expr),+;
+  ) => {
+    #[test]
+    fn $function() {
+      // load library
+      let classification = load_classification($table.as_bytes())
+        .expect("Classification loading failed!");
+      let mut library = std::collections::HashMap::new();
+      for (mobel, me_classification) in classification.iter() {
+        let mut me_library = MELibrary::new();
+        me_library.classification = me_classification.clone();
+        library.insert(mobel.clone(), me_library);
+      }
+
+      // variadic loading
+      let mut me_anchors = vec![];
+      $(
+        let mut me_anchor = MEAnchor::new();
+        me_anchor.mobel = $mobel.to_string();
+        me_anchors.push(me_anchor);
+      )+
+
+      let aggregated = aggregate_mobel(&me_anchors, &library, $level);
+      $(
+        assert_eq!(
+          aggregated.get($key),
+          Some(&$count),
+          "\n\nAggregated:\n{:#?}.\n\nKey: {:?}.\nExpected: {:?}.\n\n",
+          aggregated,
+          $key,
+          $count,
+        );
+      )+
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_aggregate_mobel!(class01;
+  params |> "mobel1\tL1HS\tLINE/L1\nmobel2\tAluY\tSINE/Alu\nmobel3\tL1PA2\tLINE/L1\n", MELevelEnum::Class;
+  expect |> "LINE" => 3, "SINE" => 1;
+  vargs ... "mobel1", "mobel3", "mobel1", "mobel2";
+);
+
+test_aggregate_mobel!(family01;
+  params |> "mobel1\tL1HS\tLINE/L1\nmobel2\tAluY\tSINE/Alu\nmobel3\tL1PA2\tLINE/L1\n", MELevelEnum::Family;
+  expect |> "L1" => 2, "Alu" => 1;
+  vargs ... "mobel1", "mobel3", "mobel2";
+);
+
+test_aggregate_mobel!(family02; // Dfam hierarchy
+  params |> "mobel1\tL1HS\troot;Interspersed_Repeat;Transposable_Element;LINE;L1\nmobel2\tHERVK11\troot;Interspersed_Repeat;Transposable_Element;Class_I_Retrotransposition;LTR;ERVK\n", MELevelEnum::Family;
+  expect |> "L1" => 2, "ERVK" => 1;
+  vargs ... "mobel1", "mobel1", "mobel2";
+);
+
+test_aggregate_mobel!(subfamily01;
+  params |> "mobel1\tL1HS\tLINE/L1\nmobel2\tAluY\tSINE/Alu\nmobel3\tL1PA2\tLINE/L1\n", MELevelEnum::Subfamily;
+  expect |> "L1HS" => 2, "L1PA2" => 1;
+  vargs ... "mobel1", "mobel3", "mobel1";
+);
+
+test_aggregate_mobel!(none01; // unclassified fall back to library name
+  params |> "mobel1\tL1HS\tLINE/L1\n", MELevelEnum::Family;
+  expect |> "L1" => 1, "mobel9" => 2;
+  vargs ... "mobel1", "mobel9", "mobel9";
+);
+
+test_aggregate_mobel!(none02;
+  params |> "mobel1\tL1HS\tLINE/L1\n", MELevelEnum::None;
+  expect |> "mobel1" => 1;
+  vargs ... "mobel1";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
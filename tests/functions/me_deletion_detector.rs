@@ -0,0 +1,112 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  detect_me_deletions,
+  load_repeat_masker,
+  ClipSideEnum,
+  Position,
+  SVCall,
+  SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// reference annotation, including non mobile element repeat
+const REPEAT_MASKER: &str = "
+  301  12.0  1.0  0.0  chr1   9001  9300 (0) C AluY     SINE/Alu      (11)  300    1  1
+ 5000   3.0  0.5  0.5  chr1  20001 26000 (0) + L1HS     LINE/L1          1 6000 (155) 2
+  250   0.0  0.0  0.0  chr1  30001 30300 (0) + (CA)n    Simple_repeat    1  300   (0) 3
+  310  10.0  0.0  0.0  chr1  40001 40290 (0) + AluSx    SINE/Alu         1  290  (22) 4
+  280  14.0  0.0  0.0  chr1  40251 40560 (0) + AluJb    SINE/Alu         1  310   (2) 5
+";
+
+// deletion or deletion join between breakends
+fn sv_call(
+  (svtype, chr2, position, end): (SVType, &str, u64, u64),
+) -> SVCall {
+  let mut sv_call = SVCall::new(svtype);
+  sv_call.chr = "chr1".to_string();
+  sv_call.chr2 = chr2.to_string();
+  sv_call.position = Position::OneBased(position);
+  sv_call.end = Position::OneBased(end);
+  sv_call.support = 3;
+  sv_call.orientation = (ClipSideEnum::Right, ClipSideEnum::Left);
+  sv_call
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// detect
+macro_rules! test_detect {
+  ( $function: ident;
+    params |> $sv_calls: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let repeat_masker = load_repeat_masker(REPEAT_MASKER.as_bytes())
+        .expect("RepeatMasker loading failed!");
+      let sv_calls = $sv_calls.iter().cloned().map(sv_call).collect::<Vec<_>>();
+      let produced = detect_me_deletions(&sv_calls, &repeat_masker, 100)
+        .iter()
+        .map(|me_deletion_call| {
+          (
+            me_deletion_call.position.one_based(),
+            me_deletion_call.end.one_based(),
+            me_deletion_call.classification.subfamily.clone(),
+            me_deletion_call.deleted_length,
+          )
+        })
+        .collect::<Vec<_>>();
+      let expect: Vec<(u64, u64, &str, u64)> = $expect;
+      let expect = expect
+        .into_iter()
+        .map(|(position, end, subfamily, deleted_length)| {
+          (position, end, subfamily.to_string(), deleted_length)
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_detect!(detect01; // deletion over annotated element
+  params |> [(SVType::Deletion, "chr1", 8950, 9320)];
+  expect |> vec![(9001, 9300, "AluY", 371)];
+);
+
+test_detect!(detect02; // long deletion labeled as translocation
+  params |> [(SVType::UnbalancedTranslocation, "chr1", 19980, 26050)];
+  expect |> vec![(20001, 26000, "L1HS", 6071)];
+);
+
+test_detect!(detect03; // deletion beyond element boundaries
+  params |> [(SVType::Deletion, "chr1", 8700, 9300)];
+  expect |> vec![];
+);
+
+test_detect!(detect04; // deletion over non mobile element repeat
+  params |> [(SVType::Deletion, "chr1", 30001, 30300)];
+  expect |> vec![];
+);
+
+test_detect!(detect05; // duplication over annotated element
+  params |> [(SVType::Duplication, "chr1", 9001, 9300)];
+  expect |> vec![];
+);
+
+test_detect!(detect06; // join between chromosomes
+  params |> [(SVType::UnbalancedTranslocation, "chr2", 19980, 26050)];
+  expect |> vec![];
+);
+
+test_detect!(detect07; // closest among overlapping elements
+  params |> [(SVType::Deletion, "chr1", 40240, 40550)];
+  expect |> vec![(40251, 40560, "AluJb", 311)];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
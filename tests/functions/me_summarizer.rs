@@ -0,0 +1,194 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  summarize_me,
+  ChrAnchor,
+  ChrAnchorEnum,
+  MEAnchor,
+  MEChimericPair,
+  MEClassification,
+  MELevelEnum,
+  MELibrary,
+  Position,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// classified mobile element library
+fn library() -> HashMap<String, MELibrary> {
+  let mut library = HashMap::new();
+  for (subfamily, hierarchy) in [
+    ("L1HS", "LINE/L1"),
+    ("L1PA2", "LINE/L1"),
+    ("L1PA3", "LINE/L1"),
+    ("AluY", "SINE/Alu"),
+  ]
+  .iter()
+  {
+    let mut me_library = MELibrary::new();
+    me_library.classification = MEClassification::load(subfamily, hierarchy);
+    library.insert(subfamily.to_string(), me_library);
+  }
+  library
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// summarize mobile element insertion
+macro_rules! test_summarize_me {
+  ( $function: ident;
+    params |> $anchors: expr;
+    expect |> $expect: expr;
+  ) => {
+    test_summarize_me!($function;
+      params |> $anchors, MELevelEnum::None;
+      expect |> $expect;
+    );
+  };
+  ( $function: ident;
+    params |> $anchors: expr, $level: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let me_chimeric_pairs = $anchors
+        .iter()
+        .map(|(mobel, cigar, position, me_flag, chr_flag, mate_flag)| {
+          let position = Position::OneBased(*position as u64);
+          let mut me_chimeric_pair = MEChimericPair::load(MEAnchor::load(
+            CIGAR::load(cigar, position).expect("CIGAR loading failed!"),
+            *me_flag,
+            mobel.to_string(),
+            Default::default(),
+            position,
+            6000.,
+          ));
+          // chromosomal alignment on read
+          if *chr_flag > 0 {
+            let mut chr_anchor = ChrAnchor::new();
+            chr_anchor.flag = *chr_flag;
+            me_chimeric_pair.read1.chr_read.push(chr_anchor);
+          }
+          // chromosomal alignment on resolved mate
+          if *mate_flag > 0 {
+            let mut chr_anchor = ChrAnchor::new();
+            chr_anchor.flag = *mate_flag;
+            me_chimeric_pair.read2.chr_read.push(chr_anchor);
+            me_chimeric_pair.chranch = ChrAnchorEnum::Read2;
+          }
+          me_chimeric_pair
+        })
+        .collect::<Vec<MEChimericPair>>();
+      let me_info = summarize_me(&me_chimeric_pairs, &library(), $level)
+        .map(|me_info| {
+          (me_info.to_string(), me_info.length, me_info.truncation)
+        });
+      let expect: Option<(&str, u64, u64)> = $expect;
+      let expect = expect.map(|(meinfo, length, truncation)| {
+        (meinfo.to_string(), length, truncation)
+      });
+      assert_eq!(
+        me_info, expect,
+        "\n\nAnchors: {:?}.\nSummary: {:?}.\nExpected: {:?}.\n\n",
+        $anchors, me_info, expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_summarize_me!(forward01; // full length
+  params |> [
+    ("L1HS", "50S50M", 1, 65, 129, 0),
+    ("L1HS", "70M30S", 5931, 65, 129, 0),
+  ];
+  expect |> Some(("L1HS,1,6000,+", 6000, 0));
+);
+
+test_summarize_me!(forward02; // 5' truncated
+  params |> [
+    ("L1HS", "50S50M", 5001, 65, 129, 0),
+    ("L1HS", "70M30S", 5931, 65, 129, 0),
+  ];
+  expect |> Some(("L1HS,5001,6000,+", 1000, 5000));
+);
+
+test_summarize_me!(reverse01;
+  params |> [
+    ("L1HS", "100M", 4001, 81, 129, 0),
+    ("L1HS", "70M30S", 5931, 65, 145, 0),
+  ];
+  expect |> Some(("L1HS,4001,6000,-", 2000, 4000));
+);
+
+test_summarize_me!(mobel01; // minority library entry excluded
+  params |> [
+    ("L1HS", "100M", 4001, 65, 129, 0),
+    ("L1HS", "70M30S", 5931, 65, 129, 0),
+    ("AluY", "100M", 1, 65, 145, 0),
+  ];
+  expect |> Some(("L1HS,4001,6000,+", 2000, 4000));
+);
+
+test_summarize_me!(family01; // sibling subfamilies merged at family level
+  params |> [
+    ("L1HS", "100M", 4001, 65, 129, 0),
+    ("L1PA2", "70M30S", 5931, 65, 129, 0),
+    ("L1PA3", "100M", 3001, 65, 129, 0),
+    ("AluY", "100M", 1, 65, 145, 0),
+    ("AluY", "100M", 101, 65, 145, 0),
+  ], MELevelEnum::Family;
+  expect |> Some(("L1HS,3001,6000,+", 3000, 3000));
+);
+
+test_summarize_me!(family02; // subfamilies apart on library entries
+  params |> [
+    ("L1HS", "100M", 4001, 65, 129, 0),
+    ("L1PA2", "70M30S", 5931, 65, 129, 0),
+    ("L1PA3", "100M", 3001, 65, 129, 0),
+    ("AluY", "100M", 1, 65, 145, 0),
+    ("AluY", "100M", 101, 65, 145, 0),
+  ];
+  expect |> Some(("AluY,1,200,-", 200, 0));
+);
+
+test_summarize_me!(strand01; // undetermined strand
+  params |> [
+    ("L1HS", "100M", 4001, 65, 129, 0),
+    ("L1HS", "70M30S", 5931, 65, 145, 0),
+  ];
+  expect |> Some(("L1HS,4001,6000,.", 2000, 4000));
+);
+
+test_summarize_me!(discordant01; // polarized against mate
+  params |> [
+    ("L1HS", "100M", 4001, 65, 0, 145),
+    ("L1HS", "70M30S", 5931, 65, 0, 145),
+  ];
+  expect |> Some(("L1HS,4001,6000,+", 2000, 4000));
+);
+
+test_summarize_me!(discordant02; // read alignment preferred over mate
+  params |> [
+    ("L1HS", "100M", 4001, 81, 129, 129),
+    ("L1HS", "70M30S", 5931, 81, 0, 145),
+  ];
+  expect |> Some(("L1HS,4001,6000,-", 2000, 4000));
+);
+
+// fail
+test_summarize_me!(fail01;
+  params |> Vec::<(&str, &str, i32, i32, i32, i32)>::new();
+  expect |> None;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,7 +1,10 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use genomic_structures::reverse_sequence;
+use genomic_structures::{
+  gc_content,
+  reverse_sequence,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -22,6 +25,18 @@ macro_rules! test_reverse_sequence {
   };
 }
 
+// GC content
+macro_rules! test_gc_content {
+  ( $function: ident;
+    $sequence: expr, $expect: expr
+  ) => {
+    #[test]
+    fn $function() {
+      assert_eq!(gc_content($sequence), $expect);
+    }
+  };
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // test
@@ -30,4 +45,10 @@ test_reverse_sequence!(rev02; "MACTHAA", "TTHAGTM".to_string());
 test_reverse_sequence!(rev03; "CAAGAAC", "GTTCTTG".to_string());
 test_reverse_sequence!(rev04; "GATTACA", "TGTAATC".to_string());
 
+test_gc_content!(gc01; "GGCCGGCC", Some(1.));
+test_gc_content!(gc02; "GATTACAA", Some(0.25));
+test_gc_content!(gc03; "ACGTNNNN", Some(0.5));
+test_gc_content!(gc04; "ACGNNNNN", None);
+test_gc_content!(gc05; "", None);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,77 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::detect_poly_tail;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// detect poly-A / poly-T tail
+macro_rules! test_detect_poly_tail {
+  ( $function: ident;
+    params |> $sequence: expr, $from_start: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let tail = detect_poly_tail($sequence, $from_start, 10, 0.1)
+        .map(|tail| (tail.base, tail.length, tail.mismatches));
+      let expect: Option<(char, i32, i32)> = $expect;
+      assert_eq!(
+        tail, expect,
+        "\n\nSequence: {:?}.\nDetected tail: {:?}.\nExpected: {:?}.\n\n",
+        $sequence, tail, expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_detect_poly_tail!(polya01;
+  params |> "AAAAAAAAAAAAGCTAGCTA", true;
+  expect |> Some(('A', 12, 0));
+);
+
+test_detect_poly_tail!(polya02; // sequencing error
+  params |> "AAAAAAGAAAAAAAACGTCGT", true;
+  expect |> Some(('A', 15, 1));
+);
+
+test_detect_poly_tail!(polya03; // lower case
+  params |> "aaaaaaaaaaaacgt", true;
+  expect |> Some(('A', 12, 0));
+);
+
+test_detect_poly_tail!(polyt01;
+  params |> "GCGAGCGCTTTTTTTTTTTT", false;
+  expect |> Some(('T', 12, 0));
+);
+
+test_detect_poly_tail!(polyt02; // tail at sequence start
+  params |> "TTTTTTTTTTTTGCTAGCTA", true;
+  expect |> Some(('T', 12, 0));
+);
+
+// fail
+test_detect_poly_tail!(fail01; // short tail
+  params |> "AAAAAAAGCTAGCTA", true;
+  expect |> None;
+);
+
+test_detect_poly_tail!(fail02; // tail away from junction
+  params |> "GCTAGCTAAAAAAAAAAAAA", true;
+  expect |> None;
+);
+
+test_detect_poly_tail!(fail03; // mismatch run
+  params |> "AAAAAGCGAAAAAAAAA", true;
+  expect |> None;
+);
+
+test_detect_poly_tail!(fail04;
+  params |> "", true;
+  expect |> None;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,7 +1,11 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use genomic_structures::threshold;
+use genomic_structures::{
+  threshold,
+  DetectionConfig,
+  Position,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -16,7 +20,7 @@ macro_rules! test_threshold {
     fn $function() {
       let mut bined_hm = std::collections::HashMap::new();
       for ix in 0..$keys.len() {
-        bined_hm.insert($keys[ix].clone(), $values[ix].clone());
+        bined_hm.insert(Position::OneBased($keys[ix]), $values[ix].clone());
       }
 
       let threshold25 = threshold(
@@ -24,7 +28,8 @@ macro_rules! test_threshold {
         $scaffold_size,
         $fdr,
         &bined_hm,
-        25
+        25,
+        &DetectionConfig::default(),
       );
       assert_eq!(
         threshold25,
@@ -0,0 +1,119 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  detect_tsd,
+  ChrAnchor,
+  MEAnchor,
+  MEChimericRead,
+  OrientationEnum,
+  Position,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// detect target site duplication
+macro_rules! test_detect_tsd {
+  ( $function: ident;
+    params |> $max_length: expr;
+    expect |> $expect: expr;
+    vargs ... $($orientation: tt, $sequence: expr, $me_flag: expr, $chr_flag: expr, $cigar: expr, $position: expr);+;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_chimeric_reads = vec![];
+      // variadic loading
+      $(
+        let mut me_anchor = MEAnchor::new();
+        me_anchor.flag = $me_flag;
+        let mut me_chimeric_read = MEChimericRead::load(me_anchor);
+        me_chimeric_read.sequence = $sequence.to_string();
+        me_chimeric_read.orientation = OrientationEnum::$orientation;
+        let position = Position::OneBased($position);
+        me_chimeric_read.chr_read.push(ChrAnchor::load(
+          CIGAR::load($cigar, position).expect("CIGAR loading failed!"),
+          "chr1".to_string(),
+          $chr_flag,
+          60,
+          position,
+          0,
+        ));
+        me_chimeric_reads.push(me_chimeric_read);
+      )+
+
+      let tsd = detect_tsd(&me_chimeric_reads, 10, 5, $max_length).map(|tsd| {
+        (
+          tsd.start.one_based(),
+          tsd.end.one_based(),
+          tsd.length,
+          tsd.sequence,
+          tsd.support,
+        )
+      });
+      let expect: Option<(u64, u64, u64, String, usize)> = $expect;
+      assert_eq!(
+        tsd, expect,
+        "\n\nDetected TSD:\n{:#?}.\n\nExpected:\n{:#?}.\n\n",
+        tsd, expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+// GGGGGGGGGG[AAGAATTC]|ME|[AAGAATTC]CCCCCCCCCC
+test_detect_tsd!(tsd01;
+  params |> 50;
+  expect |> Some((1011, 1018, 8, "AAGAATTC".to_string(), 3));
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+    Downstream, "MMMMMMMMMMMMAAGAATTCCCCCCCCCCC", 65, 65, "12S18M", 1011;
+    Downstream, "MMMMMMMMMMMAAGAATTCCCCCCCCCCCC", 65, 65, "11S19M", 1011;
+);
+
+test_detect_tsd!(tsd02; // reverse strand chromosomal alignment
+  params |> 50;
+  expect |> Some((1011, 1018, 8, "AAGAATTC".to_string(), 2));
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+    Downstream, "GGGGGGGGGGGAATTCTTMMMMMMMMMMMM", 65, 81, "12S18M", 1011;
+);
+
+test_detect_tsd!(tsd03; // unclassified reads ignored
+  params |> 50;
+  expect |> Some((1011, 1018, 8, "AAGAATTC".to_string(), 2));
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+    Downstream, "MMMMMMMMMMMMAAGAATTCCCCCCCCCCC", 65, 65, "12S18M", 1011;
+    None, "MMMMMMMMMMMMMMMMMMMMCCCCCCCCCC", 65, 65, "20S10M", 1021;
+    None, "MMMMMMMMMMMMMMMMMMMMCCCCCCCCCC", 65, 65, "20S10M", 1021;
+);
+
+// fail
+test_detect_tsd!(fail01; // maximum length
+  params |> 5;
+  expect |> None;
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+    Downstream, "MMMMMMMMMMMMAAGAATTCCCCCCCCCCC", 65, 65, "12S18M", 1011;
+);
+
+test_detect_tsd!(fail02; // junctions do not overlap
+  params |> 50;
+  expect |> None;
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+    Downstream, "MMMMMMMMMMMMCCCCCCCCCCCCCCCCCC", 65, 65, "12S18M", 1025;
+);
+
+test_detect_tsd!(fail03; // single junction
+  params |> 50;
+  expect |> None;
+  vargs ...
+    Upstream, "GGGGGGGGGGAAGAATTCMMMMMMMMMMMM", 65, 65, "18M12S", 1001;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,237 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  load_vcf,
+  write_me_vcf,
+  write_sv_vcf,
+  ClipSideEnum,
+  MEClassification,
+  MEInsertionCall,
+  Position,
+  SVCall,
+  SVType,
+  SiteFilterEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// VCF header
+const HEADER: &str = "##fileformat=VCFv4.2\n\
+  #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load structural variants
+macro_rules! test_load_sv {
+  ( $function: ident;
+    params |> $records: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let vcf = format!("{}{}", HEADER, $records.join("\n"));
+      let (sv_calls, me_insertion_calls) =
+        load_vcf(vcf.as_bytes()).expect("VCF loading failed!");
+      assert!(me_insertion_calls.is_empty());
+      let produced = sv_calls
+        .iter()
+        .map(|sv_call| {
+          (
+            sv_call.svtype,
+            (sv_call.chr.as_str(), sv_call.position.one_based()),
+            (sv_call.chr2.as_str(), sv_call.end.one_based()),
+            sv_call.orientation,
+            sv_call.support,
+          )
+        })
+        .collect::<Vec<_>>();
+      let expect: Vec<(
+        SVType,
+        (&str, u64),
+        (&str, u64),
+        (ClipSideEnum, ClipSideEnum),
+        usize,
+      )> = $expect;
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+// load mobile element insertions
+macro_rules! test_load_me {
+  ( $function: ident;
+    params |> $records: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let vcf = format!("{}{}", HEADER, $records.join("\n"));
+      let (sv_calls, me_insertion_calls) =
+        load_vcf(vcf.as_bytes()).expect("VCF loading failed!");
+      assert!(sv_calls.is_empty());
+      let produced = me_insertion_calls
+        .iter()
+        .map(|me_insertion_call| {
+          (
+            me_insertion_call.position.one_based(),
+            me_insertion_call.family().to_string(),
+            (
+              me_insertion_call.ci_start.one_based(),
+              me_insertion_call.ci_end.one_based(),
+            ),
+            me_insertion_call.precise,
+            (me_insertion_call.five_prime, me_insertion_call.three_prime),
+          )
+        })
+        .collect::<Vec<_>>();
+      let expect: Vec<(u64, &str, (u64, u64), bool, (usize, usize))> = $expect;
+      assert_eq!(
+        produced,
+        expect
+          .iter()
+          .map(|(position, family, ci, precise, support)| {
+            (*position, family.to_string(), *ci, *precise, *support)
+          })
+          .collect::<Vec<_>>()
+      );
+    }
+  };
+}
+
+// fail
+macro_rules! test_load_fail {
+  ( $function: ident;
+    params |> $records: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let vcf = format!("{}{}", HEADER, $records.join("\n"));
+      assert!(load_vcf(vcf.as_bytes()).is_err());
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_load_sv!(sv01; // symbolic alleles, sub-typed on complex variants
+  params |> [
+    "chr1\t1001\tSV1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;SVLEN=-800;END=1800;IMPRECISE;PE=5;SR=0",
+    "chr1\t5001\tSV2\tN\t<DUP:INV>\t.\tDEPTH\tSVTYPE=DUP;END=5600;PE=3",
+  ];
+  expect |> vec![
+    (SVType::Deletion, ("chr1", 1001), ("chr1", 1800), (ClipSideEnum::None, ClipSideEnum::None), 5),
+    (SVType::InvertedDuplication, ("chr1", 5001), ("chr1", 5600), (ClipSideEnum::None, ClipSideEnum::None), 3),
+  ];
+);
+
+test_load_sv!(sv02; // breakend pair loaded once
+  params |> [
+    "chr1\t20000\tSV1_1\tN\tN[chr2:5001[\t.\tPASS\tSVTYPE=BND;CHR2=chr2;PE=4;MATEID=SV1_2",
+    "chr2\t5001\tSV1_2\tN\t]chr1:20000]N\t.\tPASS\tSVTYPE=BND;CHR2=chr1;PE=4;MATEID=SV1_1",
+  ];
+  expect |> vec![
+    (SVType::Translocation, ("chr1", 20000), ("chr2", 5001), (ClipSideEnum::Right, ClipSideEnum::Left), 4),
+  ];
+);
+
+test_load_sv!(sv03; // downstream mate first, breakends swapped
+  params |> [
+    "chr2\t5001\tbnd_b\tN\t[chr1:20000[N\t.\tPASS\tSVTYPE=BND;MATEID=bnd_a",
+    "chr1\t20000\tbnd_a\tN\tN]chr2:5001]\t.\tPASS\tSVTYPE=BND;MATEID=bnd_b",
+  ];
+  expect |> vec![
+    (SVType::Translocation, ("chr1", 20000), ("chr2", 5001), (ClipSideEnum::Left, ClipSideEnum::Left), 0),
+  ];
+);
+
+test_load_sv!(sv04; // small variants & unknown types skipped
+  params |> [
+    "chr1\t100\tsnv1\tA\tG\t60\tPASS\tDP=30",
+    "chr1\t200\tcnv1\tN\t<CNV>\t.\tPASS\tSVTYPE=CNV;END=900",
+  ];
+  expect |> vec![];
+);
+
+test_load_me!(me01; // confidence interval, support & imprecision
+  params |> [
+    "chr1\t9000\tME1\tN\t<INS:ME:L1>\t.\tPASS\tSVTYPE=INS;SVLEN=6000;MEINFO=L1HS,1,6000,+;CIPOS=-10,20;IMPRECISE;SUPPORT=4,3;SR=0",
+  ];
+  expect |> vec![(9000, "L1", (8990, 9020), false, (4, 3))];
+);
+
+test_load_me!(me02; // other callers, e.g., MELT, without confidence interval
+  params |> [
+    "chr1\t10000\t.\tC\t<INS:ME:ALU>\t.\tPASS\tSVTYPE=ALU;SVLEN=281;MEINFO=AluYa5,1,281,-",
+  ];
+  expect |> vec![(10000, "ALU", (10000, 10000), true, (0, 0))];
+);
+
+test_load_fail!(fail01; // missing INFO column
+  params |> ["chr1\t1001\tSV1\tN\t<DEL>\t.\tPASS"];
+);
+
+test_load_fail!(fail02; // malformed position
+  params |> ["chr1\tfirst\tSV1\tN\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=1800"];
+);
+
+test_load_fail!(fail03; // malformed support
+  params |> ["chr1\t9000\tME1\tN\t<INS:ME:L1>\t.\tPASS\tSVTYPE=INS;SUPPORT=4"];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// round trip
+#[test]
+fn round01() { // written structural variants load back
+  let mut sv_call = SVCall::new(SVType::Duplication);
+  sv_call.chr = "chr1".to_string();
+  sv_call.chr2 = "chr1".to_string();
+  sv_call.position = Position::OneBased(3000000001);
+  sv_call.end = Position::OneBased(3000002000);
+  sv_call.support = 6;
+  sv_call.split_reads = 2;
+  sv_call.depth_ratio = Some(1.5);
+
+  let mut vcf = Vec::new();
+  write_sv_vcf(&mut vcf, &[sv_call.clone()]).expect("VCF writing failed!");
+  let (sv_calls, _) = load_vcf(&vcf[..]).expect("VCF loading failed!");
+  assert_eq!(sv_calls, vec![sv_call]);
+}
+
+#[test]
+fn round02() { // written insertions load back
+  let mut me_insertion_call = MEInsertionCall::new();
+  me_insertion_call.chr = "chr1".to_string();
+  me_insertion_call.position = Position::OneBased(10000);
+  me_insertion_call.ci_start = Position::OneBased(9990);
+  me_insertion_call.ci_end = Position::OneBased(10020);
+  me_insertion_call.me_info.mobel = "L1HS".to_string();
+  me_insertion_call.classification = MEClassification::load("L1HS", "LINE/L1");
+  me_insertion_call.five_prime = 4;
+  me_insertion_call.three_prime = 3;
+  me_insertion_call.split_reads = 1;
+  me_insertion_call.filters = vec![SiteFilterEnum::Blacklist];
+
+  let mut vcf = Vec::new();
+  write_me_vcf(&mut vcf, &[me_insertion_call.clone()])
+    .expect("VCF writing failed!");
+  let (_, me_insertion_calls) =
+    load_vcf(&vcf[..]).expect("VCF loading failed!");
+  let loaded = &me_insertion_calls[0];
+  assert_eq!(
+    (loaded.position, loaded.ci_start, loaded.ci_end),
+    (
+      me_insertion_call.position,
+      me_insertion_call.ci_start,
+      me_insertion_call.ci_end
+    )
+  );
+  assert_eq!(loaded.family(), "L1");
+  assert_eq!(loaded.me_info, me_insertion_call.me_info);
+  assert_eq!(loaded.support() + loaded.split_reads, 8);
+  assert_eq!(loaded.filters, me_insertion_call.filters);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
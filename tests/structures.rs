@@ -1,13 +1,29 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // modules
+mod allele_counter;
+mod annotation_index;
+mod bam_reader;
 mod break_point;
+mod call_merger;
 mod chr_anchor;
 mod cigar;
+mod depth_profile;
+mod detection_config;
+mod feature_index;
+mod indexed_fasta;
+mod interval_index;
 mod me_anchor;
 mod me_chimeric_pair;
 mod me_chimeric_read;
+mod me_genotyper;
+mod me_insertion_caller;
 mod raw_values;
+mod region;
+mod repeat_masker_record;
+mod sample_genotype;
+mod site_filter;
+mod sv_caller;
 // mod sv_chimeric_pair;
 // mod sv_chimeric_read;
 
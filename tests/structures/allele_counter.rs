@@ -0,0 +1,281 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::{
+  HashMap,
+  HashSet,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  AlleleCount,
+  AlleleCounter,
+  ChrAnchor,
+  ChrAnchorEnum,
+  GenomicInterval,
+  MEChimericPair,
+  Position,
+  RawValues,
+  SVChimericPair,
+  SVType,
+  CIGAR,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// breakpoints at chr1:10000 & chr1:30000
+fn sites() -> Vec<GenomicInterval> {
+  [10000, 30000]
+    .iter()
+    .map(|site| {
+      let site = Position::OneBased(*site);
+      GenomicInterval::new("chr1".to_string(), site, site)
+    })
+    .collect()
+}
+
+// chromosomal anchor
+fn chr_anchor(
+  position: u64,
+  cigar: &str,
+) -> ChrAnchor {
+  let position = Position::OneBased(position);
+  let cigar = CIGAR::load(cigar, position).expect("CIGAR loading failed!");
+  ChrAnchor::load(cigar, "chr1".to_string(), 65, 60, position, 0)
+}
+
+// sorted read IDs per breakpoint
+fn read_ids(produced: Vec<HashSet<String>>) -> Vec<Vec<String>> {
+  produced
+    .into_iter()
+    .map(|read_ids| {
+      let mut read_ids = read_ids.into_iter().collect::<Vec<String>>();
+      read_ids.sort();
+      read_ids
+    })
+    .collect()
+}
+
+// reads around chr1:10000 & chr1:30000
+const CHR_ALIGNMENT: &str = "@SQ\tSN:chr1\tLN:50000\n\
+  read1\t0\tchr1\t9950\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  clip1\t0\tchr1\t9950\t60\t60M40S\t*\t0\t0\tACGT\t*\n\
+  pair1\t99\tchr1\t9900\t60\t100M\t=\t10100\t300\tACGT\t*\n\
+  pair1\t147\tchr1\t10100\t60\t100M\t=\t9900\t-300\tACGT\t*\n\
+  disc1\t65\tchr1\t9950\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  short1\t0\tchr1\t9995\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  secondary1\t256\tchr1\t9950\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  low1\t0\tchr1\t9950\t5\t100M\t*\t0\t0\tACGT\t*\n\
+  read2\t0\tchr1\t29950\t60\t100M\t*\t0\t0\tACGT\t*\n";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// spans
+macro_rules! test_spans {
+  ( $function: ident;
+    params |> $flag: expr, $position: expr, $cigar: expr, $tlen: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let values = RawValues::load(vec![
+        "ID", $flag, "chr1", $position, "60", $cigar, "*", "0", $tlen, "ACGT",
+        "*",
+      ])
+      .expect("RawValues loading failed!");
+      let produced = AlleleCounter::new().spans(&values, &sites()[0]);
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+// reference
+macro_rules! test_reference {
+  ( $function: ident;
+    params |> $alignment: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let produced = AlleleCounter::new()
+        .reference(RawValues::records($alignment.as_bytes()), &sites())
+        .expect("Counting failed!");
+      assert_eq!(read_ids(produced), $expect);
+    }
+  };
+}
+
+// tally
+macro_rules! test_tally {
+  ( $function: ident;
+    params |> $reference: expr, $alternate: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let to_set = |read_ids: &[&str]| {
+        read_ids
+          .iter()
+          .map(|read_id| read_id.to_string())
+          .collect::<HashSet<String>>()
+      };
+      let reference =
+        $reference.iter().map(|ids| to_set(ids)).collect::<Vec<_>>();
+      let alternate =
+        $alternate.iter().map(|ids| to_set(ids)).collect::<Vec<_>>();
+      let produced = AlleleCounter::tally(&reference, &alternate);
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_spans!(spans01; // unpaired read spanning breakpoint
+  params |> "0", "9950", "100M", "0";
+  expect |> true;
+);
+
+test_spans!(spans02; // overhang short of minimum
+  params |> "0", "9995", "100M", "0";
+  expect |> false;
+);
+
+test_spans!(spans03; // clipped read
+  params |> "0", "9950", "95M5S", "0";
+  expect |> false;
+);
+
+test_spans!(spans04; // clip below minimum
+  params |> "0", "9950", "96M4S", "0";
+  expect |> true;
+);
+
+test_spans!(spans05; // proper pair template spanning breakpoint
+  params |> "99", "9800", "100M", "400";
+  expect |> true;
+);
+
+test_spans!(spans06; // discordant pair, unclipped read spanning breakpoint
+  params |> "65", "9950", "100M", "0";
+  expect |> true;
+);
+
+test_spans!(spans07; // deletion within read spanning breakpoint
+  params |> "0", "9950", "40M30D60M", "0";
+  expect |> true;
+);
+
+test_spans!(spans08; // discordant pair template not extended
+  params |> "97", "9800", "100M", "400";
+  expect |> false;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_reference!(reference01; // primary & unclipped only
+  params |> CHR_ALIGNMENT;
+  expect |> vec![
+    vec!["disc1".to_string(), "pair1".to_string(), "read1".to_string()],
+    vec!["read2".to_string()],
+  ];
+);
+
+test_reference!(reference02; // header only
+  params |> "@SQ\tSN:chr1\tLN:50000\n";
+  expect |> vec![Vec::<String>::new(), Vec::new()];
+);
+
+#[test]
+fn reference_error01() {
+  assert!(AlleleCounter::new()
+    .reference(RawValues::records("read1\t0\tchr1\n".as_bytes()), &sites())
+    .is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn me_alternate01() {
+  let mut me_chimeric_pairs = HashMap::new();
+  for (read_id, chranch, position) in [
+    ("near1", ChrAnchorEnum::Read1, 9700),
+    ("near2", ChrAnchorEnum::Read2, 10450),
+    ("far1", ChrAnchorEnum::Read1, 31000),
+    ("unresolved1", ChrAnchorEnum::None, 9900),
+  ]
+  .iter()
+  {
+    let mut me_chimeric_pair = MEChimericPair::new();
+    me_chimeric_pair.chranch = *chranch;
+    me_chimeric_pair.read1.chr_read.push(chr_anchor(*position, "100M"));
+    me_chimeric_pair.read2.chr_read.push(chr_anchor(*position, "100M"));
+    me_chimeric_pairs.insert(read_id.to_string(), me_chimeric_pair);
+  }
+
+  let produced =
+    AlleleCounter::new().me_alternate(&me_chimeric_pairs, &sites());
+  assert_eq!(read_ids(produced), vec![
+    vec!["near1".to_string(), "near2".to_string()],
+    Vec::new(),
+  ]);
+}
+
+#[test]
+fn sv_alternate01() {
+  let mut sv_chimeric_pairs = HashMap::new();
+
+  // split read clipped at breakpoint
+  let mut sv_chimeric_pair = SVChimericPair::new(SVType::None);
+  sv_chimeric_pair.read1.chr_read.push(chr_anchor(9941, "60M40S"));
+  sv_chimeric_pair.read2.chr_read.push(chr_anchor(10300, "100M"));
+  sv_chimeric_pairs.insert("split1".to_string(), sv_chimeric_pair);
+
+  // identified pair anchored near both breakpoints
+  let mut sv_chimeric_pair = SVChimericPair::new(SVType::Deletion);
+  sv_chimeric_pair.read1.chr_read.push(chr_anchor(9600, "100M"));
+  sv_chimeric_pair.read2.chr_read.push(chr_anchor(30200, "100M"));
+  sv_chimeric_pairs.insert("pair1".to_string(), sv_chimeric_pair);
+
+  // unidentified pair
+  let mut sv_chimeric_pair = SVChimericPair::new(SVType::None);
+  sv_chimeric_pair.read1.chr_read.push(chr_anchor(9800, "100M"));
+  sv_chimeric_pair.read2.chr_read.push(chr_anchor(10100, "100M"));
+  sv_chimeric_pairs.insert("concordant1".to_string(), sv_chimeric_pair);
+
+  let produced =
+    AlleleCounter::new().sv_alternate(&sv_chimeric_pairs, &sites());
+  assert_eq!(read_ids(produced), vec![
+    vec!["pair1".to_string(), "split1".to_string()],
+    vec!["pair1".to_string()],
+  ]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_tally!(tally01; // fragments supporting both alleles counted as alternate
+  params |> [vec!["pair1", "pair2", "pair3"]], [vec!["pair3", "pair4"]];
+  expect |> vec![AlleleCount::new(2, 2)];
+);
+
+test_tally!(tally02; // without support
+  params |> [Vec::<&str>::new()], [Vec::<&str>::new()];
+  expect |> vec![AlleleCount::new(0, 0)];
+);
+
+#[test]
+fn allele_count01() {
+  let allele_count = AlleleCount::new(9, 3);
+  assert_eq!(allele_count.depth(), 12);
+  assert_eq!(allele_count.allele_balance(), Some(0.25));
+  assert_eq!(allele_count.to_string(), "9,3");
+  assert_eq!(
+    allele_count.likelihoods(0.01),
+    genomic_structures::genotype_likelihoods(9, 3, 0.01)
+  );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,129 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  AnnotationIndex,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// blacklist regions, 0-based half-open
+const BED: &str = "#chrom\tstart\tend\tname
+browser position chr1:1-10000
+chr1\t0\t10000\ttelomere
+chr1\t121700000\t125100000\tcentromere
+chr1\t5000\t5100\tgap
+
+chr2\t300\t400
+chr3\t3000000000\t3000000100\tdistal
+";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// overlap
+macro_rules! test_overlap {
+  ( $function: ident;
+    params |> $chr: expr, $start: expr, $end: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let annotation_index =
+        AnnotationIndex::load_bed(BED.as_bytes()).expect("BED loading failed!");
+      let produced = annotation_index
+        .overlaps($chr, Position::OneBased($start), Position::OneBased($end))
+        .iter()
+        .map(|(region, label)| {
+          (region.start.one_based(), region.end.one_based(), label.as_str())
+        })
+        .collect::<Vec<_>>();
+      let expect: Vec<(u64, u64, &str)> = $expect;
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+// malformed
+macro_rules! test_error {
+  ( $function: ident;
+    params |> $bed: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      assert!(AnnotationIndex::load_bed($bed.as_bytes()).is_err());
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_overlap!(overlap01; // nested regions
+  params |> "chr1", 5050, 5050;
+  expect |> vec![(1, 10000, "telomere"), (5001, 5100, "gap")];
+);
+
+test_overlap!(overlap02; // long region starting far upstream
+  params |> "chr1", 125000000, 125200000;
+  expect |> vec![(121700001, 125100000, "centromere")];
+);
+
+test_overlap!(overlap03; // boundaries are inclusive
+  params |> "chr1", 10000, 10000;
+  expect |> vec![(1, 10000, "telomere")];
+);
+
+test_overlap!(overlap04; // between regions
+  params |> "chr1", 10001, 121700000;
+  expect |> vec![];
+);
+
+test_overlap!(overlap05; // unlabeled region
+  params |> "chr2", 350, 360;
+  expect |> vec![(301, 400, "")];
+);
+
+test_overlap!(overlap06; // chromosome not annotated
+  params |> "chrX", 1, 1000;
+  expect |> vec![];
+);
+
+test_overlap!(overlap07; // beyond 32-bit coordinates
+  params |> "chr3", 3000000050, 3000000050;
+  expect |> vec![(3000000001, 3000000100, "distal")];
+);
+
+// regions inserted out of order, indexed once
+#[test]
+fn insert01() {
+  let mut annotation_index = AnnotationIndex::new();
+  for (start, end, label) in [
+    (30001, 30300, "Alu"),
+    (10001, 16000, "L1"),
+    (15001, 15300, "Alu"),
+  ] {
+    let (start, end) = (Position::OneBased(start), Position::OneBased(end));
+    annotation_index.insert("chr1", start, end, label);
+  }
+  annotation_index.index();
+
+  let produced = annotation_index
+    .overlaps("chr1", Position::OneBased(15100), Position::OneBased(30100))
+    .iter()
+    .map(|(region, label)| (region.start.one_based(), label.as_str()))
+    .collect::<Vec<_>>();
+  assert_eq!(produced, vec![(10001, "L1"), (15001, "Alu"), (30001, "Alu")]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_error!(error01; // missing end
+  params |> "chr1\t100\n";
+);
+
+test_error!(error02; // non numeric coordinate
+  params |> "chr1\tstart\t200\n";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,242 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use flate2::write::DeflateEncoder;
+use flate2::{
+  Compression,
+  Crc,
+};
+use std::io::{
+  BufRead,
+  Read,
+  Write,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  decode_alignment,
+  BamReader,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// BGZF block
+fn bgzf_block(data: &[u8]) -> Vec<u8> {
+  let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+  encoder.write_all(data).expect("Compression failed!");
+  let compressed = encoder.finish().expect("Compression failed!");
+  let mut crc = Crc::new();
+  crc.update(data);
+
+  let block_size = (compressed.len() + 25) as u16;
+  let mut block =
+    vec![31, 139, 8, 4, 0, 0, 0, 0, 0, 255, 6, 0, b'B', b'C', 2, 0];
+  block.extend(block_size.to_le_bytes().iter());
+  block.extend(compressed);
+  block.extend(crc.sum().to_le_bytes().iter());
+  block.extend((data.len() as u32).to_le_bytes().iter());
+  block
+}
+
+// BAM header on chr1 & chr2
+fn header(text: &str) -> Vec<u8> {
+  let mut bam = b"BAM\x01".to_vec();
+  bam.extend((text.len() as i32).to_le_bytes().iter());
+  bam.extend(text.as_bytes());
+  bam.extend(2i32.to_le_bytes().iter());
+  for (name, length) in [("chr1", 1000i32), ("chr2", 2000i32)].iter() {
+    bam.extend((name.len() as i32 + 1).to_le_bytes().iter());
+    bam.extend(name.as_bytes());
+    bam.push(0);
+    bam.extend(length.to_le_bytes().iter());
+  }
+  bam
+}
+
+// BAM record, 0-based positions & encoded cigar, sequence & tags
+#[allow(clippy::too_many_arguments)]
+fn record(
+  name: &str,
+  flag: u16,
+  ref_id: i32,
+  pos: i32,
+  cigar: &[(u32, u32)],
+  next: (i32, i32, i32),
+  sequence: &str,
+  tags: &[u8],
+) -> Vec<u8> {
+  let mut block = Vec::new();
+  block.extend(ref_id.to_le_bytes().iter());
+  block.extend(pos.to_le_bytes().iter());
+  block.push(name.len() as u8 + 1);
+  block.push(60);
+  block.extend(0u16.to_le_bytes().iter());
+  block.extend((cigar.len() as u16).to_le_bytes().iter());
+  block.extend(flag.to_le_bytes().iter());
+  block.extend((sequence.len() as i32).to_le_bytes().iter());
+  block.extend(next.0.to_le_bytes().iter());
+  block.extend(next.1.to_le_bytes().iter());
+  block.extend(next.2.to_le_bytes().iter());
+  block.extend(name.as_bytes());
+  block.push(0);
+  for (length, operation) in cigar.iter() {
+    block.extend((length << 4 | operation).to_le_bytes().iter());
+  }
+  let codes = sequence
+    .bytes()
+    .map(|base| b"=ACMGRSVTWYHKDBN".iter().position(|code| *code == base))
+    .map(|code| code.expect("Base encoding failed!") as u8)
+    .collect::<Vec<u8>>();
+  for pair in codes.chunks(2) {
+    block.push(pair[0] << 4 | pair.get(1).copied().unwrap_or(0));
+  }
+  block.extend(std::iter::repeat(30).take(sequence.len()));
+  block.extend(tags);
+
+  let mut bam = (block.len() as u32).to_le_bytes().to_vec();
+  bam.extend(block);
+  bam
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & read
+macro_rules! test_bam_reader {
+  ( $function: ident;
+    params |> $bam: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut sam = String::new();
+      BamReader::load(&$bam[..])
+        .expect("BAM loading failed!")
+        .read_to_string(&mut sam)
+        .expect("BAM reading failed!");
+      assert_eq!(
+        sam, $expect,
+        "\n\nDecoded SAM:\n{}.\n\nExpected:\n{}.\n\n",
+        sam, $expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_bam_reader!(header01;
+  params |> header("@HD\tVN:1.6\n@SQ\tSN:chr1\tLN:1000\n");
+  expect |> "@HD\tVN:1.6\n@SQ\tSN:chr1\tLN:1000\n@SQ\tSN:chr2\tLN:2000\n";
+);
+
+test_bam_reader!(record01;
+  params |> {
+    let mut tags = b"SAZchr2,501,+,4S6M,60,0\0".to_vec();
+    tags.extend(b"NMC\x01");
+    tags.extend(b"XSs\xfe\xff");
+    tags.extend(b"ZBBc\x02\x00\x00\x00\x01\xff");
+    let mut bam = header("");
+    bam.extend(record(
+      "read1",
+      99,
+      0,
+      100,
+      &[(4, 4), (5, 0), (1, 1)],
+      (0, 300, 310),
+      "ACGTNACGTA",
+      &tags,
+    ));
+    bam
+  };
+  expect |> [
+    "@SQ\tSN:chr1\tLN:1000\n@SQ\tSN:chr2\tLN:2000\n",
+    "read1\t99\tchr1\t101\t60\t4S5M1I\t=\t301\t310\tACGTNACGTA\t",
+    "??????????\tSA:Z:chr2,501,+,4S6M,60,0\tNM:i:1\tXS:i:-2\tZB:B:c,1,-1\n",
+  ].concat();
+);
+
+test_bam_reader!(record02;
+  params |> {
+    let mut bam = header("");
+    bam.extend(record("read2", 73, 1, 0, &[(3, 0)], (0, 9, 0), "AGT", b""));
+    bam.extend(record("read3", 4, -1, -1, &[], (-1, -1, 0), "", b""));
+    bam
+  };
+  expect |> [
+    "@SQ\tSN:chr1\tLN:1000\n@SQ\tSN:chr2\tLN:2000\n",
+    "read2\t73\tchr2\t1\t60\t3M\tchr1\t10\t0\tAGT\t???\n",
+    "read3\t4\t*\t0\t60\t*\t*\t0\t0\t*\t*\n",
+  ].concat();
+);
+
+test_bam_reader!(record03; // qualities clamped onto printable range
+  params |> {
+    let mut bam = header("");
+    bam.extend(record("read4", 0, 0, 0, &[(3, 0)], (-1, -1, 0), "ACG", b""));
+    let length = bam.len();
+    bam[length - 3..].copy_from_slice(&[30, 93, 240]);
+    bam
+  };
+  expect |> [
+    "@SQ\tSN:chr1\tLN:1000\n@SQ\tSN:chr2\tLN:2000\n",
+    "read4\t0\tchr1\t1\t60\t3M\t*\t0\t0\tACG\t?~~\n",
+  ].concat();
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// decode alignment stream
+#[test]
+fn decode01() {
+  let mut bam = header("");
+  bam.extend(record("read1", 0, 0, 9, &[(3, 0)], (-1, -1, 0), "ACG", b""));
+  let mut bgzf = bgzf_block(&bam);
+  bgzf.extend(bgzf_block(b""));
+
+  let lines = decode_alignment(&bgzf[..])
+    .expect("Alignment decoding failed!")
+    .lines()
+    .collect::<Result<Vec<String>, _>>()
+    .expect("Alignment reading failed!");
+  assert_eq!(lines, vec![
+    "@SQ\tSN:chr1\tLN:1000",
+    "@SQ\tSN:chr2\tLN:2000",
+    "read1\t0\tchr1\t10\t60\t3M\t*\t0\t0\tACG\t???",
+  ]);
+}
+
+#[test]
+fn decode02() {
+  let sam = "read1\t0\tchr1\t10\t60\t3M\t*\t0\t0\tACG\t???\n";
+  let mut decoded = String::new();
+  decode_alignment(sam.as_bytes())
+    .expect("Alignment decoding failed!")
+    .read_to_string(&mut decoded)
+    .expect("Alignment reading failed!");
+  assert_eq!(decoded, sam);
+}
+
+#[test]
+fn fail01() {
+  let mut bam = header("");
+  bam.extend(record("read1", 0, 0, 9, &[(3, 0)], (-1, -1, 0), "ACG", b""));
+  bam.pop();
+
+  let mut sam = String::new();
+  let produced = BamReader::load(&bam[..])
+    .expect("BAM loading failed!")
+    .read_to_string(&mut sam);
+  assert!(produced.is_err());
+}
+
+#[test]
+fn fail02() {
+  // negative header text length
+  let bgzf = bgzf_block(b"BAM\x01\xff\xff\xff\xff");
+  assert!(decode_alignment(&bgzf[..]).is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
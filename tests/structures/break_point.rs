@@ -1,19 +1,24 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use genomic_structures::BreakPoint;
+use genomic_structures::{
+  BreakPoint,
+  ClipSideEnum,
+  Position,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // load & update
 macro_rules! test_break_point {
   ( $function: ident; $assertion: ident;
-    params |> $sequence: expr, $offset: expr;
+    params |> $sequence: expr, $offset: expr, $flank: expr;
     expect |> $expect: expr;
   ) => {
     #[test]
     fn $function() {
-      let break_point = BreakPoint::load($sequence, $offset);
+      let break_point = BreakPoint::load($sequence, $offset, $flank)
+        .expect("BreakPoint loading failed!");
 
       $assertion!(
         break_point,
@@ -30,52 +35,120 @@ macro_rules! test_break_point {
 
 // test
 test_break_point!(test01; assert_eq;
-  params |> "B1234567890OOOOO", 0.;
+  params |> "B1234567890OOOOO", 0, 10;
   expect |> BreakPoint{
     sequence: "B1234567890".to_string(),
-    coordinate: 1.,
+    coordinate: Position::OneBased(1),
+    side: ClipSideEnum::Left,
   };
 );
 
 test_break_point!(test02; assert_eq;
-  params |> "OOOOO0987654321B", 1.;
+  params |> "OOOOO0987654321B", 1, 10;
   expect |> BreakPoint{
     sequence: "0987654321B".to_string(),
-    coordinate: 0.,
+    coordinate: Position::OneBased(11),
+    side: ClipSideEnum::Right,
   };
 );
 
 test_break_point!(test03; assert_eq;
-  params |> "MMMMMMMMM0987654321B1234567890OOOOO", -19.;
+  params |> "MMMMMMMMM0987654321B1234567890OOOOO", -19, 10;
   expect |> BreakPoint{
     sequence: "MMMMMMMMM0987654321B1234567890".to_string(),
-    coordinate: 20.,
+    coordinate: Position::OneBased(20),
+    side: ClipSideEnum::Left,
   };
 );
 
 test_break_point!(test04; assert_eq;
-  params |> "OOOOO0987654321B1234567890MMMMMMMMM", 20.;
+  params |> "OOOOO0987654321B1234567890MMMMMMMMM", 20, 10;
   expect |> BreakPoint{
     sequence: "0987654321B1234567890MMMMMMMMM".to_string(),
-    coordinate: -19.,
+    coordinate: Position::OneBased(11),
+    side: ClipSideEnum::Right,
+  };
+);
+
+// clamp flank at read ends
+test_break_point!(test05; assert_eq;
+  params |> "B1234", 0, 10;
+  expect |> BreakPoint{
+    sequence: "B1234".to_string(),
+    coordinate: Position::OneBased(1),
+    side: ClipSideEnum::Left,
+  };
+);
+
+test_break_point!(test06; assert_eq;
+  params |> "4321B", 1, 10;
+  expect |> BreakPoint{
+    sequence: "4321B".to_string(),
+    coordinate: Position::OneBased(5),
+    side: ClipSideEnum::Right,
+  };
+);
+
+test_break_point!(test07; assert_eq;
+  params |> "MMMMB1234567890", -4, 3;
+  expect |> BreakPoint{
+    sequence: "MMMMB123".to_string(),
+    coordinate: Position::OneBased(5),
+    side: ClipSideEnum::Left,
   };
 );
 
 // fail
 test_break_point!(fail01; assert_ne;
-  params |> "MRRRRRRRRRROOOOO", 0.;
+  params |> "MRRRRRRRRRROOOOO", 0, 10;
   expect |> BreakPoint{
     sequence: "MRRRRRRRRRROOOOO".to_string(),
-    coordinate: 1.,
+    coordinate: Position::OneBased(1),
+    side: ClipSideEnum::Left,
   };
 );
 
 test_break_point!(fail02; assert_ne;
-  params |> "OOOOO0987654321B1234567890MMMMMMMMM", 20.;
+  params |> "OOOOO0987654321B1234567890MMMMMMMMM", 20, 10;
   expect |> BreakPoint{
     sequence: "OOOOO0987654321B1234567890MMMMMMMMM".to_string(),
-    coordinate: -19.,
+    coordinate: Position::OneBased(11),
+    side: ClipSideEnum::Right,
+  };
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// out of bounds
+macro_rules! test_break_point_error {
+  ( $function: ident;
+    params |> $sequence: expr, $offset: expr, $flank: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let break_point = BreakPoint::load($sequence, $offset, $flank);
+      assert!(
+        break_point.is_err(),
+        "\n\nCalculated BreakPoint:\n{:#?}.\n\n",
+        break_point,
+      );
+    }
   };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_break_point_error!(error01; // primary record never registered
+  params |> "", 0, 10;
+);
+
+test_break_point_error!(error02;
+  params |> "OOOOO", -5, 10;
+);
+
+test_break_point_error!(error03;
+  params |> "OOOOO", 6, 10;
 );
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
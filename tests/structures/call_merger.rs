@@ -0,0 +1,228 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  CallMerger,
+  MEInsertionCall,
+  MergeModeEnum,
+  Mergeable,
+  Position,
+  SVCall,
+  SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// structural variant
+fn sv_call(
+  svtype: SVType,
+  (chr, position): (&str, u64),
+  (chr2, end): (&str, u64),
+  support: usize,
+) -> SVCall {
+  let mut sv_call = SVCall::new(svtype);
+  sv_call.chr = chr.to_string();
+  sv_call.position = Position::OneBased(position);
+  sv_call.chr2 = chr2.to_string();
+  sv_call.end = Position::OneBased(end);
+  sv_call.support = support;
+  sv_call
+}
+
+// mobile element insertion
+fn me_insertion_call(
+  position: u64,
+  mobel: &str,
+  five_prime: usize,
+) -> MEInsertionCall {
+  let mut me_insertion_call = MEInsertionCall::new();
+  me_insertion_call.chr = "chr1".to_string();
+  me_insertion_call.position = Position::OneBased(position);
+  me_insertion_call.me_info.mobel = mobel.to_string();
+  me_insertion_call.five_prime = five_prime;
+  me_insertion_call
+}
+
+// deletion on chr1
+fn deletion(
+  position: u64,
+  end: u64,
+  support: usize,
+) -> SVCall {
+  sv_call(SVType::Deletion, ("chr1", position), ("chr1", end), support)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// matches
+macro_rules! test_matches {
+  ( $function: ident;
+    params |> $call: expr, $other: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      assert_eq!(CallMerger::new().matches(&$call, &$other), $expect);
+    }
+  };
+}
+
+// merge
+macro_rules! test_merge {
+  ( $function: ident;
+    params |> $mode: expr, $inputs: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut call_merger = CallMerger::new();
+      call_merger.mode = $mode;
+      let inputs = $inputs;
+      let produced = call_merger
+        .merge(&inputs)
+        .iter()
+        .map(|merged_call| {
+          (
+            merged_call.call.breakends().0 .1.one_based(),
+            merged_call.support_vector(inputs.len()),
+          )
+        })
+        .collect::<Vec<(u64, String)>>();
+      let expect = $expect
+        .iter()
+        .map(|(position, support_vector): &(u64, &str)| {
+          (*position, support_vector.to_string())
+        })
+        .collect::<Vec<(u64, String)>>();
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_matches!(matches01; // breakends within distance & overlapping
+  params |> deletion(1001, 3000, 1), deletion(1501, 3400, 1);
+  expect |> true;
+);
+
+test_matches!(matches02; // breakend beyond distance
+  params |> deletion(1001, 3000, 1), deletion(2002, 3000, 1);
+  expect |> false;
+);
+
+test_matches!(matches03; // reciprocal overlap below minimum
+  params |> deletion(1001, 2000, 1), deletion(1600, 2600, 1);
+  expect |> false;
+);
+
+test_matches!(matches04; // variant types differ
+  params |>
+    deletion(1001, 3000, 1),
+    sv_call(SVType::Duplication, ("chr1", 1001), ("chr1", 3000), 1);
+  expect |> false;
+);
+
+test_matches!(matches05; // breakends without overlap, i.e., translocations
+  params |>
+    sv_call(SVType::Translocation, ("chr1", 1001), ("chr5", 9000), 1),
+    sv_call(SVType::Translocation, ("chr1", 1900), ("chr5", 8100), 1);
+  expect |> true;
+);
+
+test_matches!(matches06; // partner chromosomes differ
+  params |>
+    sv_call(SVType::Translocation, ("chr1", 1001), ("chr5", 9000), 1),
+    sv_call(SVType::Translocation, ("chr1", 1001), ("chr6", 9000), 1);
+  expect |> false;
+);
+
+test_matches!(matches07; // mobile element families differ
+  params |>
+    me_insertion_call(1001, "AluY", 1),
+    me_insertion_call(1050, "L1HS", 1);
+  expect |> false;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_merge!(merge01; // union keeps private calls
+  params |> MergeModeEnum::Union, vec![
+    vec![deletion(1001, 3000, 4), deletion(50001, 52000, 6)],
+    vec![deletion(1051, 3020, 9)],
+    vec![deletion(990, 2990, 2), deletion(90001, 92000, 3)],
+  ];
+  expect |> [(1051, "111"), (50001, "100"), (90001, "001")];
+);
+
+test_merge!(merge02; // consensus on two inputs
+  params |> MergeModeEnum::Consensus(2), vec![
+    vec![deletion(1001, 3000, 4), deletion(50001, 52000, 6)],
+    vec![deletion(1051, 3020, 9)],
+    vec![deletion(990, 2990, 2), deletion(90001, 92000, 3)],
+  ];
+  expect |> [(1051, "111")];
+);
+
+test_merge!(merge03; // one call per input on each cluster
+  params |> MergeModeEnum::Union, vec![
+    vec![deletion(1001, 3000, 4), deletion(1101, 3100, 5)],
+    vec![deletion(1051, 3050, 1)],
+  ];
+  expect |> [(1001, "11"), (1101, "10")];
+);
+
+test_merge!(merge04; // first call on support ties
+  params |> MergeModeEnum::Union, vec![
+    vec![deletion(1101, 3000, 4)],
+    vec![deletion(1001, 3000, 4)],
+  ];
+  expect |> [(1001, "11")];
+);
+
+test_merge!(merge05; // insertions merged by family
+  params |> MergeModeEnum::Union, vec![
+    vec![
+      me_insertion_call(1001, "AluY", 3),
+      me_insertion_call(1020, "L1HS", 5),
+    ],
+    vec![me_insertion_call(1040, "AluY", 8)],
+  ];
+  expect |> [(1020, "10"), (1040, "11")];
+);
+
+test_merge!(merge06; // no calls
+  params |> MergeModeEnum::Union, vec![Vec::<SVCall>::new(), Vec::new()];
+  expect |> Vec::<(u64, &str)>::new();
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn heterogeneous01() {
+  // insertions called by mobile element & structural variant callers
+  let me_insertion_calls = [me_insertion_call(1001, "AluY", 6)];
+  let sv_calls = [
+    sv_call(SVType::Insertion, ("chr1", 1030), ("chr1", 1030), 2),
+    deletion(1001, 3000, 4),
+  ];
+  let inputs: Vec<Vec<&dyn Mergeable>> = vec![
+    me_insertion_calls.iter().map(|call| call as &dyn Mergeable).collect(),
+    sv_calls.iter().map(|call| call as &dyn Mergeable).collect(),
+  ];
+
+  let merged_calls = CallMerger::new().merge(&inputs);
+  let produced = merged_calls
+    .iter()
+    .map(|merged_call| {
+      (merged_call.call.element_family(), merged_call.members.clone())
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(produced, vec![
+    (Some("AluY"), vec![(0, 0), (1, 0)]),
+    (None, vec![(1, 1)]),
+  ]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
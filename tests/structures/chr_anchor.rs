@@ -5,6 +5,8 @@ use genomic_structures::{
   Anchor,
   AnchorEnum,
   ChrAnchor,
+  DetectionConfig,
+  Position,
   CIGAR,
 };
 
@@ -19,24 +21,30 @@ macro_rules! test_chr_anchor {
     #[test]
     fn $function() {
       let loaded = ChrAnchor::load(
-        CIGAR::load($loaded_cigar, $loaded_position)
+        CIGAR::load($loaded_cigar, Position::OneBased($loaded_position))
           .expect("CIGAR loading failed!"),
         $loaded_chr.clone(),
         $loaded_flag,
         $loaded_mapq,
-        $loaded_position,
+        Position::OneBased($loaded_position),
         $loaded_tlen,
       );
 
       let manual = ChrAnchor {
-        anchor:   AnchorEnum::None,
-        cigar:    CIGAR::load($manual_cigar, $manual_position)
-          .expect("CIGAR loading failed!"),
-        chr:      $manual_chr.clone(),
-        flag:     $manual_flag,
-        position: $manual_position,
-        mapq:     $manual_mapq,
-        tlen:     $manual_tlen,
+        anchor:           AnchorEnum::None,
+        cigar:            CIGAR::load(
+          $manual_cigar,
+          Position::OneBased($manual_position),
+        )
+        .expect("CIGAR loading failed!"),
+        chr:              $manual_chr.clone(),
+        flag:             $manual_flag,
+        position:         Position::OneBased($manual_position),
+        mapq:             $manual_mapq,
+        tlen:             $manual_tlen,
+        alignment_score:  None,
+        suboptimal_score: None,
+        ambiguous:        false,
       };
 
       assert_eq!(
@@ -66,12 +74,13 @@ macro_rules! test_bin {
     #[test]
     fn $function() {
       let mut chr_anchor = ChrAnchor::new();
-      chr_anchor.position = $position;
-      let binned = chr_anchor.bin();
+      chr_anchor.position = Position::OneBased($position);
+      let binned = chr_anchor.bin(&DetectionConfig::default());
+      let expect = Position::OneBased($expect);
       assert_eq!(
-        binned, $expect,
+        binned, expect,
         "\n\nValue: {:?}.\nExpected: {:?}.\n\n",
-        binned, $expect,
+        binned, expect,
       );
     }
   };
@@ -1,7 +1,11 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // crate utilities
-use genomic_structures::CIGAR;
+use genomic_structures::{
+  ClipSideEnum,
+  Position,
+  CIGAR,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -14,7 +18,8 @@ macro_rules! test_cigar {
     #[test]
     fn $function() {
       let cigar =
-        CIGAR::load($cigar, $position).expect("CIGAR loading failed!");
+        CIGAR::load($cigar, Position::OneBased($position))
+          .expect("CIGAR loading failed!");
       assert_eq!(
         cigar, $expect,
         "\n\nCalculated CIGAR:\n{:#?}.\n\nExpected:\n{:#?}.\n\n",
@@ -24,6 +29,25 @@ macro_rules! test_cigar {
   };
 }
 
+// clip junction
+macro_rules! test_clip_junction {
+  ( $function: ident;
+    params |> $cigar: expr, $position: expr;
+    expect |> $left: expr, $right: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let cigar = CIGAR::load($cigar, Position::OneBased($position))
+        .expect("CIGAR loading failed!");
+      let junctions = (
+        cigar.clip_junction(ClipSideEnum::Left).map(|p| p.one_based()),
+        cigar.clip_junction(ClipSideEnum::Right).map(|p| p.one_based()),
+      );
+      assert_eq!(junctions, ($left, $right));
+    }
+  };
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // test
@@ -33,9 +57,9 @@ test_cigar!(test01;
     align: vec![100],
     deletion: vec![],
     insertion: vec![],
-    left_boundry: 101,
+    left_boundry: Position::OneBased(101),
     left_clip: 0,
-    right_boundry: 200,
+    right_boundry: Position::OneBased(200),
     rigth_clip: 0,
     signature: "100M".to_string(),
   };
@@ -47,9 +71,9 @@ test_cigar!(test02;
     align: vec![46],
     deletion: vec![],
     insertion: vec![],
-    left_boundry: 47,
+    left_boundry: Position::OneBased(47),
     left_clip: 54,
-    right_boundry: 146,
+    right_boundry: Position::OneBased(146),
     rigth_clip: 0,
     signature: "54H46M".to_string(),
   };
@@ -61,9 +85,9 @@ test_cigar!(test03;
     align: vec![46],
     deletion: vec![],
     insertion: vec![],
-    left_boundry: 47,
+    left_boundry: Position::OneBased(47),
     left_clip: 54,
-    right_boundry: 146,
+    right_boundry: Position::OneBased(146),
     rigth_clip: 0,
     signature: "54S46M".to_string(),
   };
@@ -75,9 +99,9 @@ test_cigar!(test04;
     align: vec![67],
     deletion: vec![],
     insertion: vec![],
-    left_boundry: 98,
+    left_boundry: Position::OneBased(98),
     left_clip: 3,
-    right_boundry: 197,
+    right_boundry: Position::OneBased(197),
     rigth_clip: 30,
     signature: "3H67M30H".to_string(),
   };
@@ -89,9 +113,9 @@ test_cigar!(test05;
     align: vec![3, 80],
     deletion: vec![],
     insertion: vec![2],
-    left_boundry: 91,
+    left_boundry: Position::OneBased(91),
     left_clip: 10,
-    right_boundry: 190,
+    right_boundry: Position::OneBased(190),
     rigth_clip: 5,
     signature: "10H3M2I80M5H".to_string(),
   };
@@ -103,9 +127,9 @@ test_cigar!(test06;
     align: vec![7],
     deletion: vec![60],
     insertion: vec![],
-    left_boundry: 88,
+    left_boundry: Position::OneBased(88),
     left_clip: 13,
-    right_boundry: 187,
+    right_boundry: Position::OneBased(187),
     rigth_clip: 20,
     signature: "13H60D7M20H".to_string(),
   };
@@ -117,23 +141,51 @@ test_cigar!(test07;
     align: vec![40],
     deletion: vec![4],
     insertion: vec![6],
-    left_boundry: 51,
+    left_boundry: Position::OneBased(51),
     left_clip: 50,
-    right_boundry: 150,
+    right_boundry: Position::OneBased(150),
     rigth_clip: 0,
     signature: "50S4D6I40M".to_string(),
   };
 );
 
+test_cigar!(test11; // sequence matches & mismatches
+  params |> "10S40=2X48M", 101;
+  expect |> CIGAR{
+    align: vec![40, 2, 48],
+    deletion: vec![],
+    insertion: vec![],
+    left_boundry: Position::OneBased(91),
+    left_clip: 10,
+    right_boundry: Position::OneBased(190),
+    rigth_clip: 0,
+    signature: "10S40=2X48M".to_string(),
+  };
+);
+
+test_cigar!(test12; // reference skip
+  params |> "20M100N80M", 101;
+  expect |> CIGAR{
+    align: vec![20, 80],
+    deletion: vec![100],
+    insertion: vec![],
+    left_boundry: Position::OneBased(101),
+    left_clip: 0,
+    right_boundry: Position::OneBased(300),
+    rigth_clip: 0,
+    signature: "20M100N80M".to_string(),
+  };
+);
+
 test_cigar!(test08;
   params |> "1H10D2M2D80M5H", 101;
   expect |> CIGAR{
     align: vec![2, 80],
     deletion: vec![10, 2],
     insertion: vec![],
-    left_boundry: 100,
+    left_boundry: Position::OneBased(100),
     left_clip: 1,
-    right_boundry: 199,
+    right_boundry: Position::OneBased(199),
     rigth_clip: 5,
     signature: "1H10D2M2D80M5H".to_string(),
   };
@@ -145,26 +197,43 @@ test_cigar!(test09;
     align: vec![2, 80],
     deletion: vec![10, 2],
     insertion: vec![],
-    left_boundry: 0,
+    left_boundry: Position::OneBased(0),
     left_clip: 1,
-    right_boundry: 99,
+    right_boundry: Position::OneBased(99),
     rigth_clip: 5,
     signature: "1H10D2M2D80M5H".to_string(),
   };
 );
 
-test_cigar!(test10;
+test_cigar!(test10; // left boundry clamped at coordinate origin
   params |> "50S4D6I40M", 1;
   expect |> CIGAR{
     align: vec![40],
     deletion: vec![4],
     insertion: vec![6],
-    left_boundry: -49,
+    left_boundry: Position::OneBased(0),
     left_clip: 50,
-    right_boundry: 50,
+    right_boundry: Position::OneBased(50),
     rigth_clip: 0,
     signature: "50S4D6I40M".to_string(),
   };
 );
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_clip_junction!(junction01;
+  params |> "10S80M10S", 101;
+  expect |> Some(101), Some(181);
+);
+
+test_clip_junction!(junction02; // insertion does not consume reference
+  params |> "10S40M2I48M10S", 101;
+  expect |> Some(101), Some(189);
+);
+
+test_clip_junction!(junction03; // deletion & skip consume reference
+  params |> "40M5D20M3N25M10S", 101;
+  expect |> None, Some(194);
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
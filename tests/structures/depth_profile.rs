@@ -0,0 +1,174 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  DepthProfile,
+  IndexedFasta,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// tiled reads over chromosome, skipping or doubling region
+fn tiled(
+  length: u64,
+  region: (u64, u64),
+  copies: usize,
+) -> DepthProfile {
+  let mut depth_profile = DepthProfile::new(100);
+  for start in (1..length).step_by(50) {
+    let end = start + 99;
+    let copies = if start >= region.0 && end <= region.1 { copies } else { 2 };
+    for _ in 0..copies {
+      depth_profile.add(
+        "chr1",
+        Position::OneBased(start),
+        Position::OneBased(end),
+      );
+    }
+  }
+  depth_profile
+}
+
+// write reference on temporary directory
+fn reference(
+  name: &str,
+  sequence: &str,
+) -> IndexedFasta {
+  let directory = std::env::temp_dir().join("genomic_structures_depth_profile");
+  fs::create_dir_all(&directory).expect("Directory creation failed!");
+  let path = directory.join(name);
+  fs::remove_file(format!("{}.fai", path.display())).ok();
+  fs::write(&path, format!(">chr1\n{}\n", sequence))
+    .expect("FASTA writing failed!");
+  IndexedFasta::open(&path).expect("FASTA indexing failed!")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// add
+macro_rules! test_add {
+  ( $function: ident;
+    params |> $spans: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut depth_profile = DepthProfile::new(10);
+      for (start, end) in $spans.iter() {
+        depth_profile.add(
+          "chr1",
+          Position::OneBased(*start),
+          Position::OneBased(*end),
+        );
+      }
+      let expect: Vec<f64> = $expect;
+      assert_eq!(depth_profile.bins.get("chr1"), Some(&expect));
+    }
+  };
+}
+
+// depth ratio
+macro_rules! test_ratio {
+  ( $function: ident;
+    params |> $region: expr, $copies: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let (start, end) = $region;
+      let depth_profile = tiled(6000, $region, $copies);
+      let produced = depth_profile
+        .depth_ratio(
+          "chr1",
+          Position::OneBased(start),
+          Position::OneBased(end),
+          1000,
+        )
+        .map(|ratio| (ratio * 100.).round() / 100.);
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+// GC correction
+macro_rules! test_gc {
+  ( $function: ident;
+    params |> $sequence: expr, $depths: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let reference = reference(stringify!($function), $sequence);
+      let mut depth_profile = DepthProfile::new(10);
+      depth_profile.bins.insert("chr1".to_string(), $depths);
+      depth_profile
+        .gc_correct(&reference)
+        .expect("GC correction failed!");
+      let expect: Vec<f64> = $expect;
+      assert_eq!(depth_profile.bins["chr1"], expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_add!(add01; // read within bin
+  params |> [(1, 10)];
+  expect |> vec![1.];
+);
+
+test_add!(add02; // read across bins
+  params |> [(6, 15), (16, 25)];
+  expect |> vec![0.5, 1., 0.5];
+);
+
+test_add!(add03; // unaligned span
+  params |> [(1, 10), (30, 21)];
+  expect |> vec![1.];
+);
+
+test_ratio!(ratio01; // diploid region
+  params |> (2001, 4000), 2;
+  expect |> Some(1.);
+);
+
+test_ratio!(ratio02; // heterozygous deletion
+  params |> (2001, 4000), 1;
+  expect |> Some(0.51);
+);
+
+test_ratio!(ratio03; // homozygous deletion
+  params |> (2001, 4000), 0;
+  expect |> Some(0.03);
+);
+
+test_ratio!(ratio04; // duplication
+  params |> (2001, 4000), 3;
+  expect |> Some(1.49);
+);
+
+test_ratio!(ratio05; // region beyond profile
+  params |> (8001, 9000), 2;
+  expect |> None;
+);
+
+test_gc!(gc01; // depth doubled on GC rich bins
+  params |> &format!("{}{}", "GC".repeat(10), "AT".repeat(10)),
+    vec![4., 4., 2., 2.];
+  expect |> vec![3., 3., 3., 3.];
+);
+
+test_gc!(gc02; // assembly gap unaltered
+  params |> &format!("{}{}{}", "GC".repeat(10), "N".repeat(10), "AT".repeat(5)),
+    vec![4., 4., 0., 2.];
+  expect |> vec![4., 4., 0., 4.];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,85 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::DetectionConfig;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+macro_rules! test_detection_config {
+  ( $function: ident;
+    params |> $toml: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let loaded = DetectionConfig::from_toml($toml)
+        .expect("DetectionConfig parsing failed!");
+      assert_eq!(
+        loaded, $expect,
+        "\n\nTOML:\n{}\n\nLoaded:\n{:#?}.\n\nExpected:\n{:#?}.\n\n",
+        $toml, loaded, $expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_detection_config!(test01; // defaults
+  params |> "";
+  expect |> DetectionConfig::default();
+);
+
+test_detection_config!(test02; // short reads & small elements
+  params |> "me_limit = 120\nanchor_limit = 30\nbreakpoint_flank = 15\n";
+  expect |> DetectionConfig {
+    me_limit: 120,
+    anchor_limit: 30,
+    breakpoint_flank: 15,
+    ..DetectionConfig::default()
+  };
+);
+
+test_detection_config!(test03;
+  params |> "bin_size = 200\nbin_overlap = 100\ntranslocation_distance = 5000\nmin_mapq = 30\n";
+  expect |> DetectionConfig {
+    bin_size: 200,
+    bin_overlap: 100,
+    translocation_distance: 5000,
+    min_mapq: 30,
+    ..DetectionConfig::default()
+  };
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fail
+macro_rules! test_detection_config_error {
+  ( $function: ident;
+    params |> $toml: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      assert!(DetectionConfig::from_toml($toml).is_err());
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_detection_config_error!(error01; // unknown field
+  params |> "me_limits = 120\n";
+);
+
+test_detection_config_error!(error02; // wrong type
+  params |> "me_limit = \"120\"\n";
+);
+
+test_detection_config_error!(error03; // negative flank
+  params |> "breakpoint_flank = -1\n";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,180 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  FeatureEnum,
+  FeatureIndex,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// forward gene without gene record, UTR within first exon
+const GTF: &str = "#!genome-build test
+chr1\tsrc\texon\t10001\t10500\t.\t+\t.\tgene_id \"G1\"; transcript_id \"T1\"; gene_name \"ALPHA\";
+chr1\tsrc\tfive_prime_utr\t10001\t10100\t.\t+\t.\tgene_id \"G1\"; transcript_id \"T1\"; gene_name \"ALPHA\";
+chr1\tsrc\texon\t12001\t13000\t.\t+\t.\tgene_id \"G1\"; transcript_id \"T1\"; gene_name \"ALPHA\";
+chr1\tsrc\texon\t30001\t31000\t.\t+\t.\tgene_id \"G2\"; transcript_id \"T2\";
+";
+
+// reverse gene, exons linked through transcript
+const GFF3: &str = "##gff-version 3
+chr1\tsrc\tregion\t1\t100000\t.\t.\t.\tID=chr1:1..100000
+chr2\tsrc\tgene\t50001\t60000\t.\t-\t.\tID=gene:G3;Name=GAMMA;gene_id=G3
+chr2\tsrc\tmRNA\t50001\t60000\t.\t-\t.\tID=transcript:T3;Parent=gene:G3
+chr2\tsrc\texon\t50001\t51000\t.\t-\t.\tParent=transcript:T3
+chr2\tsrc\tthree_prime_UTR\t50001\t50200\t.\t-\t.\tParent=transcript:T3
+chr2\tsrc\texon\t59001\t60000\t.\t-\t.\tParent=transcript:T3
+##FASTA
+>chr2
+ACGT
+";
+
+// distinct loci sharing gene name
+const LOCI: &str = "chr1\tsrc\tgene\t10001\t10100\t.\t+\t.\tgene_id \"G4\"; gene_name \"Y_RNA\";
+chr1\tsrc\texon\t10001\t10100\t.\t+\t.\tgene_id \"G4\"; gene_name \"Y_RNA\";
+chr1\tsrc\texon\t90001\t90100\t.\t+\t.\tgene_id \"G5\"; gene_name \"Y_RNA\";
+chr3\tsrc\tgene\t1001\t1100\t.\t+\t.\tID=gene:G6;Name=Y_RNA
+chr3\tsrc\texon\t1001\t1100\t.\t+\t.\tParent=gene:G6
+chr3\tsrc\tgene\t80001\t80100\t.\t+\t.\tID=gene:G7;Name=Y_RNA
+chr3\tsrc\texon\t80001\t80100\t.\t+\t.\tParent=gene:G7
+";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// annotate
+macro_rules! test_annotate {
+  ( $function: ident;
+    params |> $annotation: expr, $chr: expr, $start: expr, $end: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let feature_index = FeatureIndex::load($annotation.as_bytes())
+        .expect("Annotation loading failed!");
+      let produced = feature_index
+        .annotate($chr, Position::OneBased($start), Position::OneBased($end))
+        .map(|annotation| {
+          (annotation.gene, annotation.feature, annotation.distance)
+        });
+      let expect: Option<(&str, FeatureEnum, u64)> = $expect;
+      assert_eq!(
+        produced,
+        expect.map(|(gene, feature, distance)| {
+          (gene.to_string(), feature, distance)
+        })
+      );
+    }
+  };
+}
+
+// malformed
+macro_rules! test_error {
+  ( $function: ident;
+    params |> $annotation: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      assert!(FeatureIndex::load($annotation.as_bytes()).is_err());
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_annotate!(gtf01; // UTR preferred over exon
+  params |> GTF, "chr1", 10050, 10050;
+  expect |> Some(("ALPHA", FeatureEnum::UTR, 0));
+);
+
+test_annotate!(gtf02; // exon
+  params |> GTF, "chr1", 12500, 12500;
+  expect |> Some(("ALPHA", FeatureEnum::Exon, 0));
+);
+
+test_annotate!(gtf03; // intron between exons
+  params |> GTF, "chr1", 11000, 11000;
+  expect |> Some(("ALPHA", FeatureEnum::Intron, 0));
+);
+
+test_annotate!(gtf04; // deletion spanning intron & exon
+  params |> GTF, "chr1", 11000, 12100;
+  expect |> Some(("ALPHA", FeatureEnum::Exon, 0));
+);
+
+test_annotate!(gtf05; // upstream of forward gene
+  params |> GTF, "chr1", 9000, 9000;
+  expect |> Some(("ALPHA", FeatureEnum::Promoter, 1001));
+);
+
+test_annotate!(gtf06; // downstream of forward gene
+  params |> GTF, "chr1", 20000, 20000;
+  expect |> Some(("ALPHA", FeatureEnum::Intergenic, 7000));
+);
+
+test_annotate!(gtf07; // chromosome without genes
+  params |> GTF, "chr2", 1000, 1000;
+  expect |> None;
+);
+
+test_annotate!(gtf08; // unnamed gene labeled by ID
+  params |> GTF, "chr1", 30500, 30500;
+  expect |> Some(("G2", FeatureEnum::Exon, 0));
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_annotate!(gff01; // exon through transcript parent
+  params |> GFF3, "chr2", 59500, 59500;
+  expect |> Some(("GAMMA", FeatureEnum::Exon, 0));
+);
+
+test_annotate!(gff02; // UTR through transcript parent
+  params |> GFF3, "chr2", 50100, 50100;
+  expect |> Some(("GAMMA", FeatureEnum::UTR, 0));
+);
+
+test_annotate!(gff03; // intron
+  params |> GFF3, "chr2", 55000, 55000;
+  expect |> Some(("GAMMA", FeatureEnum::Intron, 0));
+);
+
+test_annotate!(gff04; // upstream of reverse gene
+  params |> GFF3, "chr2", 61000, 61000;
+  expect |> Some(("GAMMA", FeatureEnum::Promoter, 1000));
+);
+
+test_annotate!(gff05; // downstream of reverse gene
+  params |> GFF3, "chr2", 49000, 49000;
+  expect |> Some(("GAMMA", FeatureEnum::Intergenic, 1001));
+);
+
+test_annotate!(gff06; // region records are not genes
+  params |> GFF3, "chr1", 5000, 5000;
+  expect |> None;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_annotate!(loci01; // GTF loci sharing name kept apart
+  params |> LOCI, "chr1", 50000, 50000;
+  expect |> Some(("Y_RNA", FeatureEnum::Intergenic, 39900));
+);
+
+test_annotate!(loci02; // GFF3 loci sharing name kept apart
+  params |> LOCI, "chr3", 40000, 40000;
+  expect |> Some(("Y_RNA", FeatureEnum::Intergenic, 38900));
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_error!(error01; // missing columns
+  params |> "chr1\tsrc\texon\t100\t200\n";
+);
+
+test_error!(error02; // non numeric coordinate
+  params |> "chr1\tsrc\texon\tstart\t200\t.\t+\t.\tgene_id \"G1\";\n";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,183 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use flate2::write::DeflateEncoder;
+use flate2::{
+  Compression,
+  Crc,
+};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  IndexedFasta,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// reference with uneven last lines
+const FASTA: &str =
+  ">chr1 test\nACGTACGTAC\nGGGGGCCCCC\nTTAA\n>chr2\nAAAAACCCCC\nG\n";
+
+// BGZF block
+fn bgzf_block(data: &[u8]) -> Vec<u8> {
+  let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+  encoder.write_all(data).expect("Compression failed!");
+  let compressed = encoder.finish().expect("Compression failed!");
+  let mut crc = Crc::new();
+  crc.update(data);
+
+  let block_size = (compressed.len() + 25) as u16;
+  let mut block =
+    vec![31, 139, 8, 4, 0, 0, 0, 0, 0, 255, 6, 0, b'B', b'C', 2, 0];
+  block.extend(block_size.to_le_bytes().iter());
+  block.extend(compressed);
+  block.extend(crc.sum().to_le_bytes().iter());
+  block.extend((data.len() as u32).to_le_bytes().iter());
+  block
+}
+
+// write reference on temporary directory, BGZF on 8 byte blocks
+fn reference(
+  name: &str,
+  compressed: bool,
+) -> PathBuf {
+  let directory =
+    std::env::temp_dir().join("genomic_structures_indexed_fasta");
+  fs::create_dir_all(&directory).expect("Directory creation failed!");
+  let path = directory.join(name);
+  for extension in ["fai", "gzi"] {
+    fs::remove_file(format!("{}.{}", path.display(), extension)).ok();
+  }
+  let bytes = if compressed {
+    let mut bytes = Vec::new();
+    for chunk in FASTA.as_bytes().chunks(8) {
+      bytes.extend(bgzf_block(chunk));
+    }
+    bytes.extend(bgzf_block(&[]));
+    bytes
+  } else {
+    FASTA.as_bytes().to_vec()
+  };
+  fs::write(&path, bytes).expect("Reference writing failed!");
+  path
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fetch
+macro_rules! test_fetch {
+  ( $function: ident;
+    params |> $compressed: expr, $index: expr, $region: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let name = format!(
+        "{}.{}",
+        stringify!($function),
+        if $compressed { "fa.gz" } else { "fa" }
+      );
+      let path = reference(&name, $compressed);
+      let indexed_fasta = if $index {
+        IndexedFasta::index(&path).expect("Indexing failed!");
+        IndexedFasta::open(&path).expect("Opening failed!")
+      } else {
+        IndexedFasta::open(&path).expect("Opening failed!")
+      };
+      let produced =
+        indexed_fasta.fetch_region($region).expect("Fetching failed!");
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_fetch!(fetch01; // across lines
+  params |> false, false, "chr1:8-13";
+  expect |> "TACGGG";
+);
+
+test_fetch!(fetch02; // last base
+  params |> false, true, "chr1:24-24";
+  expect |> "A";
+);
+
+test_fetch!(fetch03; // whole sequence
+  params |> false, true, "chr2";
+  expect |> "AAAAACCCCCG";
+);
+
+test_fetch!(fetch04; // across BGZF blocks
+  params |> true, false, "chr1:8-13";
+  expect |> "TACGGG";
+);
+
+test_fetch!(fetch05; // .gzi & .fai loaded from disk
+  params |> true, true, "chr2:5-11";
+  expect |> "ACCCCCG";
+);
+
+test_fetch!(fetch06; // thousands separator
+  params |> true, true, "chr1:1-1,0";
+  expect |> "ACGTACGTAC";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// index
+#[test]
+fn index01() {
+  let path = reference("index01.fa.gz", true);
+  let indexed_fasta = IndexedFasta::index(&path).expect("Indexing failed!");
+  let fai = fs::read_to_string(path.with_extension("gz.fai"))
+    .expect("Index reading failed!");
+  assert_eq!(fai, "chr1\t24\t11\t10\t11\nchr2\t11\t44\t10\t11\n");
+  assert!(path.with_extension("gz.gzi").exists());
+
+  let lengths = indexed_fasta.contig_lengths();
+  assert_eq!(lengths["chr1"], 24.);
+  assert_eq!(lengths["chr2"], 11.);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fail
+macro_rules! test_fetch_error {
+  ( $function: ident;
+    params |> $chr: expr, $start: expr, $end: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let path = reference(&format!("{}.fa", stringify!($function)), false);
+      let indexed_fasta = IndexedFasta::open(&path).expect("Opening failed!");
+      assert!(indexed_fasta
+        .fetch($chr, Position::OneBased($start), Position::OneBased($end))
+        .is_err());
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_fetch_error!(error01; // unknown sequence
+  params |> "chr3", 1, 2;
+);
+
+test_fetch_error!(error02; // beyond sequence end
+  params |> "chr2", 10, 12;
+);
+
+test_fetch_error!(error03; // 0-based start
+  params |> "chr1", 0, 2;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,255 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  GenomicInterval,
+  IntervalIndex,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// intervals labeled by insertion order, nested, adjacent & long spanning
+const INTERVALS: [(&str, u64, u64); 8] = [
+  ("chr1", 100, 200),
+  ("chr1", 150, 160),
+  ("chr1", 201, 300),
+  ("chr1", 1, 10000),
+  ("chr1", 5000, 5100),
+  ("chr1", 12000, 12500),
+  ("chr2", 500, 600),
+  ("chr2", 700, 650),
+];
+
+fn interval_index() -> IntervalIndex<usize> {
+  let mut interval_index = IntervalIndex::new();
+  for (ix, (chr, start, end)) in INTERVALS.iter().enumerate() {
+    interval_index.insert(
+      GenomicInterval::new(
+        chr.to_string(),
+        Position::OneBased(*start),
+        Position::OneBased(*end),
+      ),
+      ix,
+    );
+  }
+  interval_index.index();
+  interval_index
+}
+
+// pseudo-random intervals from linear congruential generator
+fn random_intervals(count: usize) -> Vec<(u64, u64)> {
+  let mut state: u64 = 42;
+  let mut next = |modulo: u64| {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+    (state >> 33) % modulo
+  };
+  (0..count)
+    .map(|_| {
+      let start = next(100000) + 1;
+      (start, start + next(2000))
+    })
+    .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// overlap
+macro_rules! test_overlap {
+  ( $function: ident;
+    params |> $chr: expr, $start: expr, $end: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let produced = interval_index()
+        .overlaps($chr, Position::OneBased($start), Position::OneBased($end))
+        .iter()
+        .map(|(_, value)| *value)
+        .collect::<Vec<usize>>();
+      let expect: Vec<usize> = $expect;
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+// window
+macro_rules! test_window {
+  ( $function: ident;
+    params |> $chr: expr, $start: expr, $end: expr, $flank: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let produced = interval_index()
+        .window(
+          $chr,
+          Position::OneBased($start),
+          Position::OneBased($end),
+          $flank,
+        )
+        .iter()
+        .map(|(_, value)| *value)
+        .collect::<Vec<usize>>();
+      let expect: Vec<usize> = $expect;
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+// nearest
+macro_rules! test_nearest {
+  ( $function: ident;
+    params |> $chr: expr, $start: expr, $end: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let interval_index = interval_index();
+      let produced = interval_index
+        .nearest($chr, Position::OneBased($start), Position::OneBased($end))
+        .map(|(_, value)| *value);
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+// exhaustive comparison against linear scan
+macro_rules! test_scan {
+  ( $function: ident;
+    params |> $count: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let intervals = random_intervals($count);
+      let mut interval_index = IntervalIndex::new();
+      for (ix, (start, end)) in intervals.iter().enumerate() {
+        interval_index.insert(
+          GenomicInterval::new(
+            "chr1".to_string(),
+            Position::OneBased(*start),
+            Position::OneBased(*end),
+          ),
+          ix,
+        );
+      }
+      interval_index.index();
+      assert_eq!(interval_index.len(), $count);
+
+      for (start, end) in random_intervals(200).iter() {
+        let mut produced = interval_index
+          .overlaps(
+            "chr1",
+            Position::OneBased(*start),
+            Position::OneBased(*end),
+          )
+          .iter()
+          .map(|(_, value)| *value)
+          .collect::<Vec<usize>>();
+        produced.sort();
+        let expect = intervals
+          .iter()
+          .enumerate()
+          .filter(|(_, (other_start, other_end))| {
+            other_start <= end && other_end >= start
+          })
+          .map(|(ix, _)| ix)
+          .collect::<Vec<usize>>();
+        assert_eq!(produced, expect);
+      }
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_overlap!(overlap01; // nested intervals sorted by start
+  params |> "chr1", 155, 155;
+  expect |> vec![3, 0, 1];
+);
+
+test_overlap!(overlap02; // adjacent intervals, boundaries inclusive
+  params |> "chr1", 200, 201;
+  expect |> vec![3, 0, 2];
+);
+
+test_overlap!(overlap03; // long interval starting far upstream
+  params |> "chr1", 9000, 11000;
+  expect |> vec![3];
+);
+
+test_overlap!(overlap04; // between intervals
+  params |> "chr1", 10001, 11999;
+  expect |> vec![];
+);
+
+test_overlap!(overlap05; // empty interval discarded
+  params |> "chr2", 1, 1000;
+  expect |> vec![6];
+);
+
+test_overlap!(overlap06; // chromosome not indexed
+  params |> "chrX", 1, 1000;
+  expect |> vec![];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_window!(window01; // flank reaches intervals on both sides
+  params |> "chr1", 11000, 11000, 1000;
+  expect |> vec![3, 5];
+);
+
+test_window!(window02; // flank short of both intervals
+  params |> "chr1", 11000, 11000, 999;
+  expect |> vec![];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_nearest!(nearest01; // leftmost overlapping interval
+  params |> "chr1", 5050, 5060;
+  expect |> Some(3);
+);
+
+test_nearest!(nearest02; // closer downstream interval
+  params |> "chr1", 11500, 11600;
+  expect |> Some(5);
+);
+
+test_nearest!(nearest03; // closer upstream interval
+  params |> "chr1", 10100, 10200;
+  expect |> Some(3);
+);
+
+test_nearest!(nearest04; // upstream preferred on ties
+  params |> "chr1", 11000, 11000;
+  expect |> Some(3);
+);
+
+test_nearest!(nearest05; // upstream only
+  params |> "chr1", 20000, 20000;
+  expect |> Some(5);
+);
+
+test_nearest!(nearest06; // chromosome not indexed
+  params |> "chrX", 1, 1000;
+  expect |> None;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_scan!(scan01; // single level
+  params |> 7;
+);
+
+test_scan!(scan02; // incomplete tree
+  params |> 1000;
+);
+
+test_scan!(scan03; // complete tree
+  params |> 1023;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
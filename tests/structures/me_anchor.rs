@@ -3,8 +3,11 @@
 // crate utilities
 use genomic_structures::{
   BreakPoint,
+  ClipSideEnum,
+  DetectionConfig,
   MEAnchor,
   OrientationEnum,
+  Position,
   TagME,
   CIGAR,
 };
@@ -20,23 +23,26 @@ macro_rules! test_me_anchor {
     #[test]
     fn $function() {
       let loaded = MEAnchor::load(
-        CIGAR::load($loaded_cigar, $loaded_position)
+        CIGAR::load($loaded_cigar, Position::OneBased($loaded_position))
           .expect("CIGAR loading failed!"),
         $loaded_flag,
         $loaded_mobel.clone(),
         $loaded_orientation,
-        $loaded_position,
+        Position::OneBased($loaded_position),
         $loaded_size,
       );
 
       let manual = MEAnchor {
         breakpoint:  BreakPoint::new(),
-        cigar:       CIGAR::load($manual_cigar, $manual_position)
-          .expect("CIGAR loading failed!"),
+        cigar:       CIGAR::load(
+          $manual_cigar,
+          Position::OneBased($manual_position),
+        )
+        .expect("CIGAR loading failed!"),
         flag:        $manual_flag,
         mobel:       $manual_mobel.clone(),
         orientation: $manual_orientation,
-        position:    $manual_position,
+        position:    Position::OneBased($manual_position),
         size:        $manual_size,
       };
 
@@ -63,25 +69,28 @@ test_me_anchor!(test01;
 macro_rules! test_calculate_break_point {
   ( $function: ident;
     loaded |> $loaded_sequence: expr, $loaded_cigar: expr, $loaded_flag: expr, $loaded_mobel: expr, $loaded_orientation: expr, $loaded_position: expr, $loaded_size: expr;
-    manual |> $manual_sequence: expr, $manual_coordinate: expr;
+    manual |> $manual_sequence: expr, $manual_coordinate: expr, $manual_side: expr;
   ) => {
     #[test]
     fn $function() {
       let mut loaded = MEAnchor::load(
-        CIGAR::load($loaded_cigar, $loaded_position)
+        CIGAR::load($loaded_cigar, Position::OneBased($loaded_position))
           .expect("CIGAR loading failed!"),
         $loaded_flag,
         $loaded_mobel.clone(),
         $loaded_orientation,
-        $loaded_position,
+        Position::OneBased($loaded_position),
         $loaded_size,
       );
-      loaded.calculate_break_point($loaded_sequence);
+      loaded
+        .calculate_break_point($loaded_sequence, &DetectionConfig::default())
+        .expect("BreakPoint calculation failed!");
 
       let mut manual = MEAnchor::new();
       manual.breakpoint = BreakPoint {
         sequence:   $manual_sequence,
-        coordinate: $manual_coordinate,
+        coordinate: Position::OneBased($manual_coordinate),
+        side:       $manual_side,
       };
 
       assert_eq!(
@@ -98,17 +107,17 @@ macro_rules! test_calculate_break_point {
 // test
 test_calculate_break_point!(bp01;
   loaded |> "MMMM0987654321B1234567890OOOOO", "15S15M", 83, "mobel77".to_string(), OrientationEnum::None, 1, 11000.;
-  manual |> "MMMM0987654321B1234567890".to_string(), 15.;
+  manual |> "MMMM0987654321B1234567890".to_string(), 15, ClipSideEnum::Left;
 );
 
 test_calculate_break_point!(bp02;
   loaded |> "B1234567890OOOOO", "1S15M", 83, "mobel77".to_string(), OrientationEnum::None, 1, 11000.;
-  manual |> "B1234567890".to_string(), 1.;
+  manual |> "B1234567890".to_string(), 1, ClipSideEnum::Left;
 );
 
 test_calculate_break_point!(bp03;
   loaded |> "OOOOO0987654321B", "30M1S", 75, "mobel77".to_string(), OrientationEnum::None, 10971, 11000.;
-  manual |> "0987654321B".to_string(), 0.;
+  manual |> "0987654321B".to_string(), 11, ClipSideEnum::Right;
 );
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -122,15 +131,15 @@ macro_rules! test_tag {
     #[test]
     fn $function() {
       let mut loaded = MEAnchor::load(
-        CIGAR::load($loaded_cigar, $loaded_position)
+        CIGAR::load($loaded_cigar, Position::OneBased($loaded_position))
           .expect("CIGAR loading failed!"),
         $loaded_flag,
         $loaded_mobel.clone(),
         $loaded_orientation,
-        $loaded_position,
+        Position::OneBased($loaded_position),
         $loaded_size,
       );
-      loaded.tag();
+      loaded.tag(&DetectionConfig::default());
 
       let mut manual = MEAnchor::new();
       manual.orientation = $manual_orientation;
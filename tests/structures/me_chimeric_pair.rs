@@ -2,10 +2,18 @@
 
 // crate utilities
 use genomic_structures::{
+  AmbiguityEnum,
+  AnchorResolution,
+  ChrAnchor,
   ChrAnchorEnum,
+  DetectionConfig,
   MEAnchor,
   MEChimericPair,
   OrientationEnum,
+  OriginEnum,
+  PolyTail,
+  RawValues,
+  ResolutionStrategyEnum,
 };
 
 // crate utilities
@@ -14,23 +22,107 @@ use crate::load_me_anchor;
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // load & update
+macro_rules! test_update {
+  ( $function: ident;
+    params |> $($flag: expr, $scaffold: expr, $sequence: expr => $origin: tt),+;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_chimeric_pair = MEChimericPair::new();
+      let loaded = vec![$(
+        me_chimeric_pair.update(
+          &RawValues::load(vec![
+            "ID", $flag, $scaffold, "1001", "60", "10M", "", "", "0", $sequence, "",
+          ])
+          .expect("RawValues loading failed!"),
+          OriginEnum::$origin,
+          &DetectionConfig::default(),
+        ),
+      )+];
+      let counts = (
+        loaded,
+        me_chimeric_pair.read1.me_read.len(),
+        me_chimeric_pair.read1.chr_read.len(),
+        me_chimeric_pair.read2.me_read.len(),
+        me_chimeric_pair.read2.chr_read.len(),
+      );
+      assert_eq!(
+        counts, $expect,
+        "\n\nLoaded & anchor counts: {:?}.\nExpected: {:?}.\n\n",
+        counts, $expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_update!(update01; // first in pair
+  params |> "65", "mobel77", "GATTACAGAT" => MobileElement,
+    "65", "chr1", "GATTACAGAT" => Chromosomal;
+  expect |> (vec![true, true], 1, 1, 0, 0);
+);
+
+test_update!(update02; // second in pair
+  params |> "129", "mobel77", "GATTACAGAT" => MobileElement,
+    "129", "chr1", "GATTACAGAT" => Chromosomal;
+  expect |> (vec![true, true], 0, 0, 1, 1);
+);
+
+test_update!(update03; // reverse complement
+  params |> "65", "mobel77", "GATTACAGAT" => MobileElement,
+    "81", "chr1", "ATCTGTAATC" => Chromosomal;
+  expect |> (vec![true, true], 1, 1, 0, 0);
+);
+
+test_update!(update04; // secondary alignment keeps primary sequence
+  params |> "65", "mobel77", "GATTACAGAT" => MobileElement,
+    "321", "mobel77", "*" => MobileElement,
+    "65", "chr1", "GATTACAGAT" => Chromosomal;
+  expect |> (vec![true, true, true], 2, 1, 0, 0);
+);
+
+// fail
+test_update!(fail01; // sequence mismatch
+  params |> "65", "mobel77", "GATTACAGAT" => MobileElement,
+    "65", "chr1", "CCCCCCCCCC" => Chromosomal;
+  expect |> (vec![true, false], 1, 0, 0, 0);
+);
+
+test_update!(fail02; // mate slot
+  params |> "65", "mobel77", "GATTACAGAT" => MobileElement,
+    "129", "chr1", "GATTACAGAT" => Chromosomal;
+  expect |> (vec![true, false], 1, 0, 0, 0);
+);
+
+test_update!(fail03;
+  params |> "65", "mobel77", "GATTACAGAT" => None;
+  expect |> (vec![false], 0, 0, 0, 0);
+);
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // get
 macro_rules! test_get_chr_anchor {
   ( $function: ident;
-    $sequence: expr, $expect: expr
+    $sequence: expr, $chranch: tt, $expect: expr
   ) => {
     #[test]
     fn $function() {
       let mut me_chimeric_pair = MEChimericPair::new();
-      me_chimeric_pair.read1.sequence = $sequence;
-      let retrieved = me_chimeric_pair.get_chr_anchor();
+      me_chimeric_pair.read1.sequence = $sequence.to_string();
+      me_chimeric_pair.read2.sequence = "CCCCCCC".to_string();
+      me_chimeric_pair.chranch = ChrAnchorEnum::$chranch;
+      let retrieved = me_chimeric_pair
+        .get_chr_anchor()
+        .map(|me_chimeric_read| me_chimeric_read.sequence.as_str());
+      let expect: Option<&str> = $expect;
       assert_eq!(
-        retrieved.sequence, $expect,
+        retrieved, expect,
         "\n\nInput sequence: {:?}.\nRetrieved sequence: {:?}.\nExpected: {:?}.\n\n",
-        $sequence, retrieved.sequence, $expect,
+        $sequence, retrieved, expect,
       );
     }
   };
@@ -39,10 +131,10 @@ macro_rules! test_get_chr_anchor {
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // test
-test_get_chr_anchor!(get01; "AAAAAAA".to_string(), "AAAAAAA".to_string());
-test_get_chr_anchor!(get02; "MACTHAA".to_string(), "MACTHAA".to_string());
-test_get_chr_anchor!(get03; "CAAGAAC".to_string(), "CAAGAAC".to_string());
-test_get_chr_anchor!(get04; "GATTACA".to_string(), "GATTACA".to_string());
+test_get_chr_anchor!(get01; "AAAAAAA", Read1, Some("AAAAAAA"));
+test_get_chr_anchor!(get02; "MACTHAA", Read1, Some("MACTHAA"));
+test_get_chr_anchor!(get03; "CAAGAAC", Read2, Some("CCCCCCC"));
+test_get_chr_anchor!(get04; "GATTACA", None, None);
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -53,6 +145,18 @@ macro_rules! test_tag {
     expect |> $expect: tt;
     vargs ... $($variadic_position1: expr => $variadic_orientation1: tt),+;
     vargs ... $($variadic_position2: expr => $variadic_orientation2: tt),+;
+  ) => {
+    test_tag!($function;
+      expect |> $expect, None;
+      vargs ... $($variadic_position1 => $variadic_orientation1),+;
+      vargs ... $($variadic_position2 => $variadic_orientation2),+;
+    );
+  };
+
+  ( $function: ident;
+    expect |> $expect: tt, $ambiguity: tt;
+    vargs ... $($variadic_position1: expr => $variadic_orientation1: tt),+;
+    vargs ... $($variadic_position2: expr => $variadic_orientation2: tt),+;
   ) => {
     #[test]
     fn $function() {
@@ -65,6 +169,7 @@ macro_rules! test_tag {
       me_chimeric_pair.tag();
       // assert
       assert_eq!(me_chimeric_pair.chranch, ChrAnchorEnum::$expect);
+      assert_eq!(me_chimeric_pair.ambiguity, AmbiguityEnum::$ambiguity);
     }
   };
 }
@@ -73,32 +178,32 @@ macro_rules! test_tag {
 
 // fail
 test_tag!(tag00; // none
-  expect |> None;
+  expect |> None, Unoriented;
   vargs ... 0 => None, 0 => None;
   vargs ... 0 => None, 0 => None;
 );
 
 test_tag!(tag01; // ambigous ends
-  expect |> None;
+  expect |> None, BothMobileElement;
   vargs ... 50 => Upstream, 75 => Upstream;
   vargs ... 1000 => Downstream;
 );
 
 test_tag!(tag02; // palindromic reads
-  expect |> None;
+  expect |> None, Palindromic;
   vargs ... 0 => None, 75 => Upstream;
   vargs ... 1050 => Downstream, 75 => Upstream;
 );
 
 test_tag!(tag03; // palindromic reads
-  expect |> None;
+  expect |> None, Palindromic;
   vargs ... 50 => Downstream, 75 => Upstream;
   vargs ... 50 => Upstream, 75 => Upstream;
 );
 
 // test upstream
 test_tag!(tag10; // upstream same position both reads
-  expect |> None;
+  expect |> None, EqualEdges;
   vargs ... 100 => Upstream, 50 => Upstream;
   vargs ... 50 => Upstream, 75 => Upstream;
 );
@@ -141,7 +246,7 @@ test_tag!(tag16;
 
 // test downstream
 test_tag!(tag20; // downstream same position both reads
-  expect |> None;
+  expect |> None, EqualEdges;
   vargs ... 10100 => Downstream, 10050 => Downstream;
   vargs ... 10100 => Downstream, 10075 => Downstream;
 );
@@ -182,4 +287,90 @@ test_tag!(tag26;
   vargs ... 10150 => Downstream, 1075 => Downstream, 1075 => Downstream;
 );
 
+// test tail
+#[test]
+fn tag_tail01() { // tail-only read anchored by chromosomal mate
+  let mut me_chimeric_pair = MEChimericPair::new();
+  me_chimeric_pair.read1.tail = Some(PolyTail::new());
+  me_chimeric_pair.tag();
+  assert_eq!(me_chimeric_pair.chranch, ChrAnchorEnum::Read2);
+  assert_eq!(me_chimeric_pair.read1.orientation, OrientationEnum::Downstream);
+}
+
+#[test]
+fn tag_tail02() { // tail-only read anchoring mobile element mate
+  let mut me_chimeric_pair = MEChimericPair::new();
+  me_chimeric_pair.read1.tail = Some(PolyTail::new());
+  load_me_anchor!(me_chimeric_pair.read2, 10150, Downstream);
+  me_chimeric_pair.tag();
+  assert_eq!(me_chimeric_pair.chranch, ChrAnchorEnum::Read1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// resolve
+macro_rules! test_resolve {
+  ( $function: ident;
+    params |> $strategy: tt, $mapq1: expr, $mapq2: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_chimeric_pair = MEChimericPair::new();
+      // palindromic read1 & unoriented read2
+      load_me_anchor!(me_chimeric_pair.read1, 50, Upstream);
+      load_me_anchor!(me_chimeric_pair.read1, 1000, Downstream);
+      for (me_chimeric_read, mapq) in [
+        (&mut me_chimeric_pair.read1, $mapq1),
+        (&mut me_chimeric_pair.read2, $mapq2),
+      ] {
+        if let Some(mapq) = mapq {
+          let mut chr_anchor = ChrAnchor::new();
+          chr_anchor.mapq = mapq;
+          me_chimeric_read.chr_read.push(chr_anchor);
+          me_chimeric_read.select_chr_anchor(20);
+        }
+      }
+      me_chimeric_pair.tag();
+      let resolution =
+        me_chimeric_pair.resolve(ResolutionStrategyEnum::$strategy);
+      assert_eq!(resolution, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_resolve!(resolve01; // exclude
+  params |> Exclude, Some(60), Some(0);
+  expect |> AnchorResolution::Ambiguous(AmbiguityEnum::Palindromic);
+);
+
+test_resolve!(resolve02; // unique mate mapping
+  params |> MateMapping, Some(60), Some(0);
+  expect |> AnchorResolution::Resolved(ChrAnchorEnum::Read1);
+);
+
+test_resolve!(resolve03; // higher mate mapping
+  params |> MateMapping, Some(30), Some(60);
+  expect |> AnchorResolution::Resolved(ChrAnchorEnum::Read2);
+);
+
+test_resolve!(resolve04; // unmapped mate
+  params |> MateMapping, None::<i32>, Some(60);
+  expect |> AnchorResolution::Resolved(ChrAnchorEnum::Read2);
+);
+
+// fail
+test_resolve!(fail04; // indistinguishable mates
+  params |> MateMapping, Some(60), Some(60);
+  expect |> AnchorResolution::Ambiguous(AmbiguityEnum::Palindromic);
+);
+
+test_resolve!(fail05; // ambiguous mates
+  params |> MateMapping, Some(0), Some(0);
+  expect |> AnchorResolution::Ambiguous(AmbiguityEnum::Palindromic);
+);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
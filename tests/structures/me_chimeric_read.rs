@@ -2,10 +2,12 @@
 
 // crate utilities
 use genomic_structures::{
+  ChrAnchor,
   MEAnchor,
   MEChimericRead,
   OrientationEnum,
   Sequence,
+  CIGAR,
 };
 
 // crate utilities
@@ -159,6 +161,79 @@ test_edge!(edge05;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// select chromosomal anchor
+macro_rules! test_select_chr_anchor {
+  ( $function: ident;
+    expect |> $expect_chr: expr, $expect_quality: expr, $expect_ambiguous: expr;
+    vargs ... $($chr: expr => $mapq: expr, $score: expr, $suboptimal: expr, $cigar: expr),+;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_chimeric_read = MEChimericRead::new();
+      // variadic loading
+      $(
+        let mut chr_anchor = ChrAnchor::new();
+        chr_anchor.chr = $chr.to_string();
+        chr_anchor.mapq = $mapq;
+        chr_anchor.alignment_score = $score;
+        chr_anchor.suboptimal_score = $suboptimal;
+        chr_anchor.cigar = CIGAR::load($cigar, chr_anchor.position)
+          .expect("CIGAR loading failed!");
+        me_chimeric_read.chr_read.push(chr_anchor);
+      )+
+      // select
+      me_chimeric_read.select_chr_anchor(20);
+      let chosen = me_chimeric_read.chr_anchor().expect("No anchor found!");
+      // assert
+      assert_eq!(
+        (chosen.chr.as_str(), me_chimeric_read.quality, chosen.ambiguous),
+        ($expect_chr, $expect_quality, $expect_ambiguous),
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_select_chr_anchor!(select01; // mapping quality
+  expect |> "chr2", 60, false;
+  vargs ... "chr1" => 10, Some(100), None, "100M", "chr2" => 60, Some(90), None, "100M";
+);
+
+test_select_chr_anchor!(select02; // score difference
+  expect |> "chr2", 60, false;
+  vargs ... "chr1" => 60, Some(100), Some(95), "100M", "chr2" => 60, Some(100), Some(80), "100M";
+);
+
+test_select_chr_anchor!(select03; // aligned length
+  expect |> "chr2", 60, false;
+  vargs ... "chr1" => 60, None, None, "50S50M", "chr2" => 60, None, None, "20S80M";
+);
+
+test_select_chr_anchor!(select04; // quality from chosen anchor regardless of order
+  expect |> "chr1", 60, false;
+  vargs ... "chr1" => 60, None, None, "100M", "chr2" => 0, None, None, "100M";
+);
+
+// ambiguous
+test_select_chr_anchor!(ambiguous01; // low mapping quality
+  expect |> "chr1", 0, true;
+  vargs ... "chr1" => 0, Some(100), None, "100M";
+);
+
+test_select_chr_anchor!(ambiguous02; // suboptimal score equal to alignment score
+  expect |> "chr1", 60, true;
+  vargs ... "chr1" => 60, Some(100), Some(100), "100M";
+);
+
+test_select_chr_anchor!(ambiguous03; // tied alternate
+  expect |> "chr1", 60, true;
+  vargs ... "chr1" => 60, Some(100), None, "100M", "chr2" => 60, Some(100), None, "100M";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // reverse sequence
 macro_rules! test_reverse_sequence {
   ( $function: ident;
@@ -187,3 +262,13 @@ test_reverse_sequence!(rev03; "CAAGAAC".to_string(), "GTTCTTG");
 test_reverse_sequence!(rev04; "GATTACA".to_string(), "TGTAATC");
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// display
+#[test]
+fn display01() { // partially loaded read prints placeholders
+  let mut me_chimeric_read = MEChimericRead::new();
+  me_chimeric_read.sequence = "ACGT".to_string();
+  assert_eq!(me_chimeric_read.to_string(), ".\t.\t0\tACGT\t\n");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,220 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  GenotypeEnum,
+  MEGenotyper,
+  MEInsertionCall,
+  MELibrary,
+  Position,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// sample site
+fn me_insertion_call(
+  chr: &str,
+  position: u64,
+  mobel: &str,
+  (precise, five_prime): (bool, usize),
+) -> MEInsertionCall {
+  let mut me_insertion_call = MEInsertionCall::new();
+  me_insertion_call.chr = chr.to_string();
+  me_insertion_call.position = Position::OneBased(position);
+  me_insertion_call.me_info.mobel = mobel.to_string();
+  me_insertion_call.precise = precise;
+  me_insertion_call.five_prime = five_prime;
+  me_insertion_call
+}
+
+// catalog sites at chr1:10000 & chr1:30000
+fn sites() -> Vec<MEInsertionCall> {
+  vec![
+    me_insertion_call("chr1", 10000, "AluY", (true, 0)),
+    me_insertion_call("chr1", 30000, "L1HS", (true, 0)),
+  ]
+}
+
+// mobile element library
+fn library() -> HashMap<String, MELibrary> {
+  let mut me_library = MELibrary::new();
+  me_library.size = 300;
+  me_library.classification.family = "AluY".to_string();
+  let mut library = HashMap::new();
+  library.insert("AluY".to_string(), me_library);
+  library
+}
+
+// mates aligned reverse at mobile element start, split read clip at start
+const ME_ALIGNMENT: &str = "@SQ\tSN:AluY\tLN:300\n\
+  disc1\t69\t*\t0\t0\t*\t*\t0\t0\tACGT\t*\n\
+  disc1\t153\tAluY\t1\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  disc2\t69\t*\t0\t0\t*\t*\t0\t0\tACGT\t*\n\
+  disc2\t153\tAluY\t1\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  disc3\t69\t*\t0\t0\t*\t*\t0\t0\tACGT\t*\n\
+  disc3\t153\tAluY\t1\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  split1\t89\tAluY\t1\t60\t55S45M\t*\t0\t0\tACGT\t*\n\
+  split1\t133\t*\t0\t0\t*\t*\t0\t0\tACGT\t*\n";
+
+// reads around chr1:10000, none around chr1:30000
+const CHR_ALIGNMENT: &str = "@SQ\tSN:chr1\tLN:50000\n\
+  pair1\t99\tchr1\t9900\t60\t100M\t=\t10100\t300\tACGT\t*\n\
+  pair1\t147\tchr1\t10100\t60\t100M\t=\t9900\t-300\tACGT\t*\n\
+  read1\t0\tchr1\t9950\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  split1\t81\tchr1\t9945\t60\t55M45S\t*\t0\t0\tACGT\t*\n\
+  split1\t161\tchr1\t9720\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  disc1\t97\tchr1\t9700\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  disc2\t97\tchr1\t9960\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  disc3\t97\tchr1\t9800\t60\t100M\t*\t0\t0\tACGT\t*\n\
+  low1\t0\tchr1\t9950\t5\t100M\t*\t0\t0\tACGT\t*\n\
+  far1\t0\tchr1\t20000\t60\t100M\t*\t0\t0\tACGT\t*\n";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// catalog
+macro_rules! test_catalog {
+  ( $function: ident;
+    params |> $sample_calls: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let produced = MEGenotyper::new()
+        .catalog(&$sample_calls)
+        .iter()
+        .map(|site| (site.chr.clone(), site.position.one_based()))
+        .collect::<Vec<(String, u64)>>();
+      let expect = $expect
+        .iter()
+        .map(|(chr, position): &(&str, u64)| (chr.to_string(), *position))
+        .collect::<Vec<(String, u64)>>();
+      assert_eq!(produced, expect);
+    }
+  };
+}
+
+// count
+macro_rules! test_count {
+  ( $function: ident;
+    params |> $me_alignment: expr, $chr_alignment: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let produced = MEGenotyper::new()
+        .count(
+          $me_alignment.as_bytes(),
+          $chr_alignment.as_bytes(),
+          &library(),
+          &sites(),
+        )
+        .expect("Counting failed!")
+        .iter()
+        .map(|allele_count| (allele_count.reference, allele_count.alternate))
+        .collect::<Vec<(usize, usize)>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_catalog!(catalog01; // shared site merged on best supported call
+  params |> [
+    vec![me_insertion_call("chr1", 10000, "AluY", (false, 2))],
+    vec![me_insertion_call("chr1", 10060, "AluY", (false, 7))],
+  ];
+  expect |> [("chr1", 10060)];
+);
+
+test_catalog!(catalog02; // precise call preferred over support
+  params |> [
+    vec![me_insertion_call("chr1", 10000, "AluY", (true, 2))],
+    vec![me_insertion_call("chr1", 10060, "AluY", (false, 7))],
+  ];
+  expect |> [("chr1", 10000)];
+);
+
+test_catalog!(catalog03; // sites beyond merge distance kept apart
+  params |> [
+    vec![me_insertion_call("chr1", 10000, "AluY", (true, 2))],
+    vec![me_insertion_call("chr1", 10101, "AluY", (true, 2))],
+  ];
+  expect |> [("chr1", 10000), ("chr1", 10101)];
+);
+
+test_catalog!(catalog04; // families & chromosomes kept apart
+  params |> [
+    vec![
+      me_insertion_call("chr2", 500, "AluY", (true, 2)),
+      me_insertion_call("chr1", 10000, "AluY", (true, 2)),
+    ],
+    vec![me_insertion_call("chr1", 10020, "L1HS", (true, 2))],
+  ];
+  expect |> [("chr1", 10000), ("chr1", 10020), ("chr2", 500)];
+);
+
+test_catalog!(catalog05; // no calls
+  params |> [Vec::<MEInsertionCall>::new()];
+  expect |> Vec::<(&str, u64)>::new();
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_count!(count01; // spanning pair & read, split read, discordant pairs
+  params |> ME_ALIGNMENT, CHR_ALIGNMENT;
+  expect |> vec![(2, 4), (0, 0)];
+);
+
+test_count!(count02; // mates aligned as the same read in pair ignored
+  params |> ME_ALIGNMENT.replace("disc1\t153", "disc1\t89"), CHR_ALIGNMENT;
+  expect |> vec![(2, 3), (0, 0)];
+);
+
+test_count!(count03; // without mobile element mates, unclipped reads only
+  params |> "", CHR_ALIGNMENT;
+  expect |> vec![(3, 0), (0, 0)];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn genotype01() {
+  let sample_genotypes = MEGenotyper::new()
+    .genotype(
+      ME_ALIGNMENT.as_bytes(),
+      CHR_ALIGNMENT.as_bytes(),
+      &library(),
+      &sites(),
+    )
+    .expect("Genotyping failed!");
+  let produced = sample_genotypes
+    .iter()
+    .map(|sample_genotype| sample_genotype.genotype)
+    .collect::<Vec<GenotypeEnum>>();
+  assert_eq!(produced, vec![
+    GenotypeEnum::Heterozygous,
+    GenotypeEnum::NoCall
+  ]);
+  assert_eq!(sample_genotypes[1].to_string(), "./.:.:.:0,0");
+}
+
+#[test]
+fn error01() {
+  assert!(MEGenotyper::new()
+    .count(
+      "".as_bytes(),
+      "read1\t0\tchr1\n".as_bytes(),
+      &library(),
+      &sites(),
+    )
+    .is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,278 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  MEClassification,
+  MEInsertionCaller,
+  MELevelEnum,
+  MELibrary,
+  StrandEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// deterministic read sequence
+fn sequence(seed: usize) -> String {
+  let mut state = seed as u64 * 2_654_435_761 + 1;
+  (0..100)
+    .map(|_| {
+      state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+      ['A', 'C', 'G', 'T'][(state >> 33) as usize % 4]
+    })
+    .collect()
+}
+
+// SAM record
+fn record(
+  read_id: &str,
+  flag: i32,
+  scaffold: &str,
+  position: i32,
+  cigar: &str,
+  seq: &str,
+) -> String {
+  format!(
+    "{}\t{}\t{}\t{}\t60\t{}\t*\t0\t0\t{}\t*\n",
+    read_id, flag, scaffold, position, cigar, seq
+  )
+}
+
+// mobile element library
+fn library() -> HashMap<String, MELibrary> {
+  let mut me_library = MELibrary::new();
+  me_library.size = 6000;
+  me_library.classification.family = "L1".to_string();
+  let mut library = HashMap::new();
+  library.insert("L1".to_string(), me_library);
+  library
+}
+
+// synthetic insertion at chr1:10000, plus a lone pair at chr1:3000
+fn alignments() -> (String, String) {
+  let mut me_sam = "@SQ\tSN:L1\tLN:6000\n".to_string();
+  let mut chr_sam = "@SQ\tSN:chr1\tLN:20000\n".to_string();
+
+  // 5' discordant pairs: forward anchor, mate reverse at consensus start
+  for (ix, position) in [9730, 9770, 3010].iter().enumerate() {
+    let id = format!("five{}", ix);
+    let (seq1, seq2) = (sequence(ix), sequence(ix + 100));
+    me_sam.push_str(&record(&id, 69, "*", 0, "*", &seq1));
+    me_sam.push_str(&record(&id, 153, "L1", 51, "100M", &seq2));
+    chr_sam.push_str(&record(&id, 97, "chr1", *position, "100M", &seq1));
+    chr_sam.push_str(&record(&id, 133, "*", 0, "*", &seq2));
+  }
+
+  // 5' split pairs: forward anchor, mate spans junction
+  for (ix, (position, chr_cigar, me_cigar)) in
+    [(9945, "55M45S", "55S45M"), (9950, "50M50S", "50S50M")]
+      .iter()
+      .enumerate()
+  {
+    let id = format!("split{}", ix);
+    let (seq1, seq2) = (sequence(ix + 200), sequence(ix + 300));
+    me_sam.push_str(&record(&id, 89, "L1", 1, me_cigar, &seq1));
+    me_sam.push_str(&record(&id, 133, "*", 0, "*", &seq2));
+    chr_sam.push_str(&record(&id, 81, "chr1", *position, chr_cigar, &seq1));
+    let mate_position = 9720 + ix as i32 * 20;
+    chr_sam.push_str(&record(&id, 161, "chr1", mate_position, "100M", &seq2));
+  }
+
+  // 3' discordant pairs: reverse anchor, mate forward at consensus end
+  for (ix, (position, me_position)) in
+    [(10110, 5850), (10130, 5870), (10150, 5890), (10170, 5901)]
+      .iter()
+      .enumerate()
+  {
+    let id = format!("three{}", ix);
+    let (seq1, seq2) = (sequence(ix + 400), sequence(ix + 500));
+    me_sam.push_str(&record(&id, 69, "*", 0, "*", &seq1));
+    me_sam.push_str(&record(&id, 137, "L1", *me_position, "100M", &seq2));
+    chr_sam.push_str(&record(&id, 81, "chr1", *position, "100M", &seq1));
+  }
+
+  (me_sam, chr_sam)
+}
+
+// 3' junction reads clipped within poly-A tail, mates anchored downstream
+fn tail_alignments() -> (String, String) {
+  let (mut me_sam, mut chr_sam) = alignments();
+  for ix in 0..2 {
+    let id = format!("tail{}", ix);
+    let seq1 = format!(
+      "{}{}{}",
+      &sequence(ix + 600)[..15],
+      "A".repeat(15),
+      &sequence(ix + 700)[..70]
+    );
+    let seq2 = sequence(ix + 800);
+    me_sam.push_str(&record(&id, 69, "*", 0, "*", &seq1));
+    me_sam.push_str(&record(&id, 133, "*", 0, "*", &seq2));
+    chr_sam.push_str(&record(&id, 97, "chr1", 10001, "30S70M", &seq1));
+    let mate_position = 10120 + ix as i32 * 20;
+    chr_sam.push_str(&record(&id, 145, "chr1", mate_position, "100M", &seq2));
+  }
+  (me_sam, chr_sam)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// call
+macro_rules! test_call {
+  ( $function: ident;
+    params |> $cluster_distance: expr;
+    expect |> $count: expr, $position: expr, $precise: expr, $support: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let (me_sam, chr_sam) = alignments();
+      let mut me_insertion_caller = MEInsertionCaller::new();
+      me_insertion_caller.cluster_distance = $cluster_distance;
+      let me_insertion_calls = me_insertion_caller
+        .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library())
+        .expect("Insertion calling failed!");
+
+      assert_eq!(me_insertion_calls.len(), $count);
+      let me_insertion_call = &me_insertion_calls[0];
+      assert_eq!(me_insertion_call.chr, "chr1");
+      assert_eq!(me_insertion_call.position.one_based(), $position);
+      assert_eq!(me_insertion_call.precise, $precise);
+      assert_eq!(me_insertion_call.support(), $support);
+      assert_eq!(me_insertion_call.me_info.strand, StrandEnum::Forward);
+      assert_eq!(me_insertion_call.classification.family, "L1");
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_call!(call01; // both junctions clustered onto a single site
+  params |> 500;
+  expect |> 1, 10000, true, 8;
+);
+
+test_call!(call02; // junctions apart, 5' site refined by split reads
+  params |> 100;
+  expect |> 2, 10000, true, 4;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// summary
+#[test]
+fn summary01() {
+  let (me_sam, chr_sam) = alignments();
+  let me_insertion_calls = MEInsertionCaller::new()
+    .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library())
+    .expect("Insertion calling failed!");
+
+  let me_insertion_call = &me_insertion_calls[0];
+  assert_eq!(me_insertion_call.me_info.to_string(), "L1,1,6000,+");
+  assert_eq!(
+    (me_insertion_call.five_prime, me_insertion_call.three_prime),
+    (4, 4)
+  );
+  assert_eq!(me_insertion_call.split_reads, 2);
+  assert_eq!(
+    (
+      me_insertion_call.ci_start.one_based(),
+      me_insertion_call.ci_end.one_based()
+    ),
+    (10000, 10000)
+  );
+  assert_eq!(me_insertion_call.tsd, None);
+}
+
+#[test]
+fn tail01() { // tail-only junction reads count as 3' evidence
+  let (me_sam, chr_sam) = tail_alignments();
+  let me_insertion_calls = MEInsertionCaller::new()
+    .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library())
+    .expect("Insertion calling failed!");
+
+  let me_insertion_call = &me_insertion_calls[0];
+  assert_eq!(
+    (me_insertion_call.five_prime, me_insertion_call.three_prime),
+    (4, 6)
+  );
+  assert_eq!(me_insertion_call.tail_length, Some(15));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// hierarchy level
+#[test]
+fn level01() { // sibling subfamilies merged at family level
+  // 5' mates on L1HS, 3' mates on L1PA2
+  let (me_sam, chr_sam) = alignments();
+  let me_sam = me_sam
+    .lines()
+    .map(|line| {
+      let subfamily = if line.starts_with("three") {
+        "\tL1PA2\t"
+      } else {
+        "\tL1HS\t"
+      };
+      format!("{}\n", line.replacen("\tL1\t", subfamily, 1))
+    })
+    .collect::<String>();
+  let mut library = HashMap::new();
+  for subfamily in ["L1HS", "L1PA2"].iter() {
+    let mut me_library = MELibrary::new();
+    me_library.size = 6000;
+    me_library.classification = MEClassification::load(subfamily, "LINE/L1");
+    library.insert(subfamily.to_string(), me_library);
+  }
+
+  let mut me_insertion_caller = MEInsertionCaller::new();
+  let me_insertion_calls = me_insertion_caller
+    .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library)
+    .expect("Insertion calling failed!");
+  assert_eq!(me_insertion_calls[0].me_info.to_string(), "L1HS,1,150,+");
+
+  me_insertion_caller.level = MELevelEnum::Family;
+  let me_insertion_calls = me_insertion_caller
+    .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library)
+    .expect("Insertion calling failed!");
+  let me_insertion_call = &me_insertion_calls[0];
+  assert_eq!(me_insertion_call.me_info.to_string(), "L1HS,1,6000,+");
+  assert_eq!(me_insertion_call.me_info.support, 8);
+  assert_eq!(me_insertion_call.classification.family, "L1");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// malformed records
+#[test]
+fn skip01() { // malformed, out of range & unsupported records skipped
+  let (me_sam, chr_sam) = alignments();
+  let me_sam = format!("{}read\t0\tL1\n", me_sam);
+  let chr_sam = format!(
+    "@SQ\tSN:chr2\tLN:many\n{}{}{}",
+    chr_sam,
+    record("read", 0, "chr1", 10001, "10Q90M", "ACGT"),
+    record("read", 4097, "chr1", 10001, "100M", "ACGT")
+  );
+  let me_insertion_calls = MEInsertionCaller::new()
+    .call(me_sam.as_bytes(), chr_sam.as_bytes(), &library())
+    .expect("Insertion calling failed!");
+
+  assert_eq!(me_insertion_calls.len(), 1);
+  assert_eq!(me_insertion_calls[0].support(), 8);
+}
+
+// fail
+#[test]
+fn fail01() { // reading failure
+  let me_insertion_caller = MEInsertionCaller::new();
+  assert!(me_insertion_caller
+    .call(&b"read\t0\xff\n"[..], "".as_bytes(), &library())
+    .is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
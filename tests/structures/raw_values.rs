@@ -6,6 +6,9 @@ use genomic_structures::{
   OrientationEnum,
   RawValues,
   ReadControl,
+  StrandEnum,
+  SupplementaryAlignment,
+  Position,
   CIGAR,
 };
 
@@ -41,14 +44,118 @@ test_raw_values!(test01;
     },
     flag: 16,
     scaffold: "scaffold".to_string(),
-    position: 1,
+    position: Position::OneBased(1),
     quality: 60,
-    cigar: CIGAR::load("100M", 1).expect("CIGAR loading failed!"),
+    cigar: CIGAR::load("100M", Position::OneBased(1))
+      .expect("CIGAR loading failed!"),
     tlen: 100,
     sequence: "GATTACA".to_string(),
     orientation: OrientationEnum::None,
     extra: ExtraValuesEnum::None,
+    alignment_score: None,
+    suboptimal_score: None,
+    supplementary: vec![],
   };
 );
 
-////////////////////////////////////////////////////////////////////////////////////////////////////
+test_raw_values!(test02; // optional fields
+  params |> vec!["ID", "16", "scaffold", "1", "60", "100M", "", "", "100", "GATTACA", "", "NM:i:0", "AS:i:100", "XS:i:95"];
+  expect |> RawValues{
+    read_id: ReadControl{
+      current: "ID".to_string(),
+      previous: "".to_string(),
+    },
+    flag: 16,
+    scaffold: "scaffold".to_string(),
+    position: Position::OneBased(1),
+    quality: 60,
+    cigar: CIGAR::load("100M", Position::OneBased(1))
+      .expect("CIGAR loading failed!"),
+    tlen: 100,
+    sequence: "GATTACA".to_string(),
+    orientation: OrientationEnum::None,
+    extra: ExtraValuesEnum::None,
+    alignment_score: Some(100),
+    suboptimal_score: Some(95),
+    supplementary: vec![],
+  };
+);
+
+test_raw_values!(test03; // supplementary alignments
+  params |> vec!["ID", "0", "chr1", "1001", "60", "60M40S", "", "", "0", "GATTACA", "", "SA:Z:chr2,5001,-,60H40M,50,0;chr1,9001,+,70H30M,20,2;"];
+  expect |> RawValues{
+    read_id: ReadControl{
+      current: "ID".to_string(),
+      previous: "".to_string(),
+    },
+    flag: 0,
+    scaffold: "chr1".to_string(),
+    position: Position::OneBased(1001),
+    quality: 60,
+    cigar: CIGAR::load("60M40S", Position::OneBased(1001))
+      .expect("CIGAR loading failed!"),
+    tlen: 0,
+    sequence: "GATTACA".to_string(),
+    orientation: OrientationEnum::None,
+    extra: ExtraValuesEnum::None,
+    alignment_score: None,
+    suboptimal_score: None,
+    supplementary: vec![
+      SupplementaryAlignment{
+        chr: "chr2".to_string(),
+        position: Position::OneBased(5001),
+        strand: StrandEnum::Reverse,
+        cigar: CIGAR::load("60H40M", Position::OneBased(5001))
+          .expect("CIGAR loading failed!"),
+        mapq: 50,
+        mismatches: 0,
+      },
+      SupplementaryAlignment{
+        chr: "chr1".to_string(),
+        position: Position::OneBased(9001),
+        strand: StrandEnum::Forward,
+        cigar: CIGAR::load("70H30M", Position::OneBased(9001))
+          .expect("CIGAR loading failed!"),
+        mapq: 20,
+        mismatches: 2,
+      },
+    ],
+  };
+);
+
+////////////////////////////////////////////////////////////////////////////////////
+
+test_raw_values!(test04; // malformed supplementary alignments dropped
+  params |> vec!["ID", "0", "chr1", "1001", "60", "60M40S", "", "", "0", "GATTACA", "", "SA:Z:chr2,5001,-,60H40M,50,0;chr1,9001,?,70H30M,20,2;chr3,1,+,40M,high,0;chr4"];
+  expect |> RawValues{
+    read_id: ReadControl{
+      current: "ID".to_string(),
+      previous: "".to_string(),
+    },
+    flag: 0,
+    scaffold: "chr1".to_string(),
+    position: Position::OneBased(1001),
+    quality: 60,
+    cigar: CIGAR::load("60M40S", Position::OneBased(1001))
+      .expect("CIGAR loading failed!"),
+    tlen: 0,
+    sequence: "GATTACA".to_string(),
+    orientation: OrientationEnum::None,
+    extra: ExtraValuesEnum::None,
+    alignment_score: None,
+    suboptimal_score: None,
+    supplementary: vec![
+      SupplementaryAlignment{
+        chr: "chr2".to_string(),
+        position: Position::OneBased(5001),
+        strand: StrandEnum::Reverse,
+        cigar: CIGAR::load("60H40M", Position::OneBased(5001))
+          .expect("CIGAR loading failed!"),
+        mapq: 50,
+        mismatches: 0,
+      },
+    ],
+  };
+);
+
+////////////////
@@ -0,0 +1,141 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  GenomicInterval,
+  Position,
+  Region,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// parse
+macro_rules! test_parse {
+  ( $function: ident;
+    params |> $region: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let region = $region.parse::<Region>().expect("Parsing failed!");
+      assert_eq!((region.chr.as_str(), region.span()), $expect);
+    }
+  };
+}
+
+// malformed
+macro_rules! test_error {
+  ( $function: ident;
+    params |> $region: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      assert!($region.parse::<Region>().is_err());
+    }
+  };
+}
+
+// position conversion
+macro_rules! test_position {
+  ( $function: ident;
+    params |> $position: expr;
+    expect |> $one_based: expr, $zero_based: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let position: Position = $position;
+      assert_eq!(
+        (position.one_based(), position.zero_based()),
+        ($one_based, $zero_based)
+      );
+      assert_eq!(position.to_one_based(), position.to_zero_based());
+    }
+  };
+}
+
+// interval conversion
+macro_rules! test_interval {
+  ( $function: ident;
+    params |> $region: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let interval = GenomicInterval::from(&$region);
+      let produced = (interval.start.one_based(), interval.end.one_based());
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_parse!(parse01; // span
+  params |> "chr1:1001-2000";
+  expect |> ("chr1", (1001, 2000));
+);
+
+test_parse!(parse02; // thousands separators
+  params |> "chr1:1,001-2,000";
+  expect |> ("chr1", (1001, 2000));
+);
+
+test_parse!(parse03; // single position
+  params |> "chrX:500";
+  expect |> ("chrX", (500, 500));
+);
+
+test_parse!(parse04; // scaffold over 2 Gb
+  params |> "chr3B:2500000000-2500000100";
+  expect |> ("chr3B", (2500000000, 2500000100));
+);
+
+test_parse!(parse05; // colon on sequence name
+  params |> "HLA-A*01:01:1-100";
+  expect |> ("HLA-A*01:01", (1, 100));
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_error!(error01; // sequence name alone
+  params |> "chr1";
+);
+
+test_error!(error02; // zero coordinate on 1-based region
+  params |> "chr1:0-100";
+);
+
+test_error!(error03; // reversed coordinates
+  params |> "chr1:200-100";
+);
+
+test_error!(error04; // non numeric coordinate
+  params |> "chr1:start-100";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_position!(position01; // 1-based
+  params |> Position::OneBased(100);
+  expect |> 100, 99;
+);
+
+test_position!(position02; // 0-based
+  params |> Position::ZeroBased(99);
+  expect |> 100, 99;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_interval!(interval01; // BED region onto inclusive interval
+  params |> Region::zero_based("chr1", 1000, 2000);
+  expect |> (1001, 2000);
+);
+
+test_interval!(interval02; // region beyond 32-bit coordinates
+  params |> Region::one_based("chr3B", 2500000000, 2500000100);
+  expect |> (2500000000, 2500000100);
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,103 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  MEClassification,
+  Position,
+  RepeatMaskerRecord,
+  StrandEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load & update
+macro_rules! test_repeat_masker_record {
+  ( $function: ident;
+    params |> $line: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let loaded = RepeatMaskerRecord::load($line.split_whitespace().collect())
+        .expect("RepeatMaskerRecord loading failed!");
+      assert_eq!(
+        loaded, $expect,
+        "\n\nLoaded RepeatMaskerRecord:\n{:#?}.\n\nExpected:\n{:#?}.\n\n",
+        loaded, $expect,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_repeat_masker_record!(test01;
+  params |> "1234 1.1 0.2 0.3 chr1 1001 7000 (100) + L1HS LINE/L1 1 6000 (155) 1";
+  expect |> RepeatMaskerRecord{
+    score: 1234,
+    divergence: 1.1,
+    deletion: 0.2,
+    insertion: 0.3,
+    scaffold: "chr1".to_string(),
+    start: Position::OneBased(1001),
+    end: Position::OneBased(7000),
+    strand: StrandEnum::Forward,
+    classification: MEClassification::load("L1HS", "LINE/L1"),
+    repeat_start: Position::OneBased(1),
+    repeat_end: Position::OneBased(6000),
+    repeat_left: 155,
+    id: 1,
+  };
+);
+
+test_repeat_masker_record!(test02;
+  params |> "301 12.0 1.0 0.0 chr1 9001 9300 (0) C AluY SINE/Alu (11) 300 1 2 *";
+  expect |> RepeatMaskerRecord{
+    score: 301,
+    divergence: 12.,
+    deletion: 1.,
+    insertion: 0.,
+    scaffold: "chr1".to_string(),
+    start: Position::OneBased(9001),
+    end: Position::OneBased(9300),
+    strand: StrandEnum::Reverse,
+    classification: MEClassification::load("AluY", "SINE/Alu"),
+    repeat_start: Position::OneBased(1),
+    repeat_end: Position::OneBased(300),
+    repeat_left: 11,
+    id: 2,
+  };
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// fail
+macro_rules! test_repeat_masker_record_fail {
+  ( $function: ident;
+    params |> $line: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let loaded = RepeatMaskerRecord::load($line.split_whitespace().collect());
+      assert!(
+        loaded.is_err(),
+        "\n\nLoaded RepeatMaskerRecord:\n{:#?}.\n\n",
+        loaded,
+      );
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_repeat_masker_record_fail!(fail01;
+  params |> "score div. del. ins. sequence begin end (left) repeat class/family begin end (left) ID";
+);
+
+test_repeat_masker_record_fail!(fail02;
+  params |> "1234 1.1 0.2 0.3 chr1 1001 7000 (100) + L1HS LINE/L1";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,82 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  GenotypeEnum,
+  SampleGenotype,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// load
+macro_rules! test_load {
+  ( $function: ident;
+    params |> $reference: expr, $alternate: expr;
+    expect |> $genotype: expr, $quality: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let sample_genotype = SampleGenotype::load($reference, $alternate, 0.01);
+      assert_eq!(
+        (sample_genotype.genotype, sample_genotype.quality),
+        ($genotype, $quality)
+      );
+    }
+  };
+}
+
+// display
+macro_rules! test_display {
+  ( $function: ident;
+    params |> $reference: expr, $alternate: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let sample_genotype = SampleGenotype::load($reference, $alternate, 0.01);
+      assert_eq!(sample_genotype.to_string(), $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_load!(load01; // reference only
+  params |> 20, 0;
+  expect |> GenotypeEnum::HomozygousReference, 59;
+);
+
+test_load!(load02; // balanced alleles, quality capped
+  params |> 10, 10;
+  expect |> GenotypeEnum::Heterozygous, 99;
+);
+
+test_load!(load03; // alternate only
+  params |> 0, 20;
+  expect |> GenotypeEnum::HomozygousAlternate, 59;
+);
+
+test_load!(load04; // single fragment, low confidence
+  params |> 0, 1;
+  expect |> GenotypeEnum::HomozygousAlternate, 5;
+);
+
+test_load!(load05; // without support
+  params |> 0, 0;
+  expect |> GenotypeEnum::NoCall, 0;
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_display!(display01; // scaled likelihoods
+  params |> 0, 2;
+  expect |> "1/1:7:-3.99,-0.59,0.00:0,2";
+);
+
+test_display!(display02; // no-call keeps allele depths
+  params |> 0, 0;
+  expect |> "./.:.:.:0,0";
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,132 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  AnnotationIndex,
+  MEClassification,
+  MEInsertionCall,
+  Position,
+  SiteFilter,
+  SiteFilterEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// blacklist & reference elements
+fn site_filter() -> SiteFilter {
+  let mut site_filter = SiteFilter::new();
+  site_filter.blacklist =
+    AnnotationIndex::load_bed("chr1\t50000\t60000\tgap\n".as_bytes())
+      .expect("BED loading failed!");
+  for (start, end, label) in
+    [(10001, 16000, "L1"), (30001, 30300, "Alu"), (55001, 55300, "Alu")].iter()
+  {
+    site_filter.reference.insert(
+      "chr1",
+      Position::OneBased(*start),
+      Position::OneBased(*end),
+      label,
+    );
+  }
+  site_filter.reference.index();
+  site_filter
+}
+
+// candidate insertion
+fn me_insertion_call(
+  position: u64,
+  (subfamily, class_family): (&str, &str),
+) -> MEInsertionCall {
+  let mut me_insertion_call = MEInsertionCall::new();
+  me_insertion_call.chr = "chr1".to_string();
+  me_insertion_call.position = Position::OneBased(position);
+  me_insertion_call.classification =
+    MEClassification::load(subfamily, class_family);
+  me_insertion_call
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// tag
+macro_rules! test_tag {
+  ( $function: ident;
+    params |> $position: expr, $classification: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let mut me_insertion_call = me_insertion_call($position, $classification);
+      site_filter().tag(&mut me_insertion_call);
+      let expect: Vec<SiteFilterEnum> = $expect;
+      assert_eq!(me_insertion_call.filters, expect);
+    }
+  };
+}
+
+// apply
+macro_rules! test_apply {
+  ( $function: ident;
+    params |> $drop: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let me_insertion_calls = vec![
+        me_insertion_call(20000, ("L1HS", "LINE/L1")),
+        me_insertion_call(30400, ("AluY", "SINE/Alu")),
+        me_insertion_call(55200, ("AluY", "SINE/Alu")),
+      ];
+      let produced = site_filter()
+        .apply(me_insertion_calls, $drop)
+        .iter()
+        .map(|me_insertion_call| {
+          (
+            me_insertion_call.position.one_based(),
+            me_insertion_call.filters.len(),
+          )
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_tag!(tag01; // site away from annotations
+  params |> 20000, ("L1HS", "LINE/L1");
+  expect |> vec![];
+);
+
+test_tag!(tag02; // site within distance of same family element
+  params |> 16400, ("L1HS", "LINE/L1");
+  expect |> vec![SiteFilterEnum::ReferenceElement];
+);
+
+test_tag!(tag03; // site beyond distance of same family element
+  params |> 16600, ("L1HS", "LINE/L1");
+  expect |> vec![];
+);
+
+test_tag!(tag04; // site next to element of different family
+  params |> 30400, ("L1HS", "LINE/L1");
+  expect |> vec![];
+);
+
+test_tag!(tag05; // blacklisted site within same family element
+  params |> 55200, ("AluY", "SINE/Alu");
+  expect |> vec![SiteFilterEnum::Blacklist, SiteFilterEnum::ReferenceElement];
+);
+
+test_apply!(apply01; // tag filtered calls
+  params |> false;
+  expect |> vec![(20000, 0), (30400, 1), (55200, 2)];
+);
+
+test_apply!(apply02; // drop filtered calls
+  params |> true;
+  expect |> vec![(20000, 0)];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,382 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// standard libraries
+use std::fs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// crate utilities
+use genomic_structures::{
+  IndexedFasta,
+  SVCaller,
+  SVType,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// forward & reverse mates per pair
+fn alignments(pairs: &[(&str, i32, &str, i32)]) -> String {
+  let mut sam = "@SQ\tSN:chr1\tLN:20000\n@SQ\tSN:chr2\tLN:20000\n".to_string();
+  for (ix, (chr1, position1, chr2, position2)) in pairs.iter().enumerate() {
+    sam.push_str(&format!(
+      "pair{}\t97\t{}\t{}\t60\t100M\t*\t0\t0\tACGT\t*\n",
+      ix, chr1, position1
+    ));
+    sam.push_str(&format!(
+      "pair{}\t145\t{}\t{}\t60\t100M\t*\t0\t0\tACGT\t*\n",
+      ix, chr2, position2
+    ));
+  }
+  sam
+}
+
+// pseudo random reference, with microhomology planted at deletion junction
+fn reference(
+  name: &str,
+  left: usize,
+  right: usize,
+  homology: usize,
+) -> (IndexedFasta, Vec<u8>) {
+  let mut seed: u64 = 7;
+  let mut bases = (0..3000)
+    .map(|_| {
+      seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+      b"ACGT"[(seed >> 62) as usize]
+    })
+    .collect::<Vec<u8>>();
+  for shift in 0..=homology {
+    // homology boundary must differ
+    bases[right - 2 - shift] = if shift < homology {
+      bases[left - 1 - shift]
+    } else if bases[left - 1 - shift] == b'A' {
+      b'C'
+    } else {
+      b'A'
+    };
+  }
+  if bases[left] == bases[right - 1] {
+    bases[right - 1] = if bases[left] == b'A' { b'C' } else { b'A' };
+  }
+
+  let directory = std::env::temp_dir().join("genomic_structures_sv_caller");
+  fs::create_dir_all(&directory).expect("Directory creation failed!");
+  let path = directory.join(name);
+  fs::remove_file(format!("{}.fai", path.display())).ok();
+  let mut fasta = b">chr1\n".to_vec();
+  fasta.extend(&bases);
+  fasta.push(b'\n');
+  fs::write(&path, fasta).expect("FASTA writing failed!");
+  (IndexedFasta::open(&path).expect("FASTA indexing failed!"), bases)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// call
+macro_rules! test_call {
+  ( $function: ident;
+    params |> $pairs: expr;
+    expect |> $expect: expr;
+  ) => {
+    test_call!($function;
+      params |> $pairs, Vec::<&str>::new();
+      expect |> $expect;
+    );
+  };
+  ( $function: ident;
+    params |> $pairs: expr, $records: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let sam = alignments(&$pairs) + &$records.join("\n");
+      let sv_calls = SVCaller::new()
+        .call(sam.as_bytes(), None)
+        .expect("Structural variant calling failed!");
+      let produced = sv_calls
+        .iter()
+        .map(|sv_call| {
+          (
+            sv_call.svtype,
+            sv_call.chr.as_str(),
+            sv_call.position.one_based(),
+            sv_call.end.one_based(),
+            sv_call.support,
+          )
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+// call with junction characterization
+macro_rules! test_junction {
+  ( $function: ident;
+    params |> $left: expr, $right: expr, $homology: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let (reference, bases) =
+        reference(stringify!($function), $left, $right, $homology);
+      let segment = |start: usize, end: usize| {
+        String::from_utf8(bases[start - 1..end].to_vec())
+          .expect("Sequence decoding failed!")
+      };
+
+      let record = |id: &str, flag: i32, position: usize, cigar: &str, seq| {
+        format!(
+          "{}\t{}\tchr1\t{}\t60\t{}\t*\t0\t0\t{}\t*\n",
+          id, flag, position, cigar, seq
+        )
+      };
+
+      // discordant pair, plus split reads clipped downstream & upstream
+      let sam = vec![
+        record("pair0", 97, $left - 99, "100M", "ACGT".to_string()),
+        record("pair0", 145, $right + 100, "100M", "ACGT".to_string()),
+        record(
+          "pair1",
+          97,
+          $left - 59,
+          "60M40S",
+          segment($left - 59, $left) + &segment($right, $right + 39),
+        ),
+        record("pair1", 145, $right + 150, "100M", "ACGT".to_string()),
+        record("pair2", 97, $left - 80, "100M", "ACGT".to_string()),
+        record(
+          "pair2",
+          145,
+          $right,
+          "30S70M",
+          segment($left - 29, $left) + &segment($right, $right + 69),
+        ),
+      ]
+      .concat();
+
+      let sv_calls = SVCaller::new()
+        .call(sam.as_bytes(), Some(&reference))
+        .expect("Structural variant calling failed!");
+      let produced = sv_calls
+        .iter()
+        .map(|sv_call| {
+          let sv_junction =
+            sv_call.junction.clone().expect("Junction not resolved!");
+          (
+            sv_call.position.one_based(),
+            sv_call.end.one_based(),
+            sv_junction.homology,
+          )
+        })
+        .collect::<Vec<_>>();
+
+      let (position, end): (usize, usize) = $expect;
+      assert_eq!(produced, vec![(
+        position as u64,
+        end as u64,
+        segment(position, position + $homology - 1)
+      )]);
+    }
+  };
+}
+
+// call on split reads
+macro_rules! test_split {
+  ( $function: ident;
+    params |> $sam: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      let sv_calls = SVCaller::new()
+        .call($sam.as_bytes(), None)
+        .expect("Structural variant calling failed!");
+      let produced = sv_calls
+        .iter()
+        .map(|sv_call| {
+          (
+            sv_call.svtype,
+            sv_call.position.one_based(),
+            sv_call.end.one_based(),
+            sv_call.support,
+            sv_call.split_reads,
+          )
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+// call with read depth over heterozygous, homozygous or no deletion
+macro_rules! test_depth {
+  ( $function: ident;
+    params |> $copies: expr;
+    expect |> $expect: expr;
+  ) => {
+    #[test]
+    fn $function() {
+      // concordant pairs tiled over chromosome, thinned on deleted region
+      let copies: usize = $copies;
+      let deleted = |position: i32| position + 99 >= 2001 && position <= 2600;
+      let mut pairs = (1..4800)
+        .step_by(25)
+        .enumerate()
+        .filter(|(ix, position)| {
+          !(deleted(*position) || deleted(position + 200)) || ix % 2 < copies
+        })
+        .map(|(_, position)| ("chr1", position, "chr1", position + 200))
+        .collect::<Vec<_>>();
+      pairs.extend(vec![
+        ("chr1", 1850, "chr1", 2660),
+        ("chr1", 1880, "chr1", 2650),
+        ("chr1", 1900, "chr1", 2670),
+      ]);
+
+      let sv_calls = SVCaller::new()
+        .call(alignments(&pairs).as_bytes(), None)
+        .expect("Structural variant calling failed!");
+      let produced = sv_calls
+        .iter()
+        .map(|sv_call| (sv_call.copy_number(), sv_call.depth_supported()))
+        .collect::<Vec<_>>();
+      assert_eq!(produced, $expect);
+    }
+  };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// test
+test_call!(call01; // deletion narrowed onto inner breakends
+  params |> [
+    ("chr1", 1000, "chr1", 1800),
+    ("chr1", 1020, "chr1", 1790),
+    ("chr1", 990, "chr1", 1810),
+  ];
+  expect |> vec![(SVType::Deletion, "chr1", 1120, 1789, 3)];
+);
+
+test_call!(call02; // translocation without reciprocal junction
+  params |> [
+    ("chr1", 5000, "chr2", 8000),
+    ("chr1", 5050, "chr2", 8020),
+  ];
+  expect |> vec![(SVType::Translocation, "chr1", 5000, 8119, 2)];
+);
+
+test_call!(call03; // concordant pairs & lone discordant pair
+  params |> [
+    ("chr1", 1000, "chr1", 1200),
+    ("chr1", 3000, "chr1", 3200),
+    ("chr1", 6000, "chr1", 6800),
+  ];
+  expect |> vec![];
+);
+
+test_call!(call04; // deletion beyond translocation distance
+  params |> [
+    ("chr1", 1000, "chr1", 6000),
+    ("chr1", 1020, "chr1", 5990),
+    ("chr1", 990, "chr1", 6010),
+  ];
+  expect |> vec![(SVType::Deletion, "chr1", 1120, 5989, 3)];
+);
+
+test_call!(call05; // malformed records skipped
+  params |> [
+    ("chr1", 1000, "chr1", 1800),
+    ("chr1", 1020, "chr1", 1790),
+    ("chr1", 990, "chr1", 1810),
+  ], [
+    "pair3\t97\tchr1\tfirst\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair4\t4097\tchr1\t1300\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair5\t97\tchr1",
+  ];
+  expect |> vec![(SVType::Deletion, "chr1", 1120, 1789, 3)];
+);
+
+test_call!(call06; // deletion opened past deleted bases on anchor
+  params |> [], [
+    "pair0\t97\tchr1\t1000\t60\t50M10D50M\t*\t0\t0\tACGT\t*",
+    "pair0\t145\tchr1\t1850\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair1\t97\tchr1\t1000\t60\t50M10D50M\t*\t0\t0\tACGT\t*",
+    "pair1\t145\tchr1\t1900\t60\t100M\t*\t0\t0\tACGT\t*",
+  ];
+  expect |> vec![(SVType::Deletion, "chr1", 1110, 1849, 2)];
+);
+
+test_call!(call07; // reciprocal translocation
+  params |> [
+    ("chr1", 5000, "chr2", 8000),
+    ("chr1", 5050, "chr2", 8020),
+    ("chr2", 7700, "chr1", 5300),
+    ("chr2", 7750, "chr1", 5320),
+  ];
+  expect |> vec![(SVType::BalancedTranslocation, "chr1", 5000, 8119, 4)];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+test_junction!(junction01; // blunt deletion junction
+  params |> 1100, 1800, 0;
+  expect |> (1101, 1799);
+);
+
+test_junction!(junction02; // deletion junction over microhomology
+  params |> 1100, 1800, 2;
+  expect |> (1099, 1797);
+);
+
+test_split!(split01; // discordant pairs & split reads onto precise deletion
+  params |> [
+    "pair0\t97\tchr1\t1000\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair0\t145\tchr1\t1850\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair1\t97\tchr1\t1020\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair1\t145\tchr1\t1900\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair2\t97\tchr1\t1041\t60\t60M40S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,1801,+,60S40M,60,0;",
+    "pair2\t145\tchr1\t1950\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair3\t97\tchr1\t1050\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair3\t145\tchr1\t1801\t60\t60S40M\t*\t0\t0\tACGT\t*\tSA:Z:chr1,1041,-,60M40S,60,0;",
+  ].join("\n");
+  expect |> vec![(SVType::Deletion, 1101, 1800, 4, 2)];
+);
+
+test_split!(split02; // split reads alone, low quality supplementary ignored
+  params |> [
+    "pair0\t97\tchr1\t1041\t60\t60M40S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,1801,+,60S40M,60,0;",
+    "pair0\t145\tchr1\t1200\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair1\t97\tchr1\t1061\t60\t40M60S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,1801,+,40S60M,60,0;",
+    "pair1\t145\tchr1\t1250\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair2\t97\tchr1\t1041\t60\t60M40S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,1801,+,60S40M,5,0;",
+    "pair2\t145\tchr1\t1300\t60\t100M\t*\t0\t0\tACGT\t*",
+  ].join("\n");
+  expect |> vec![(SVType::Deletion, 1101, 1800, 0, 2)];
+);
+
+test_split!(split03; // split reads onto deletion beyond translocation distance
+  params |> [
+    "pair0\t97\tchr1\t1041\t60\t60M40S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,6801,+,60S40M,60,0;",
+    "pair0\t145\tchr1\t1200\t60\t100M\t*\t0\t0\tACGT\t*",
+    "pair1\t97\tchr1\t1061\t60\t40M60S\t*\t0\t0\tACGT\t*\tSA:Z:chr1,6801,+,40S60M,60,0;",
+    "pair1\t145\tchr1\t1250\t60\t100M\t*\t0\t0\tACGT\t*",
+  ].join("\n");
+  expect |> vec![(SVType::Deletion, 1101, 6800, 0, 2)];
+);
+
+test_depth!(depth01; // homozygous deletion
+  params |> 0;
+  expect |> vec![(Some(0), true)];
+);
+
+test_depth!(depth02; // heterozygous deletion
+  params |> 1;
+  expect |> vec![(Some(1), true)];
+);
+
+test_depth!(depth03; // deletion unsupported by read depth
+  params |> 2;
+  expect |> vec![(Some(2), false)];
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
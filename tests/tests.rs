@@ -1,6 +1,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // modules
+mod binary;
 mod functions;
 mod macros;
 mod structures;
@@ -19,9 +20,13 @@ macro_rules! load_me_anchor {
   ( $me_chimeric_read: expr, $position: expr, $orientation: tt ) => {
     let mut me_anchor = MEAnchor::new();
     me_anchor.orientation = OrientationEnum::$orientation;
+    let position = genomic_structures::Position::OneBased($position);
     match OrientationEnum::$orientation {
-      OrientationEnum::Upstream => me_anchor.cigar.left_boundry = $position,
-      OrientationEnum::Downstream => me_anchor.cigar.right_boundry = $position,
+      OrientationEnum::Upstream => {
+        me_anchor.position = position;
+        me_anchor.cigar.left_boundry = position;
+      }
+      OrientationEnum::Downstream => me_anchor.cigar.right_boundry = position,
       _ => (),
     }
     $me_chimeric_read.me_read.push(me_anchor);